@@ -0,0 +1,63 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::Lambertian;
+use ray_tracing_rs::objects::{Aabb, BvhNode, BvhSplit, Hittable, Sphere};
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::scene::generate::poisson_spheres;
+use ray_tracing_rs::{Interval, Point};
+
+use std::time::Duration;
+
+fn bvh_split(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bvh-split");
+
+    // World setup: the final scene's layout of small spheres around three large ones
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut world = poisson_spheres(
+        &Aabb::new(Point::new(-11.0, 0.2, -11.0), Point::new(11.0, 0.2, 11.0)),
+        480,
+        0.2..=0.2,
+        &mut rng,
+    );
+    let diffuse = Lambertian::new(Color::new(0.5, 0.5, 0.5));
+    for x in [-4.0, 0.0, 4.0] {
+        world.add(Sphere::new(Point::new(x, 1.0, 0.0), 1.0, diffuse.clone()));
+    }
+
+    let origin = Point::new(13.0, 2.0, 3.0);
+    let rays: Vec<_> = (0..1024)
+        .map(|_| {
+            let target = Point::new(
+                rng.random_range(-11.0..11.0),
+                0.2,
+                rng.random_range(-11.0..11.0),
+            );
+            (target - origin).unit()
+        })
+        .collect();
+
+    for split in [BvhSplit::Median, BvhSplit::Sah] {
+        let tree = BvhNode::build_with(&world, split).unwrap();
+        group.bench_function(format!("{split:?}"), |b| {
+            b.iter(|| {
+                for dir in &rays {
+                    let ray = Ray::new(&origin, *dir);
+                    black_box(tree.hit(Interval::new(0.001, f64::MAX), &ray).is_some());
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().measurement_time(Duration::from_secs(5));
+    targets = bvh_split
+}
+criterion_main!(benches);
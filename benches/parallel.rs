@@ -65,6 +65,21 @@ fn basic_world(c: &mut Criterion) {
             },
         );
 
+        group.bench_with_input(
+            BenchmarkId::new("parallel-samples", spp),
+            &spp,
+            |b, &spp| {
+                b.iter(|| {
+                    let render_options = RenderOptions::new()
+                        .parallel(ParallelOptions::AllAtOnce)
+                        .parallel_samples(true);
+                    let image_options = image.antialias(spp);
+                    camera.set_image_options(image_options);
+                    black_box(camera.render_in_memory_with_options(&world, &render_options))
+                })
+            },
+        );
+
         group.bench_with_input(
             BenchmarkId::new("series-computation", spp),
             &spp,
@@ -0,0 +1,60 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+
+use ray_tracing_rs::scene::{ParallelOptions, RenderOptions};
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{
+    Camera, CameraConfig, CameraModel, CameraPose, HittableList, ImageOptions,
+    PerspectiveProjection, Point,
+};
+
+use std::time::Duration;
+
+fn ppm_write(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ppm-write");
+
+    // An empty world at one sample per pixel, so the time is mostly spent
+    // writing the file rather than tracing
+    let world = HittableList::new();
+    let image = ImageOptions::new(1920, 1080).unwrap().antialias(0);
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(0.0, 0.0, -1.0),
+        Vector::new(0.0, 1.0, 0.0),
+    )
+    .unwrap();
+    let projection = PerspectiveProjection::new(90.0).unwrap();
+    let model = CameraModel::pinhole(1.0).unwrap();
+    let camera = Camera::new(CameraConfig::new(pose, image, projection, model));
+    let path = std::env::temp_dir().join("ray-tracing-rs-ppm-write-bench.ppm");
+
+    for (name, parallel) in [
+        ("all-at-once", ParallelOptions::AllAtOnce),
+        ("by-rows", ParallelOptions::ByRows),
+        ("series", ParallelOptions::Series),
+    ] {
+        let render_options = RenderOptions::new().parallel(parallel);
+        group.bench_with_input(
+            BenchmarkId::new("1080p", name),
+            &render_options,
+            |b, options| b.iter(|| camera.render_with_options(&world, &path, options).unwrap()),
+        );
+    }
+
+    // A single column, so per-row overhead dominates
+    let mut tall = camera.clone();
+    tall.set_image_options(ImageOptions::new(1, 2000).unwrap().antialias(0));
+    let by_rows = RenderOptions::new().parallel(ParallelOptions::ByRows);
+    group.bench_function("1x2000/by-rows", |b| {
+        b.iter(|| tall.render_with_options(&world, &path, &by_rows).unwrap())
+    });
+    group.finish();
+
+    let _ = std::fs::remove_file(path);
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10).measurement_time(Duration::from_secs(10));
+    targets = ppm_write
+}
+criterion_main!(benches);
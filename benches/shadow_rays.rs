@@ -0,0 +1,57 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::Lambertian;
+use ray_tracing_rs::objects::{Hittable, Sphere};
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{HittableList, Interval, Point};
+
+use std::time::Duration;
+
+fn shadow_rays(c: &mut Criterion) {
+    let mut group = c.benchmark_group("shadow-rays");
+
+    // World setup: a grid of spheres, so an occluded ray has many candidates
+    let mut world = HittableList::new();
+    let diffuse = Lambertian::new(Color::new(0.5, 0.5, 0.5));
+    for a in -5..5 {
+        for b in -5..5 {
+            let center = Point::new(a as f64, 0.0, b as f64 - 10.0);
+            world.add(Sphere::new(center, 0.4, diffuse.clone()));
+        }
+    }
+
+    let origin = Point::new(0.0, 0.0, 0.0);
+    let dirs: Vec<_> = (0..256)
+        .map(|i| Vector::new((i as f64 / 256.0) - 0.5, 0.0, -1.0).unit())
+        .collect();
+
+    group.bench_function("hit", |b| {
+        b.iter(|| {
+            for dir in &dirs {
+                let ray = Ray::new(&origin, *dir);
+                black_box(world.hit(Interval::new(0.001, f64::MAX), &ray).is_some());
+            }
+        })
+    });
+
+    group.bench_function("hit_p", |b| {
+        b.iter(|| {
+            for dir in &dirs {
+                let ray = Ray::new(&origin, *dir);
+                black_box(world.hit_p(Interval::new(0.001, f64::MAX), &ray));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10).measurement_time(Duration::from_secs(5));
+    targets = shadow_rays
+}
+criterion_main!(benches);
@@ -0,0 +1,54 @@
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::Lambertian;
+use ray_tracing_rs::objects::{Hittable, Sphere};
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{HittableList, Interval, Point};
+
+use std::time::Duration;
+
+fn single_object(c: &mut Criterion) {
+    let mut group = c.benchmark_group("single-object");
+
+    // The same sphere on its own and as the only object in a list, so the
+    // difference is the list's per-ray overhead
+    let diffuse = Lambertian::new(Color::new(0.5, 0.5, 0.5));
+    let sphere = Sphere::new(Point::new(0.0, 0.0, -3.0), 1.0, diffuse.clone());
+    let mut world = HittableList::new();
+    world.add(Sphere::new(Point::new(0.0, 0.0, -3.0), 1.0, diffuse));
+
+    let origin = Point::new(0.0, 0.0, 0.0);
+    let dirs: Vec<_> = (0..256)
+        .map(|i| Vector::new((i as f64 / 256.0) - 0.5, 0.0, -1.0).unit())
+        .collect();
+
+    group.bench_function("sphere", |b| {
+        b.iter(|| {
+            for dir in &dirs {
+                let ray = Ray::new(&origin, *dir);
+                black_box(sphere.hit(Interval::new(0.001, f64::MAX), &ray).is_some());
+            }
+        })
+    });
+
+    group.bench_function("list", |b| {
+        b.iter(|| {
+            for dir in &dirs {
+                let ray = Ray::new(&origin, *dir);
+                black_box(world.hit(Interval::new(0.001, f64::MAX), &ray).is_some());
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10).measurement_time(Duration::from_secs(5));
+    targets = single_object
+}
+criterion_main!(benches);
@@ -0,0 +1,91 @@
+//! Example use of the ray tracing library
+//!
+//! Orbit a camera around a small scene, re-rendering a low-resolution preview
+//! to `orbit-preview.ppm` after every step. Keep the file open in a viewer that
+//! reloads on change.
+//!
+//! Type a command and press enter: the arrow keys (or `a`/`d`/`w`/`s`) orbit
+//! left, right, up, and down, and `q` quits.
+
+use std::io::{self, BufRead, Write};
+
+use ray_tracing_rs::{
+    color::Color,
+    materials::{Lambertian as Matte, Metal},
+    objects::{Plane, Sphere},
+    vector::Vector,
+    {
+        Camera, CameraConfig, CameraModel, CameraPose, HittableList, ImageOptions,
+        PerspectiveProjection, Point,
+    },
+};
+
+/// Degrees turned per key press.
+const STEP: f64 = 15.0;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = env_logger::try_init();
+
+    let mut world = HittableList::new();
+    world
+        .add(Plane::new(
+            Vector::new(0.0, 1.0, 0.0).unit(),
+            0.5,
+            Matte::new(Color::new(0.8, 0.8, 0.0)),
+        ))
+        .add(Sphere::new(
+            Point::new(0.0, 0.0, 0.0),
+            0.5,
+            Matte::new(Color::new(0.1, 0.2, 0.5)),
+        ))
+        .add(Sphere::new(
+            Point::new(1.0, 0.0, 0.0),
+            0.5,
+            Metal::new(Color::new(0.8, 0.6, 0.2), 0.1),
+        ));
+
+    // Small and lightly sampled, so each step renders quickly
+    let image = ImageOptions::new(160, 90)?.antialias(4);
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 1.0, 4.0),
+        Point::new(0.5, 0.0, 0.0),
+        Vector::new(0.0, 1.0, 0.0),
+    )?;
+    let projection = PerspectiveProjection::new(40.0)?;
+    let model = CameraModel::pinhole(1.0)?;
+    let mut camera = Camera::new(CameraConfig::new(pose, image, projection, model));
+
+    let path = "orbit-preview.ppm";
+    camera.render(&world, path)?;
+    println!("Rendered {path}. Arrows or a/d/w/s to orbit, q to quit.");
+
+    for line in io::stdin().lock().lines() {
+        let moved = match line?.trim() {
+            "\x1b[D" | "a" => camera.orbit_step(-STEP),
+            "\x1b[C" | "d" => camera.orbit_step(STEP),
+            "\x1b[A" | "w" => camera.orbit_tilt(STEP),
+            "\x1b[B" | "s" => camera.orbit_tilt(-STEP),
+            "q" => break,
+            _ => {
+                println!("Unknown command");
+                continue;
+            }
+        };
+        if let Err(error) = moved {
+            println!("Can't orbit further: {error}");
+            continue;
+        }
+
+        camera.render(&world, path)?;
+        let position = camera.position();
+        print!(
+            "Camera at ({:.2}, {:.2}, {:.2}) > ",
+            position.x(),
+            position.y(),
+            position.z()
+        );
+        io::stdout().flush()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,203 @@
+//! What rays see when they escape the scene, and how to sample it as a light.
+
+use std::f64::consts::PI;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::color::Color;
+use crate::image::Image;
+use crate::utils::rand::random;
+use crate::vector::{UtVector, Vector};
+
+/// Radiance arriving from infinitely far away.
+#[derive(Clone, Default)]
+pub enum Background {
+    /// The default white-to-blue gradient sky.
+    #[default]
+    Sky,
+    /// An equirectangular environment map, importance-sampled by brightness.
+    Environment(Arc<EnvironmentMap>),
+}
+
+impl fmt::Debug for Background {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Background::Sky => f.write_str("Sky"),
+            Background::Environment(_) => f.write_str("Environment(..)"),
+        }
+    }
+}
+
+impl Background {
+    /// Radiance arriving from direction `dir`.
+    pub fn radiance(&self, dir: &UtVector) -> Color {
+        match self {
+            Background::Sky => {
+                let a = (dir.y() + 1.0) * 0.5;
+                let b = Color::new(0.5, 0.70196, 1.0);
+                Color::new(1.0, 1.0, 1.0) * (1.0 - a) + b * a
+            }
+            Background::Environment(map) => map.radiance(dir),
+        }
+    }
+
+    /// Density, per solid angle, with which [`Background::random_direction`] picks `dir`.
+    pub fn pdf_value(&self, dir: &UtVector) -> f64 {
+        match self {
+            Background::Sky => 1.0 / (4.0 * PI),
+            Background::Environment(map) => map.pdf_value(dir),
+        }
+    }
+
+    /// Pick a direction to sample the background in: uniformly for the sky,
+    /// and in proportion to brightness for environment maps.
+    pub fn random_direction(&self) -> UtVector {
+        match self {
+            Background::Sky => Vector::random_unit().unit(),
+            Background::Environment(map) => map.random_direction(),
+        }
+    }
+}
+
+/// A latitude-longitude image of the surroundings.
+///
+/// Row 0 looks straight up (+y) and the last row straight down. Columns wrap
+/// around the y-axis starting from +x, turning towards +z.
+pub struct EnvironmentMap {
+    image: Image,
+    /// Cumulative distribution over rows, normalized to end at 1.0.
+    marginal: Vec<f64>,
+    /// Per-row cumulative distributions over columns, each ending at 1.0.
+    conditional: Vec<Vec<f64>>,
+    /// Probability of picking each texel, row-major.
+    texel_probability: Vec<f64>,
+    /// Whether every texel is black, so the map gives off no light.
+    black: bool,
+}
+
+impl EnvironmentMap {
+    /// Build an environment map and its sampling distribution from `image`.
+    ///
+    /// An entirely black image makes a map that gives off no light and is
+    /// sampled uniformly. Returns `None` if the image is empty or its
+    /// brightness overflows.
+    pub fn new(image: Image) -> Option<Self> {
+        let (width, height) = (image.width() as usize, image.height() as usize);
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        // Weight texels by luminance and by the solid angle they cover, which
+        // shrinks towards the poles
+        let weigh = |luminance: fn(&Color) -> f64| -> Vec<f64> {
+            (0..height)
+                .flat_map(|row| {
+                    let sin_theta = (PI * (row as f64 + 0.5) / height as f64).sin();
+                    image.pixels()[row * width..(row + 1) * width]
+                        .iter()
+                        .map(move |pixel| luminance(pixel) * sin_theta)
+                })
+                .collect()
+        };
+        let mut weights = weigh(|pixel| pixel.luminance().max(0.0));
+        let mut total: f64 = weights.iter().sum();
+        if !total.is_finite() {
+            return None;
+        }
+        let black = total <= 0.0;
+        if black {
+            weights = weigh(|_| 1.0);
+            total = weights.iter().sum();
+        }
+
+        let mut marginal = Vec::with_capacity(height);
+        let mut conditional = Vec::with_capacity(height);
+        let mut running = 0.0;
+        for row in weights.chunks(width) {
+            let row_total: f64 = row.iter().sum();
+            running += row_total;
+            marginal.push(running / total);
+            conditional.push(cumulative(row, row_total));
+        }
+
+        Some(Self {
+            image,
+            marginal,
+            conditional,
+            texel_probability: weights.iter().map(|weight| weight / total).collect(),
+            black,
+        })
+    }
+
+    /// Whether every texel is black, so the map gives off no light.
+    pub fn is_black(&self) -> bool {
+        self.black
+    }
+
+    /// Radiance arriving from direction `dir`, from the nearest texel.
+    pub fn radiance(&self, dir: &UtVector) -> Color {
+        let (column, row) = self.texel(dir);
+        self.image.get(column, row).expect("texel is in bounds")
+    }
+
+    /// Density, per solid angle, with which [`EnvironmentMap::random_direction`] picks `dir`.
+    pub fn pdf_value(&self, dir: &UtVector) -> f64 {
+        let (column, row) = self.texel(dir);
+        let (width, height) = (self.image.width() as f64, self.image.height() as f64);
+        let sin_theta = (PI * (row as f64 + 0.5) / height).sin();
+        let probability = self.texel_probability[row as usize * width as usize + column as usize];
+
+        // Each texel spans (2π / width) by (π / height) radians
+        probability * width * height / (2.0 * PI * PI * sin_theta)
+    }
+
+    /// Pick a direction in proportion to the radiance arriving from it.
+    pub fn random_direction(&self) -> UtVector {
+        let (width, height) = (self.image.width() as f64, self.image.height() as f64);
+        let row = pick(&self.marginal, random());
+        let column = pick(&self.conditional[row], random());
+
+        let theta = PI * (row as f64 + random()) / height;
+        let phi = 2.0 * PI * (column as f64 + random()) / width;
+        Vector::new(
+            theta.sin() * phi.cos(),
+            theta.cos(),
+            theta.sin() * phi.sin(),
+        )
+        .unit()
+    }
+
+    /// Column and row of the texel seen in direction `dir`.
+    fn texel(&self, dir: &UtVector) -> (u32, u32) {
+        let (width, height) = (self.image.width(), self.image.height());
+        let theta = dir.y().clamp(-1.0, 1.0).acos();
+        let phi = dir.z().atan2(dir.x()).rem_euclid(2.0 * PI);
+
+        let row = ((theta / PI * height as f64) as u32).min(height - 1);
+        let column = ((phi / (2.0 * PI) * width as f64) as u32).min(width - 1);
+        (column, row)
+    }
+}
+
+/// Running sums of `weights` divided by `total`. All-zero rows get a uniform distribution.
+fn cumulative(weights: &[f64], total: f64) -> Vec<f64> {
+    let count = weights.len() as f64;
+    let mut running = 0.0;
+    weights
+        .iter()
+        .enumerate()
+        .map(|(i, weight)| {
+            if total > 0.0 {
+                running += weight;
+                running / total
+            } else {
+                (i + 1) as f64 / count
+            }
+        })
+        .collect()
+}
+
+/// Index of the first bin whose cumulative probability exceeds `u`.
+fn pick(cdf: &[f64], u: f64) -> usize {
+    cdf.partition_point(|&c| c <= u).min(cdf.len() - 1)
+}
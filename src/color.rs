@@ -1,22 +1,182 @@
 //! Module containing [`Color`] and logic for operations with it.
 
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops;
+use std::sync::OnceLock;
 
 use crate::utils::rand::{random, random_range};
+/// Range of wavelengths, in nanometers, sampled by spectral renders.
+pub const VISIBLE_WAVELENGTHS: (f64, f64) = (380.0, 780.0);
+
+/// How colors outside the displayable `[0.0, 1.0]` range are brought into it
+/// before quantizing, see [`Color::to_rgb8_mapped`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GamutMapping {
+    /// Clamp each channel on its own. Saturated highlights shift hue, e.g.
+    /// bright orange clips toward yellow.
+    #[default]
+    Clip,
+    /// Desaturate toward gray of the same luminance, just enough to fit,
+    /// keeping the hue. Colors brighter than white become white.
+    PreserveHue,
+}
+
 // Was `Copy` a good idea?
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub struct Color {
     r: f64,
     g: f64,
     b: f64,
 }
 
+/// Hashes the exact bit patterns of the channels, for render fingerprints.
+impl Hash for Color {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        [self.r, self.g, self.b].map(f64::to_bits).hash(state);
+    }
+}
+
 impl Color {
+    /// Create a new [`Color`] from linear components.
+    ///
+    /// The renderer works in linear space and only gamma-encodes on output, so
+    /// values picked in a paint program should go through [`Color::srgb`] instead.
     pub fn new(r: f64, g: f64, b: f64) -> Self {
         Color { r, g, b }
     }
 
+    /// Create a new [`Color`] from linear components. Same as [`Color::new`].
+    pub fn linear(r: f64, g: f64, b: f64) -> Self {
+        Self::new(r, g, b)
+    }
+
+    /// Create a new [`Color`] from sRGB-encoded components in `[0.0, 1.0]`,
+    /// converting them to linear space.
+    pub fn srgb(r: f64, g: f64, b: f64) -> Self {
+        let srgb_to_linear = |e: f64| {
+            if e <= 0.04045 {
+                e / 12.92
+            } else {
+                ((e + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        Color::new(srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b))
+    }
+
+    /// The linear RGB weight of a single wavelength, in nanometers.
+    ///
+    /// Weights are normalized so that averaging over wavelengths drawn
+    /// uniformly from [`VISIBLE_WAVELENGTHS`] gives white. Some saturated
+    /// wavelengths have negative channels, since they lie outside the sRGB gamut.
+    pub fn from_wavelength(wavelength: f64) -> Self {
+        static NORMALIZATION: OnceLock<Color> = OnceLock::new();
+        let normalization = NORMALIZATION.get_or_init(|| {
+            let (min, max) = VISIBLE_WAVELENGTHS;
+            let steps = 1000;
+            let step = (max - min) / steps as f64;
+            let mut sum = Color::new(0.0, 0.0, 0.0);
+            for k in 0..steps {
+                sum += wavelength_to_rgb(min + (k as f64 + 0.5) * step);
+            }
+            let mean = sum / steps as f64;
+            Color::new(1.0 / mean.r, 1.0 / mean.g, 1.0 / mean.b)
+        });
+
+        wavelength_to_rgb(wavelength) * *normalization
+    }
+
+    /// The linear RGB color of a blackbody at `kelvin`, scaled so its
+    /// brightest channel is 1.
+    ///
+    /// Uses the cubic spline fit of the Planckian locus by Kim et al. (2002),
+    /// so temperatures are clamped to its range of 1667 K to 25000 K. Candle
+    /// light is around 1900 K, incandescent bulbs 2700 K and daylight 6500 K.
+    pub fn from_kelvin(kelvin: f64) -> Self {
+        let t = kelvin.clamp(1667.0, 25000.0);
+        let (t2, t3) = (t * t, t * t * t);
+        let x = if t <= 4000.0 {
+            -0.2661239e9 / t3 - 0.2343589e6 / t2 + 0.8776956e3 / t + 0.179910
+        } else {
+            -3.0258469e9 / t3 + 2.1070379e6 / t2 + 0.2226347e3 / t + 0.240390
+        };
+        let (x2, x3) = (x * x, x * x * x);
+        let y = if t <= 2222.0 {
+            -1.1063814 * x3 - 1.34811020 * x2 + 2.18555832 * x - 0.20219683
+        } else if t <= 4000.0 {
+            -0.9549476 * x3 - 1.37418593 * x2 + 2.09137015 * x - 0.16748867
+        } else {
+            3.0817580 * x3 - 5.87338670 * x2 + 3.75112997 * x - 0.37001483
+        };
+
+        // Chromaticity to XYZ at unit luminance, then into linear sRGB
+        let (cx, cy, cz) = (x / y, 1.0, (1.0 - x - y) / y);
+        let rgb = [
+            3.2406 * cx - 1.5372 * cy - 0.4986 * cz,
+            -0.9689 * cx + 1.8758 * cy + 0.0415 * cz,
+            0.0557 * cx - 0.2040 * cy + 1.0570 * cz,
+        ]
+        .map(|channel: f64| channel.max(0.0));
+        let max = rgb[0].max(rgb[1]).max(rgb[2]);
+
+        Color::new(rgb[0] / max, rgb[1] / max, rgb[2] / max)
+    }
+
+    /// Relative luminance of the linear color, with Rec. 709 weights.
+    pub fn luminance(&self) -> f64 {
+        0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b
+    }
+
+    /// This color brought into the `[0.0, 1.0]` cube as `mapping` says.
+    pub fn gamut_mapped(&self, mapping: GamutMapping) -> Self {
+        let clamp = |e: f64| e.clamp(0.0, 1.0);
+        match mapping {
+            GamutMapping::Clip => Color::new(clamp(self.r), clamp(self.g), clamp(self.b)),
+            GamutMapping::PreserveHue => {
+                let luminance = self.luminance();
+                if luminance >= 1.0 {
+                    return Color::new(1.0, 1.0, 1.0);
+                }
+                if luminance <= 0.0 {
+                    return Color::new(0.0, 0.0, 0.0);
+                }
+                // Blending toward gray keeps the ratios of channel differences,
+                // and so the hue; use the largest blend that fits the cube
+                let (max, min) = (
+                    self.r.max(self.g).max(self.b),
+                    self.r.min(self.g).min(self.b),
+                );
+                let mut saturation: f64 = 1.0;
+                if max > 1.0 {
+                    saturation = saturation.min((1.0 - luminance) / (max - luminance));
+                }
+                if min < 0.0 {
+                    saturation = saturation.min(luminance / (luminance - min));
+                }
+                let blend = |e: f64| clamp(luminance + (e - luminance) * saturation);
+                Color::new(blend(self.r), blend(self.g), blend(self.b))
+            }
+        }
+    }
+
+    /// Whether every channel is neither NaN nor infinite.
+    pub fn is_finite(&self) -> bool {
+        self.r.is_finite() && self.g.is_finite() && self.b.is_finite()
+    }
+
+    pub fn r(&self) -> f64 {
+        self.r
+    }
+
+    pub fn g(&self) -> f64 {
+        self.g
+    }
+
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+
     pub fn random() -> Self {
         Color {
             r: random(),
@@ -32,6 +192,22 @@ impl Color {
             b: random_range(min, max),
         }
     }
+
+    /// Gamma-encode and quantize to 8 bits per channel, as written to image files.
+    pub fn to_rgb8(&self) -> [u8; 3] {
+        self.to_rgb8_mapped(GamutMapping::Clip)
+    }
+
+    /// Like [`Color::to_rgb8`], but bringing the color into gamut with `mapping`
+    /// first.
+    pub fn to_rgb8_mapped(&self, mapping: GamutMapping) -> [u8; 3] {
+        let color = self.gamut_mapped(mapping);
+        // Pray compiler optimizes this
+        let linear_to_gamma = |e: f64| if e > 0.0 { e.sqrt() } else { 0.0 };
+        let quantize = |e: f64| (255.0 * linear_to_gamma(e)) as u8;
+
+        [quantize(color.r), quantize(color.g), quantize(color.b)]
+    }
 }
 
 impl ops::Mul<f64> for Color {
@@ -85,20 +261,99 @@ impl ops::AddAssign<Color> for Color {
     }
 }
 
+impl ops::Sub<f64> for Color {
+    type Output = Color;
+    fn sub(self, rhs: f64) -> Self::Output {
+        Color::new(self.r - rhs, self.g - rhs, self.b - rhs)
+    }
+}
+
+impl ops::Sub<Color> for Color {
+    type Output = Color;
+    fn sub(self, rhs: Color) -> Self::Output {
+        Color::new(self.r - rhs.r, self.g - rhs.g, self.b - rhs.b)
+    }
+}
+
+impl ops::Neg for Color {
+    type Output = Color;
+    fn neg(self) -> Self::Output {
+        Color::new(-self.r, -self.g, -self.b)
+    }
+}
+
 impl fmt::Display for Color {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // Pray compiler optimizes this
-        let linear_to_gamma = |e: f64| if e > 0.0 { e.sqrt() } else { 0.0 };
+        // P3 PPM format
+        let [r, g, b] = self.to_rgb8();
+        write!(f, "{} {} {}", r, g, b)
+    }
+}
 
-        let r = linear_to_gamma(self.r);
-        let g = linear_to_gamma(self.g);
-        let b = linear_to_gamma(self.b);
+/// A running sum of [`Color`]s with compensated (Kahan–Babuška) summation.
+///
+/// Adding many small samples to a large total loses their low bits; this
+/// tracks the rounding error of each addition and adds it back, so the total
+/// stays accurate to about one rounding error however many samples go in.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ColorSum {
+    sum: [f64; 3],
+    compensation: [f64; 3],
+}
 
-        // P3 PPM format
-        let r = (255.0 * r.clamp(0.0, 1.0)) as u8;
-        let g = (255.0 * g.clamp(0.0, 1.0)) as u8;
-        let b = (255.0 * b.clamp(0.0, 1.0)) as u8;
+impl ColorSum {
+    /// An empty sum.
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-        write!(f, "{} {} {}", r, g, b)
+    /// Add `color` to the sum.
+    pub fn add(&mut self, color: Color) {
+        for (i, x) in [color.r, color.g, color.b].into_iter().enumerate() {
+            let sum = self.sum[i];
+            let total = sum + x;
+            self.sum[i] = total;
+            // An infinite total has no low bits to recover, and subtracting
+            // it back out would give NaN
+            if !total.is_finite() {
+                continue;
+            }
+            // Recover the low bits of whichever operand was smaller
+            self.compensation[i] += if sum.abs() >= x.abs() {
+                (sum - total) + x
+            } else {
+                (x - total) + sum
+            };
+        }
+    }
+
+    /// The sum of every color added so far.
+    pub fn total(&self) -> Color {
+        let [r, g, b] = std::array::from_fn(|i| self.sum[i] + self.compensation[i]);
+        Color::new(r, g, b)
     }
 }
+
+/// Unnormalized linear sRGB of a wavelength, from the multi-lobe fit of the
+/// CIE 1931 color matching functions by Wyman, Sloan and Shirley (2013).
+fn wavelength_to_rgb(wavelength: f64) -> Color {
+    let lobe = |mu: f64, sigma_below: f64, sigma_above: f64| {
+        let sigma = if wavelength < mu {
+            sigma_below
+        } else {
+            sigma_above
+        };
+        (-0.5 * ((wavelength - mu) / sigma).powi(2)).exp()
+    };
+
+    let x = 1.056 * lobe(599.8, 37.9, 31.0) + 0.362 * lobe(442.0, 16.0, 26.7)
+        - 0.065 * lobe(501.1, 20.4, 26.2);
+    let y = 0.821 * lobe(568.8, 46.9, 40.5) + 0.286 * lobe(530.9, 16.3, 31.1);
+    let z = 1.217 * lobe(437.0, 11.8, 36.0) + 0.681 * lobe(459.0, 26.0, 13.8);
+
+    Color::new(
+        3.2406 * x - 1.5372 * y - 0.4986 * z,
+        -0.9689 * x + 1.8758 * y + 0.0415 * z,
+        0.0557 * x - 0.2040 * y + 1.0570 * z,
+    )
+}
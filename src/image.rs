@@ -0,0 +1,384 @@
+//! Module defining [`Image`], an in-memory grid of [`Color`]s.
+//!
+//! Images can be saved losslessly with [`Image::save_raw`], e.g. to resume an
+//! accumulated render later, or in a format chosen by file extension with
+//! [`Image::save`], and P3 PPMs can be read back with [`Image::load_ppm`].
+//! With the `exr` feature enabled, linear float buffers can be
+//! saved with [`write_exr`].
+
+use std::{
+    fs,
+    io::{self, Read, Write},
+    path::Path,
+};
+
+use crate::color::{Color, GamutMapping};
+
+/// Leading bytes of every file written by [`Image::save_raw`].
+const RAW_MAGIC: &[u8; 8] = b"RTRAW\0\0\x01";
+
+/// How [`Image::blend`] combines a layer with the image beneath it.
+///
+/// All modes operate on linear color values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlendMode {
+    /// The layer replaces the base.
+    Normal,
+    /// Channels are multiplied, darkening the base.
+    Multiply,
+    /// Channels are summed, brightening the base.
+    Add,
+    /// Inverted channels are multiplied, brightening without exceeding white.
+    Screen,
+}
+
+impl BlendMode {
+    fn apply(self, base: Color, layer: Color) -> Color {
+        let screen = |a: f64, b: f64| 1.0 - (1.0 - a) * (1.0 - b);
+        match self {
+            BlendMode::Normal => layer,
+            BlendMode::Multiply => base * layer,
+            BlendMode::Add => base + layer,
+            BlendMode::Screen => Color::new(
+                screen(base.r(), layer.r()),
+                screen(base.g(), layer.g()),
+                screen(base.b(), layer.b()),
+            ),
+        }
+    }
+}
+
+/// A rectangular grid of pixels stored row by row, top to bottom.
+#[derive(Clone, Debug)]
+pub struct Image {
+    width: u32,
+    height: u32,
+    pixels: Vec<Color>,
+}
+
+impl Image {
+    /// Create a black [`Image`] of the given size.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![Color::new(0.0, 0.0, 0.0); (width * height) as usize],
+        }
+    }
+
+    /// Wrap row-major `pixels` as an [`Image`].
+    ///
+    /// Returns `None` if `pixels` does not contain exactly `width * height` entries.
+    pub fn from_pixels(width: u32, height: u32, pixels: Vec<Color>) -> Option<Self> {
+        if pixels.len() != (width * height) as usize {
+            return None;
+        }
+        Some(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &[Color] {
+        &self.pixels
+    }
+
+    pub fn into_pixels(self) -> Vec<Color> {
+        self.pixels
+    }
+
+    /// Pixel at column `x` and row `y`, or `None` if out of bounds.
+    pub fn get(&self, x: u32, y: u32) -> Option<Color> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        Some(self.pixels[(y * self.width + x) as usize])
+    }
+
+    /// Overwrite the pixel at column `x` and row `y`.
+    ///
+    /// Panics if the coordinates are out of bounds.
+    pub fn set(&mut self, x: u32, y: u32, color: Color) {
+        assert!(x < self.width && y < self.height, "pixel out of bounds");
+        self.pixels[(y * self.width + x) as usize] = color;
+    }
+
+    /// Composite `other` over this image with `mode`, mixed in by `factor`.
+    ///
+    /// A `factor` of 0.0 keeps this image and 1.0 applies the blend fully.
+    /// Panics if the images differ in size.
+    pub fn blend(&self, other: &Image, mode: BlendMode, factor: f64) -> Image {
+        assert!(
+            self.width == other.width && self.height == other.height,
+            "blended images must have the same size"
+        );
+
+        let pixels = self
+            .pixels
+            .iter()
+            .zip(&other.pixels)
+            .map(|(&base, &layer)| base * (1.0 - factor) + mode.apply(base, layer) * factor)
+            .collect();
+        Self {
+            width: self.width,
+            height: self.height,
+            pixels,
+        }
+    }
+
+    /// Root-mean-square error against `reference`, over every channel of
+    /// every pixel in linear space.
+    ///
+    /// Panics if the images differ in size.
+    pub fn rmse(&self, reference: &Image) -> f64 {
+        assert!(
+            self.width == reference.width && self.height == reference.height,
+            "compared images must have the same size"
+        );
+        if self.pixels.is_empty() {
+            return 0.0;
+        }
+
+        let squared_error: f64 = self
+            .pixels
+            .iter()
+            .zip(&reference.pixels)
+            .map(|(a, b)| {
+                (a.r() - b.r()).powi(2) + (a.g() - b.g()).powi(2) + (a.b() - b.b()).powi(2)
+            })
+            .sum();
+        (squared_error / (3 * self.pixels.len()) as f64).sqrt()
+    }
+
+    /// Peak signal-to-noise ratio against `reference` in decibels, taking 1.0
+    /// as the peak. Higher is closer; identical images give infinity.
+    ///
+    /// Panics if the images differ in size.
+    pub fn psnr(&self, reference: &Image) -> f64 {
+        -20.0 * self.rmse(reference).log10()
+    }
+
+    /// Save the image in the format named by `path`'s extension.
+    ///
+    /// `.ppm` writes a P3 PPM, gamma-encoded like [`Camera::render`](crate::Camera::render).
+    /// `.png` (with the `png` feature) writes an 8-bit sRGB PNG, and `.exr`
+    /// (with the `exr` feature) keeps linear floats. Other extensions return an
+    /// [`io::ErrorKind::Unsupported`] error.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.save_mapped(path, GamutMapping::Clip)
+    }
+
+    /// Like [`Image::save`], but bringing colors outside the displayable range
+    /// into it with `mapping` when they are converted to 8-bit channels.
+    ///
+    /// The image itself is unchanged, and EXR output keeps the linear values.
+    pub fn save_mapped<P: AsRef<Path>>(&self, path: P, mapping: GamutMapping) -> io::Result<()> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match extension.as_str() {
+            "ppm" => self.save_ppm(path, mapping),
+            #[cfg(feature = "png")]
+            "png" => self.save_png(path, mapping),
+            #[cfg(feature = "exr")]
+            "exr" => {
+                let buffer: Vec<f32> = self
+                    .pixels
+                    .iter()
+                    .flat_map(|pixel| [pixel.r() as f32, pixel.g() as f32, pixel.b() as f32])
+                    .collect();
+                write_exr(path, &buffer, self.width, self.height)
+            }
+            _ => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("unsupported image format: {}", path.display()),
+            )),
+        }
+    }
+
+    fn save_ppm(&self, path: &Path, mapping: GamutMapping) -> io::Result<()> {
+        let mut file = io::BufWriter::new(fs::File::create(path)?);
+        write_ppm_header(&mut file, self.width, self.height, None)?;
+        for pixel in &self.pixels {
+            write_ppm_pixel(&mut file, pixel, mapping)?;
+        }
+        file.flush()
+    }
+
+    #[cfg(feature = "png")]
+    fn save_png(&self, path: &Path, mapping: GamutMapping) -> io::Result<()> {
+        let file = io::BufWriter::new(fs::File::create(path)?);
+        let mut encoder = png::Encoder::new(file, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let data: Vec<u8> = self
+            .pixels
+            .iter()
+            .flat_map(|pixel| pixel.to_rgb8_mapped(mapping))
+            .collect();
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&data))
+            .map_err(io::Error::other)
+    }
+
+    /// Load a P3 PPM, such as one written by [`Image::save`], undoing its
+    /// gamma encoding.
+    ///
+    /// The 8-bit channels only approximate the original colors, but saving
+    /// the loaded image again writes the same file. Comment lines are skipped.
+    /// Returns an [`io::ErrorKind::InvalidData`] error for anything else.
+    pub fn load_ppm<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let invalid = |message| io::Error::new(io::ErrorKind::InvalidData, message);
+        let text = fs::read_to_string(path)?;
+        let mut tokens = text
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .flat_map(str::split_whitespace);
+
+        if tokens.next() != Some("P3") {
+            return Err(invalid("not a P3 PPM"));
+        }
+        let mut numbers = tokens.map(|token| token.parse::<u32>());
+        let mut next = || match numbers.next() {
+            Some(Ok(number)) => Ok(number),
+            _ => Err(invalid("malformed or truncated PPM")),
+        };
+        let (width, height, max) = (next()?, next()?, next()?);
+        if max != 255 {
+            return Err(invalid("only 8-bit PPMs are supported"));
+        }
+
+        // The middle of each quantization step, squared to undo the gamma,
+        // encodes back to the same value
+        let decode = |value: u32| ((value.min(255) as f64 + 0.5) / 255.0).min(1.0).powi(2);
+        let pixels = (0..width as usize * height as usize)
+            .map(|_| {
+                let [r, g, b] = [next()?, next()?, next()?];
+                Ok(Color::new(decode(r), decode(g), decode(b)))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        Self::from_pixels(width, height, pixels).ok_or_else(|| invalid("empty PPM"))
+    }
+
+    /// Save the exact channel values along with `samples`, the number of
+    /// samples they hold, so accumulation can pick up where it left off.
+    ///
+    /// The format is a small header followed by little-endian `f64`s, row by row.
+    pub fn save_raw<P: AsRef<Path>>(&self, path: P, samples: u32) -> io::Result<()> {
+        let mut bytes = Vec::with_capacity(RAW_MAGIC.len() + 12 + self.pixels.len() * 24);
+        bytes.extend_from_slice(RAW_MAGIC);
+        for header in [self.width, self.height, samples] {
+            bytes.extend_from_slice(&header.to_le_bytes());
+        }
+        for pixel in &self.pixels {
+            for channel in [pixel.r(), pixel.g(), pixel.b()] {
+                bytes.extend_from_slice(&channel.to_le_bytes());
+            }
+        }
+        fs::File::create(path)?.write_all(&bytes)
+    }
+
+    /// Load an image written by [`Image::save_raw`], with its sample count.
+    ///
+    /// Returns an [`io::ErrorKind::InvalidData`] error if the file is not a
+    /// raw image or is truncated.
+    pub fn load_raw<P: AsRef<Path>>(path: P) -> io::Result<(Self, u32)> {
+        let invalid = |message| io::Error::new(io::ErrorKind::InvalidData, message);
+        let mut bytes = Vec::new();
+        fs::File::open(path)?.read_to_end(&mut bytes)?;
+
+        let body = bytes
+            .strip_prefix(RAW_MAGIC)
+            .ok_or_else(|| invalid("not a raw image"))?;
+        if body.len() < 12 {
+            return Err(invalid("raw image header is truncated"));
+        }
+        let (header, body) = body.split_at(12);
+        let [width, height, samples] = [0, 4, 8].map(|offset| {
+            u32::from_le_bytes(header[offset..offset + 4].try_into().expect("4 bytes"))
+        });
+        if body.len() != width as usize * height as usize * 24 {
+            return Err(invalid("raw image size does not match its header"));
+        }
+
+        let channels = body
+            .chunks_exact(8)
+            .map(|channel| f64::from_le_bytes(channel.try_into().expect("8 bytes")))
+            .collect::<Vec<_>>();
+        let pixels = channels
+            .chunks_exact(3)
+            .map(|rgb| Color::new(rgb[0], rgb[1], rgb[2]))
+            .collect();
+        Ok((
+            Self::from_pixels(width, height, pixels).expect("size checked above"),
+            samples,
+        ))
+    }
+}
+
+/// Write the header of a `width` by `height` P3 PPM, with an optional
+/// `comment` line after the magic number.
+pub(crate) fn write_ppm_header(
+    out: &mut impl Write,
+    width: u32,
+    height: u32,
+    comment: Option<&str>,
+) -> io::Result<()> {
+    writeln!(out, "P3")?;
+    if let Some(comment) = comment {
+        writeln!(out, "# {comment}")?;
+    }
+    writeln!(out, "{width} {height}")?;
+    writeln!(out, "255")
+}
+
+/// Write `pixel` as one line of a P3 PPM body, mapped into gamut with `mapping`.
+pub(crate) fn write_ppm_pixel(
+    out: &mut impl Write,
+    pixel: &Color,
+    mapping: GamutMapping,
+) -> io::Result<()> {
+    let [r, g, b] = pixel.to_rgb8_mapped(mapping);
+    writeln!(out, "{r} {g} {b}")
+}
+
+/// Write a linear RGB float buffer, as returned by
+/// [`Camera::render_to_f32`](crate::Camera::render_to_f32), to an OpenEXR file.
+///
+/// `buffer` holds 3 floats per pixel, row by row. Returns an
+/// [`io::ErrorKind::InvalidInput`] error if it does not hold exactly
+/// `width * height` pixels.
+#[cfg(feature = "exr")]
+pub fn write_exr<P: AsRef<Path>>(
+    path: P,
+    buffer: &[f32],
+    width: u32,
+    height: u32,
+) -> io::Result<()> {
+    let (width, height) = (width as usize, height as usize);
+    if buffer.len() != width * height * 3 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "buffer must hold 3 floats for every pixel",
+        ));
+    }
+
+    exr::prelude::write_rgb_file(path, width, height, |x, y| {
+        let i = 3 * (y * width + x);
+        (buffer[i], buffer[i + 1], buffer[i + 2])
+    })
+    .map_err(io::Error::other)
+}
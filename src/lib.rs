@@ -1,16 +1,37 @@
 //! Re-exports
 
+pub mod background;
 pub mod color;
+pub mod image;
 pub mod materials;
 pub mod objects;
+pub mod presets;
 pub mod ray;
 pub mod scene;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod textures;
 pub mod vector;
 
 pub use objects::HittableList;
 pub use scene::{
-    Camera, CameraConfig, CameraModel, CameraPose, ConfigError, ImageOptions, PerspectiveProjection,
+    Camera, CameraConfig, CameraModel, CameraPose, ConfigError, ImageOptions,
+    PerspectiveProjection, RenderError, Renderer,
 };
+pub use utils::interval::Interval;
 pub use vector::Point;
 
+/// Seed the random number generator of the calling thread.
+///
+/// All sampling (antialiasing jitter, scattering, Fresnel choices in glass and
+/// so on) draws from a per-thread generator, so work traced on this thread,
+/// such as [`Ray::color`](ray::Ray::color), repeats exactly after the same
+/// seed. Parallel renders run on other threads, which keep their own
+/// generators and are not affected; use
+/// [`RenderOptions::seed`](scene::RenderOptions::seed) to make them repeat.
+pub fn seed_thread_rng(seed: u64) {
+    utils::rand::seed(seed);
+}
+
+mod macros;
 mod utils;
@@ -0,0 +1,24 @@
+//! Macros for building scenes.
+
+/// Build a [`HittableList`](crate::HittableList) holding each of the given
+/// objects, in order.
+///
+/// ```
+/// use ray_tracing_rs::{color::Color, hittable_list, materials::Lambertian, objects::Sphere, Point};
+///
+/// let material = Lambertian::new(Color::new(0.8, 0.8, 0.0));
+/// let world = hittable_list![
+///     Sphere::new(Point::new(0.0, 0.0, -1.0), 0.5, material.clone()),
+///     Sphere::new(Point::new(0.0, -100.5, -1.0), 100.0, material),
+/// ];
+/// assert_eq!(world.objects().len(), 2);
+/// ```
+#[macro_export]
+macro_rules! hittable_list {
+    ($($object:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut list = $crate::HittableList::new();
+        $(list.add($object);)*
+        list
+    }};
+}
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use super::Dielectric;
+use super::EmergentRay;
+use super::Material;
+use super::RayInteraction;
+
+use crate::color::Color;
+use crate::objects::HitRecord;
+use crate::ray::Ray;
+use crate::utils;
+use crate::utils::rand::random;
+
+/// A thin, clear dielectric layer over another material, like varnish or car paint.
+///
+/// Each hit either reflects specularly off the coat, with the coat's Fresnel
+/// reflectance as probability, or passes through to the base material. The
+/// coat is treated as infinitely thin, so it neither refracts nor absorbs.
+#[derive(Clone)]
+pub struct Clearcoat {
+    base: Arc<dyn Material>,
+    ior: f64,
+}
+
+impl Clearcoat {
+    /// Coat `base` with a layer of index of refraction `ior` (about 1.5 for lacquer).
+    pub fn new(base: impl Material + 'static, ior: f64) -> Self {
+        Self {
+            base: Arc::new(base),
+            ior,
+        }
+    }
+}
+
+impl Material for Clearcoat {
+    fn interact<'a>(&self, ray: &Ray, record: &'a HitRecord) -> RayInteraction<'a> {
+        let cos_theta = (-ray.dir()).dot(&record.normal).clamp(0.0, 1.0);
+        if Dielectric::reflectance(cos_theta, self.ior) > random() {
+            return RayInteraction::Scattered(EmergentRay {
+                inner: ray.child(&record.point, ray.dir().reflect(&record.normal)),
+                attenuation: Color::new(1.0, 1.0, 1.0),
+            });
+        }
+        self.base.interact(ray, record)
+    }
+
+    fn shade_back_faces(&self) -> bool {
+        self.base.shade_back_faces()
+    }
+
+    fn emitted(&self, ray: &Ray, record: &HitRecord) -> Color {
+        self.base.emitted(ray, record)
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "Clearcoat",
+            self.base.fingerprint()?,
+            self.ior.to_bits(),
+        )))
+    }
+}
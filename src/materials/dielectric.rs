@@ -1,52 +1,88 @@
-use crate::utils::rand::random;
-
 use super::EmergentRay;
 use super::Material;
 use super::RayInteraction;
+use super::schlick;
 
 use crate::color::Color;
 use crate::objects::HitRecord;
 use crate::ray::Ray;
+use crate::utils;
+use crate::utils::rand;
+
+/// Wavelength, in nanometers, at which [`Dielectric::cauchy`] glass is
+/// evaluated for rays that carry no wavelength (the Fraunhofer d-line).
+const REFERENCE_WAVELENGTH: f64 = 587.6;
 
 pub struct Dielectric {
     ior: f64,
+    /// Cauchy `B` coefficient in µm², or 0.0 for a wavelength-independent IOR.
+    dispersion: f64,
 }
 
 impl Dielectric {
     pub fn new(ior: f64) -> Self {
-        Self { ior }
+        Self {
+            ior,
+            dispersion: 0.0,
+        }
+    }
+
+    /// Dispersive glass whose IOR follows Cauchy's equation `a + b / λ²`, with
+    /// `λ` in micrometers. Crown glass is roughly `a = 1.5046, b = 0.0042`.
+    ///
+    /// Dispersion is only visible in spectral renders, where rays carry a
+    /// wavelength; other rays use the IOR at 587.6 nm.
+    pub fn cauchy(a: f64, b: f64) -> Self {
+        Self {
+            ior: a,
+            dispersion: b,
+        }
+    }
+
+    /// The index of refraction for light of `wavelength` nanometers.
+    pub fn ior_at(&self, wavelength: Option<f64>) -> f64 {
+        let micrometers = wavelength.unwrap_or(REFERENCE_WAVELENGTH) / 1000.0;
+        self.ior + self.dispersion / (micrometers * micrometers)
     }
 
-    fn reflectance(cosine: f64, ior: f64) -> f64 {
-        // Shlick's approximation for reflectance
-        let mut r0 = (1.0 - ior) / (1.0 + ior);
-        r0 = r0 * r0;
-        r0 + (1.0 - r0) * (1.0 - cosine).powi(5)
+    pub(super) fn reflectance(cosine: f64, ior: f64) -> f64 {
+        let r0 = ((1.0 - ior) / (1.0 + ior)).powi(2);
+        schlick(cosine, r0)
     }
 }
 
 impl Material for Dielectric {
     fn interact<'a>(&self, ray: &Ray, record: &'a HitRecord) -> RayInteraction<'a> {
+        let material_ior = self.ior_at(ray.wavelength());
         let ior = if record.front_face {
-            1.0 / self.ior
+            1.0 / material_ior
         } else {
-            self.ior
+            material_ior
         };
 
         let incident = ray.dir();
 
-        let cos_theta = (-incident).dot(record.normal.inner()).min(1.0);
+        let cos_theta = (-incident).dot(&record.normal).min(1.0);
         let sin_theta = (1.0 - cos_theta * cos_theta).sqrt();
-        let direction = if ior * sin_theta > 1.0 || Self::reflectance(cos_theta, ior) > random() {
-            // TIR
-            incident.reflect(&record.normal)
-        } else {
-            incident.refract(&record.normal, ior)
-        };
+        let direction =
+            if ior * sin_theta > 1.0 || Self::reflectance(cos_theta, ior) > rand::random() {
+                // TIR
+                incident.reflect(&record.normal)
+            } else {
+                incident.refract(&record.normal, ior)
+            };
 
         RayInteraction::Scattered(EmergentRay {
-            inner: Ray::new(&record.point, direction),
+            inner: ray.child(&record.point, direction),
             attenuation: Color::new(1.0, 1.0, 1.0),
         })
     }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "Dielectric",
+            self.ior.to_bits(),
+            self.dispersion.to_bits(),
+        )))
+    }
 }
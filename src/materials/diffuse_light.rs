@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use super::Material;
+use super::RayInteraction;
+
+use crate::color::Color;
+use crate::objects::HitRecord;
+use crate::ray::Ray;
+use crate::textures::Texture;
+use crate::utils;
+
+/// A light-emitting surface that does not scatter incoming rays.
+#[derive(Clone)]
+pub struct DiffuseLight {
+    emission: Emission,
+}
+
+#[derive(Clone)]
+enum Emission {
+    Constant(Color),
+    Animated(Arc<dyn Fn(f64) -> Color + Send + Sync>),
+    Textured(Arc<dyn Texture>),
+}
+
+impl DiffuseLight {
+    pub fn new(emit: Color) -> Self {
+        Self {
+            emission: Emission::Constant(emit),
+        }
+    }
+
+    /// Create a light whose emission depends on [`Ray::time`], e.g. to make it
+    /// pulse across animation frames.
+    ///
+    /// ```ignore
+    /// # use ray_tracing_rs::{color::Color, materials::DiffuseLight};
+    /// let pulsing = DiffuseLight::animated(|t| Color::new(1.0, 1.0, 1.0) * (4.0 * t));
+    /// ```
+    pub fn animated(emit: impl Fn(f64) -> Color + Send + Sync + 'static) -> Self {
+        Self {
+            emission: Emission::Animated(Arc::new(emit)),
+        }
+    }
+
+    /// Create a light whose emission is read from `texture` at the hit's
+    /// (u, v), e.g. a glowing screen showing a pattern.
+    pub fn textured(texture: impl Texture + 'static) -> Self {
+        Self {
+            emission: Emission::Textured(Arc::new(texture)),
+        }
+    }
+}
+
+impl Material for DiffuseLight {
+    fn interact<'a>(&self, _ray: &Ray, _record: &'a HitRecord) -> RayInteraction<'a> {
+        RayInteraction::Absorbed
+    }
+
+    fn emitted(&self, ray: &Ray, record: &HitRecord) -> Color {
+        match &self.emission {
+            Emission::Constant(emit) => *emit,
+            Emission::Animated(emit) => emit(ray.time()),
+            Emission::Textured(texture) => {
+                let (u, v) = record.uv();
+                texture.value_filtered(u, v, &record.point(), ray.uv_footprint(record))
+            }
+        }
+    }
+
+    /// Animated lights depend on an opaque closure, so only constant ones have a fingerprint.
+    fn fingerprint(&self) -> Option<u64> {
+        match &self.emission {
+            Emission::Constant(emit) => Some(utils::fingerprint(("DiffuseLight", emit))),
+            Emission::Animated(_) => None,
+            Emission::Textured(texture) => {
+                Some(utils::fingerprint(("DiffuseLight", texture.fingerprint()?)))
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use super::EmergentRay;
+use super::Material;
+use super::RayInteraction;
+use super::schlick;
+
+use crate::color::Color;
+use crate::objects::HitRecord;
+use crate::ray::Ray;
+use crate::utils;
+use crate::vector::Vector;
+
+/// A conductor whose reflectance rises towards white at grazing angles.
+///
+/// `albedo` is the reflectance at normal incidence. Each channel follows
+/// Schlick's approximation from there, so edges brighten like real metals.
+#[derive(Clone)]
+pub struct FresnelMetal {
+    albedo: Color,
+    roughness: f64,
+}
+
+impl FresnelMetal {
+    pub fn new(albedo: Color, roughness: f64) -> Self {
+        Self { albedo, roughness }
+    }
+
+    /// Reflectance for light arriving at an angle whose cosine is `cos_theta`.
+    pub fn reflectance(&self, cos_theta: f64) -> Color {
+        Color::new(
+            schlick(cos_theta, self.albedo.r()),
+            schlick(cos_theta, self.albedo.g()),
+            schlick(cos_theta, self.albedo.b()),
+        )
+    }
+}
+
+impl Material for FresnelMetal {
+    fn interact<'a>(&self, ray: &Ray, record: &'a HitRecord) -> RayInteraction<'a> {
+        let cos_theta = (-ray.dir()).dot(&record.normal).clamp(0.0, 1.0);
+        let reflected_direction = (ray.dir().reflect(&record.normal).unit()
+            + (Vector::random_unit() * self.roughness))
+            .unit();
+        if reflected_direction.dot(&record.normal) < 0.0 {
+            return RayInteraction::Absorbed;
+        }
+        let reflected_ray = ray.child(&record.point, reflected_direction);
+        RayInteraction::Scattered(EmergentRay {
+            attenuation: self.reflectance(cos_theta),
+            inner: reflected_ray,
+        })
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "FresnelMetal",
+            self.albedo,
+            self.roughness.to_bits(),
+        )))
+    }
+}
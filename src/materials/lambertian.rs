@@ -1,3 +1,6 @@
+use std::f64::consts::PI;
+use std::sync::Arc;
+
 use super::EmergentRay;
 use super::Material;
 use super::RayInteraction;
@@ -5,29 +8,67 @@ use super::RayInteraction;
 use crate::color::Color;
 use crate::objects::HitRecord;
 use crate::ray::Ray;
-use crate::vector::Vector;
+use crate::textures::{SolidColor, Texture};
+use crate::utils;
+use crate::vector::{UtVector, Vector};
 
 #[derive(Clone)]
 pub struct Lambertian {
-    albedo: Color,
+    albedo: Arc<dyn Texture>,
+    two_sided: bool,
 }
 
 impl Lambertian {
     pub fn new(albedo: Color) -> Self {
-        Self { albedo }
+        Self::textured(SolidColor::new(albedo))
+    }
+
+    /// Create a [`Lambertian`] whose albedo is sampled from a texture at each hit.
+    pub fn textured(albedo: impl Texture + 'static) -> Self {
+        Self {
+            albedo: Arc::new(albedo),
+            two_sided: true,
+        }
+    }
+
+    /// Whether back faces are shaded like front faces (the default). One-sided
+    /// materials render back faces black, which suits thin open surfaces.
+    pub fn two_sided(mut self, two_sided: bool) -> Self {
+        self.two_sided = two_sided;
+        self
     }
 }
 
 impl Material for Lambertian {
-    fn interact<'a>(&self, _ray: &Ray, record: &'a HitRecord) -> RayInteraction<'a> {
+    fn shade_back_faces(&self) -> bool {
+        self.two_sided
+    }
+
+    /// Scattering is cosine-weighted about the normal.
+    fn scattering_pdf(&self, _ray: &Ray, record: &HitRecord, dir: &UtVector) -> Option<f64> {
+        Some(record.normal.dot(dir).max(0.0) / PI)
+    }
+
+    fn interact<'a>(&self, ray: &Ray, record: &'a HitRecord) -> RayInteraction<'a> {
         // Non-Lambertian implementation:
         // let direction = &record.normal + &Vector::random_on_hemisphere(&record.normal);
 
-        let scatter_direction = (record.normal.inner() + Vector::random_unit()).unit();
-        let scattered_ray = Ray::new(&record.point, scatter_direction);
+        let scatter_direction = (record.normal + Vector::random_unit()).unit();
+        let scattered_ray = ray.child(&record.point, scatter_direction);
+        let (u, v) = record.uv();
         RayInteraction::Scattered(EmergentRay {
-            attenuation: self.albedo,
+            attenuation: self
+                .albedo
+                .value_filtered(u, v, &record.point, ray.uv_footprint(record)),
             inner: scattered_ray,
         })
     }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "Lambertian",
+            self.albedo.fingerprint()?,
+            self.two_sided,
+        )))
+    }
 }
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use super::Material;
+use super::RayInteraction;
+
+use crate::color::Color;
+use crate::objects::HitRecord;
+use crate::ray::Ray;
+use crate::utils;
+use crate::utils::rand::random;
+use crate::vector::UtVector;
+
+/// Two materials split by latitude, like a planet with ice caps.
+///
+/// Latitude is the hit's [`height`](HitRecord::height) within its object, which
+/// runs from 0.0 at the object's lowest point to 1.0 at its highest.
+/// Above `boundary` the surface is `top`, below it `bottom`, and within
+/// `softness` of it each hit picks one at random, weighted by a smoothstep.
+#[derive(Clone)]
+pub struct LatitudeBlend {
+    top: Arc<dyn Material>,
+    bottom: Arc<dyn Material>,
+    boundary: f64,
+    softness: f64,
+}
+
+impl LatitudeBlend {
+    /// Use `top` above latitude `boundary` and `bottom` below it, blending over
+    /// a band `softness` wide. Both are fractions of the object's height.
+    pub fn new(
+        top: impl Material + 'static,
+        bottom: impl Material + 'static,
+        boundary: f64,
+        softness: f64,
+    ) -> Self {
+        Self {
+            top: Arc::new(top),
+            bottom: Arc::new(bottom),
+            boundary,
+            softness: softness.max(0.0),
+        }
+    }
+
+    /// How much of `top` is used at `record`, from 0.0 to 1.0.
+    fn top_weight(&self, record: &HitRecord) -> f64 {
+        let height = record.height();
+        if self.softness == 0.0 {
+            return if height >= self.boundary { 1.0 } else { 0.0 };
+        }
+        let x = ((height - self.boundary) / self.softness + 0.5).clamp(0.0, 1.0);
+        x * x * (3.0 - 2.0 * x)
+    }
+
+    /// The material used at `record`, if the hit is outside the blend band.
+    fn only(&self, record: &HitRecord) -> Option<&Arc<dyn Material>> {
+        match self.top_weight(record) {
+            1.0 => Some(&self.top),
+            0.0 => Some(&self.bottom),
+            _ => None,
+        }
+    }
+}
+
+impl Material for LatitudeBlend {
+    fn interact<'a>(&self, ray: &Ray, record: &'a HitRecord) -> RayInteraction<'a> {
+        if random() < self.top_weight(record) {
+            self.top.interact(ray, record)
+        } else {
+            self.bottom.interact(ray, record)
+        }
+    }
+
+    /// Inside the blend band the scattering material is chosen at random, so
+    /// no single density describes it and light sampling is skipped there.
+    fn scattering_pdf(&self, ray: &Ray, record: &HitRecord, dir: &UtVector) -> Option<f64> {
+        self.only(record)?.scattering_pdf(ray, record, dir)
+    }
+
+    fn shade_back_faces(&self) -> bool {
+        self.top.shade_back_faces() || self.bottom.shade_back_faces()
+    }
+
+    fn emitted(&self, ray: &Ray, record: &HitRecord) -> Color {
+        let weight = self.top_weight(record);
+        self.top.emitted(ray, record) * weight + self.bottom.emitted(ray, record) * (1.0 - weight)
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "LatitudeBlend",
+            self.top.fingerprint()?,
+            self.bottom.fingerprint()?,
+            self.boundary.to_bits(),
+            self.softness.to_bits(),
+        )))
+    }
+}
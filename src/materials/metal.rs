@@ -1,3 +1,6 @@
+use std::f64::consts::PI;
+use std::sync::Arc;
+
 use super::EmergentRay;
 use super::Material;
 use super::RayInteraction;
@@ -5,32 +8,96 @@ use super::RayInteraction;
 use crate::color::Color;
 use crate::objects::HitRecord;
 use crate::ray::Ray;
-use crate::vector::Vector;
+use crate::textures::{SolidColor, Texture};
+use crate::utils;
+use crate::vector::{UtVector, Vector};
 
 #[derive(Clone)]
 pub struct Metal {
     albedo: Color,
-    roughness: f64,
+    roughness: Arc<dyn Texture>,
 }
 
 impl Metal {
     pub fn new(albedo: Color, roughness: f64) -> Self {
-        Self { albedo, roughness }
+        Self::with_roughness_texture(albedo, SolidColor::scalar(roughness))
+    }
+
+    /// Create a [`Metal`] whose roughness varies over its surface.
+    ///
+    /// The roughness at a hit is the mean of the texture's channels, so a gray
+    /// texture maps directly to the scalar roughness used by [`Metal::new`].
+    pub fn with_roughness_texture(albedo: Color, roughness: impl Texture + 'static) -> Self {
+        Self {
+            albedo,
+            roughness: Arc::new(roughness),
+        }
+    }
+
+    /// Roughness at the hit `record`, filtered over the footprint of `ray`.
+    fn roughness_at(&self, ray: &Ray, record: &HitRecord) -> f64 {
+        let (u, v) = record.uv();
+        let sample = self
+            .roughness
+            .value_filtered(u, v, &record.point, ray.uv_footprint(record));
+        (sample.r() + sample.g() + sample.b()) / 3.0
     }
 }
 
 impl Material for Metal {
     fn interact<'a>(&self, ray: &Ray, record: &'a HitRecord) -> RayInteraction<'a> {
-        let reflected_direction = (ray.dir().reflect(&record.normal).unit()
-            + (Vector::random_unit() * self.roughness))
-            .unit();
+        let roughness = self.roughness_at(ray, record);
+
+        let reflected_direction =
+            (ray.dir().reflect(&record.normal).unit() + (Vector::random_unit() * roughness)).unit();
         if reflected_direction.dot(&record.normal) < 0.0 {
             return RayInteraction::Absorbed;
         }
-        let reflected_ray = Ray::new(&record.point, reflected_direction);
+        let reflected_ray = ray.child(&record.point, reflected_direction);
         RayInteraction::Scattered(EmergentRay {
             attenuation: self.albedo,
             inner: reflected_ray,
         })
     }
+
+    /// Rough metal scatters towards the mirror direction `r` pushed by a
+    /// random point on a sphere of radius `roughness` around it. Directions
+    /// below the surface are absorbed, so they have no density. Smooth metal
+    /// is a mirror and has no density at all.
+    fn scattering_pdf(&self, ray: &Ray, record: &HitRecord, dir: &UtVector) -> Option<f64> {
+        let roughness = self.roughness_at(ray, record);
+        if roughness <= 0.0 {
+            return None;
+        }
+        if dir.dot(&record.normal) < 0.0 {
+            return Some(0.0);
+        }
+
+        // `dir` meets the sphere at distances t solving
+        // t² - 2t(dir·r) + 1 - roughness² = 0. The sphere's area element
+        // dA maps to solid angle as dω = dA |cos α| / t², with
+        // |cos α| = √discriminant / roughness, and the sphere is sampled
+        // uniformly with density 1 / (4π roughness²)
+        let mirror = ray.dir().reflect(&record.normal).unit();
+        let along = dir.dot(&mirror);
+        let discriminant = along * along - 1.0 + roughness * roughness;
+        if discriminant <= 0.0 {
+            return Some(0.0);
+        }
+        let root = discriminant.sqrt().max(1e-12);
+        let density = [along - root, along + root]
+            .into_iter()
+            .filter(|&t| t > 0.0)
+            .map(|t| t * t / (4.0 * PI * roughness * root))
+            .sum();
+        Some(density)
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "Metal",
+            self.albedo,
+            self.roughness.fingerprint()?,
+        )))
+    }
 }
@@ -1,18 +1,45 @@
 //! Module containing [`Material`] trait, and implementors of it, such as:
 //! * [`Lambertian`]
 //! * [`Metal`]
+//! * [`FresnelMetal`]
+//! * [`DiffuseLight`]
+//! * [`ThinFilm`]
+//!
+//! [`NormalMapped`] wraps another material to add surface detail from a normal map,
+//! and [`Clearcoat`] layers a glossy dielectric coat over one. [`ShadowCatcher`]
+//! surfaces only show the shadows cast onto them, and [`LatitudeBlend`] splits a
+//! surface between two materials by latitude.
+//!
+//! Named materials can be shared between objects through a [`MaterialRegistry`].
 
+pub mod clearcoat;
 pub mod dielectric;
+pub mod diffuse_light;
+pub mod fresnel_metal;
 pub mod lambertian;
+pub mod latitude_blend;
 pub mod metal;
+pub mod normal_mapped;
+pub mod registry;
+pub mod shadow_catcher;
+pub mod thin_film;
 
+pub use clearcoat::Clearcoat;
 pub use dielectric::Dielectric;
+pub use diffuse_light::DiffuseLight;
+pub use fresnel_metal::FresnelMetal;
 pub use lambertian::Lambertian;
+pub use latitude_blend::LatitudeBlend;
 pub use metal::Metal;
+pub use normal_mapped::NormalMapped;
+pub use registry::MaterialRegistry;
+pub use shadow_catcher::ShadowCatcher;
+pub use thin_film::ThinFilm;
 
 use crate::color::Color;
 use crate::objects::HitRecord;
 use crate::ray::Ray;
+use crate::vector::UtVector;
 
 pub enum RayInteraction<'a> {
     Absorbed,
@@ -24,6 +51,65 @@ pub struct EmergentRay<'a> {
     pub(crate) attenuation: Color,
 }
 
+impl<'a> EmergentRay<'a> {
+    /// The scattered ray.
+    pub fn ray(&self) -> &Ray<'a> {
+        &self.inner
+    }
+
+    /// How much each channel of the scattered ray's color is kept.
+    pub fn attenuation(&self) -> Color {
+        self.attenuation
+    }
+}
+
+/// Schlick's approximation of Fresnel reflectance, given the reflectance `r0`
+/// at normal incidence and the cosine of the incident angle.
+pub(crate) fn schlick(cosine: f64, r0: f64) -> f64 {
+    r0 + (1.0 - r0) * (1.0 - cosine).powi(5)
+}
+
 pub trait Material: Send + Sync {
     fn interact<'a>(&self, ray: &Ray, record: &'a HitRecord) -> RayInteraction<'a>;
+
+    /// Density, per solid angle, with which [`Material::interact`] scatters
+    /// towards `dir`.
+    ///
+    /// Returns `None` for materials that scatter into a single discrete
+    /// direction (mirrors, glass), which cannot be combined with light
+    /// sampling. Implementors returning `Some` must make the attenuation from
+    /// `interact` equal their response towards the sampled direction divided
+    /// by this density, independent of the direction chosen.
+    fn scattering_pdf(&self, _ray: &Ray, _record: &HitRecord, _dir: &UtVector) -> Option<f64> {
+        None
+    }
+
+    /// Whether hits on the back of a surface are shaded. When `false`, rays
+    /// that hit a back face see black instead of interacting.
+    fn shade_back_faces(&self) -> bool {
+        true
+    }
+
+    /// Whether the surface is a [`ShadowCatcher`], which the renderer darkens
+    /// where light is blocked instead of shading through [`Material::interact`].
+    fn catches_shadows(&self) -> bool {
+        false
+    }
+
+    /// Light given off by the surface at `record`. Most materials emit nothing.
+    fn emitted(&self, _ray: &Ray, _record: &HitRecord) -> Color {
+        Color::new(0.0, 0.0, 0.0)
+    }
+
+    /// A hash of the material's parameters, or `None`, the default, if it cannot
+    /// be fingerprinted. See [`Hittable::fingerprint`](crate::objects::Hittable::fingerprint).
+    fn fingerprint(&self) -> Option<u64> {
+        None
+    }
+
+    /// Name of the material's type, for diagnostics such as
+    /// [`RenderOptions::log_bounces`](crate::scene::RenderOptions::log_bounces).
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
 }
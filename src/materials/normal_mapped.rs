@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use super::EmergentRay;
+use super::Material;
+use super::RayInteraction;
+
+use crate::color::Color;
+use crate::objects::HitRecord;
+use crate::ray::Ray;
+use crate::textures::Texture;
+use crate::utils;
+use crate::vector::{UtVector, Vector};
+
+/// Wraps another material, bending the shading normal with a normal map.
+///
+/// Each texel's (r, g, b) in `[0.0, 1.0]` encodes a tangent-space normal as
+/// `2 * color - 1`, with blue pointing out of the surface, so a flat map is
+/// `(0.5, 0.5, 1.0)`. The tangent frame is [`HitRecord::tangent_frame`], so the
+/// map has no fixed orientation along the surface.
+#[derive(Clone)]
+pub struct NormalMapped {
+    inner: Arc<dyn Material>,
+    normal_map: Arc<dyn Texture>,
+}
+
+impl NormalMapped {
+    pub fn new(inner: impl Material + 'static, normal_map: impl Texture + 'static) -> Self {
+        Self {
+            inner: Arc::new(inner),
+            normal_map: Arc::new(normal_map),
+        }
+    }
+
+    /// The shading normal at `record` after applying the normal map.
+    pub fn normal(&self, record: &HitRecord) -> UtVector {
+        let (u, v) = record.uv();
+        let texel = self.normal_map.value(u, v, &record.point());
+        let local = Vector::new(
+            2.0 * texel.r() - 1.0,
+            2.0 * texel.g() - 1.0,
+            2.0 * texel.b() - 1.0,
+        );
+        record.tangent_frame().transform(&local).unit()
+    }
+
+    fn perturbed(&self, record: &HitRecord) -> HitRecord {
+        record.with_normal(self.normal(record))
+    }
+}
+
+impl Material for NormalMapped {
+    fn interact<'a>(&self, ray: &Ray, record: &'a HitRecord) -> RayInteraction<'a> {
+        let perturbed = self.perturbed(record);
+        match self.inner.interact(ray, &perturbed) {
+            RayInteraction::Absorbed => RayInteraction::Absorbed,
+            // Re-root the scattered ray on `record`, which outlives the perturbed copy.
+            RayInteraction::Scattered(emergent) => RayInteraction::Scattered(EmergentRay {
+                inner: emergent.inner.child(&record.point, *emergent.inner.dir()),
+                attenuation: emergent.attenuation,
+            }),
+        }
+    }
+
+    fn scattering_pdf(&self, ray: &Ray, record: &HitRecord, dir: &UtVector) -> Option<f64> {
+        self.inner.scattering_pdf(ray, &self.perturbed(record), dir)
+    }
+
+    fn shade_back_faces(&self) -> bool {
+        self.inner.shade_back_faces()
+    }
+
+    fn emitted(&self, ray: &Ray, record: &HitRecord) -> Color {
+        self.inner.emitted(ray, &self.perturbed(record))
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "NormalMapped",
+            self.inner.fingerprint()?,
+            self.normal_map.fingerprint()?,
+        )))
+    }
+}
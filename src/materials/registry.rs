@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::Material;
+
+/// A table of named, shareable materials.
+///
+/// Objects built from the same registry entry share a single allocation, so a
+/// material only needs to be described once no matter how many objects use it.
+#[derive(Clone, Default)]
+pub struct MaterialRegistry {
+    materials: HashMap<String, Arc<dyn Material>>,
+}
+
+impl MaterialRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `material` under `name`, replacing any previous entry.
+    ///
+    /// Returns the shared handle that was stored.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        material: impl Material + 'static,
+    ) -> Arc<dyn Material> {
+        let material: Arc<dyn Material> = Arc::new(material);
+        self.materials.insert(name.into(), Arc::clone(&material));
+        material
+    }
+
+    /// Look up a material by name.
+    pub fn get(&self, name: &str) -> Option<Arc<dyn Material>> {
+        self.materials.get(name).map(Arc::clone)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.materials.contains_key(name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.materials.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.materials.is_empty()
+    }
+}
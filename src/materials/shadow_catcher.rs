@@ -0,0 +1,51 @@
+use super::EmergentRay;
+use super::Material;
+use super::RayInteraction;
+
+use crate::color::Color;
+use crate::objects::HitRecord;
+use crate::ray::Ray;
+use crate::utils;
+
+/// An invisible surface that only shows the shadows cast onto it, for
+/// compositing rendered objects over a photograph.
+///
+/// Rays pass straight through, keeping their [`RayType`](crate::ray::RayType),
+/// so the surface shows whatever lies behind it.
+/// When lights are registered with
+/// [`RenderOptions::lights`](crate::scene::RenderOptions::lights), points whose
+/// sampled light is blocked are darkened, and in transparent-background
+/// renders the catcher's alpha is the fraction of light blocked: clear where
+/// lit and opaque black where fully shadowed.
+///
+/// Shadow rays pass through catchers, so a catcher never blocks light from
+/// reaching the rest of the scene.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ShadowCatcher;
+
+impl ShadowCatcher {
+    /// Create a shadow catcher. It has no settings: how dark its shadows are
+    /// depends only on the lights registered for the render.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Material for ShadowCatcher {
+    fn interact<'a>(&self, ray: &Ray, record: &'a HitRecord) -> RayInteraction<'a> {
+        RayInteraction::Scattered(EmergentRay {
+            inner: ray
+                .child(&record.point, *ray.dir())
+                .with_ray_type(ray.ray_type()),
+            attenuation: Color::new(1.0, 1.0, 1.0),
+        })
+    }
+
+    fn catches_shadows(&self) -> bool {
+        true
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint("ShadowCatcher"))
+    }
+}
@@ -0,0 +1,108 @@
+use std::f64::consts::PI;
+
+use super::EmergentRay;
+use super::Material;
+use super::RayInteraction;
+
+use crate::color::Color;
+use crate::objects::HitRecord;
+use crate::ray::Ray;
+use crate::utils;
+
+/// Wavelengths, in nanometers, at which RGB rays evaluate the film's red,
+/// green, and blue reflectance.
+const RGB_WAVELENGTHS: [f64; 3] = [650.0, 532.0, 450.0];
+
+/// A specular surface colored by a thin transparent film, like a soap bubble
+/// or an oil slick.
+///
+/// Light reflected off the top and bottom of the film interferes, so the
+/// reflectance depends on wavelength and shifts with the view angle. Only the
+/// reflection is traced; light passing through the film is lost. Spectral
+/// rays see the exact reflectance at their wavelength; RGB rays are tinted by
+/// the reflectance at one wavelength per channel.
+#[derive(Clone)]
+pub struct ThinFilm {
+    thickness: f64,
+    ior: f64,
+    substrate_ior: f64,
+}
+
+impl ThinFilm {
+    /// A film `thickness_nm` nanometers thick with index of refraction `ior`,
+    /// with air on both sides.
+    pub fn new(thickness_nm: f64, ior: f64) -> Self {
+        Self {
+            thickness: thickness_nm,
+            ior,
+            substrate_ior: 1.0,
+        }
+    }
+
+    /// Set the index of refraction of what lies beneath the film, e.g. 1.33 for
+    /// oil on water. Defaults to 1.0, a free-standing film such as a bubble.
+    pub fn substrate_ior(mut self, substrate_ior: f64) -> Self {
+        self.substrate_ior = substrate_ior;
+        self
+    }
+
+    /// Reflectance for light arriving at an angle whose cosine is `cos_theta`,
+    /// evaluated at the red, green, and blue wavelengths.
+    pub fn reflectance(&self, cos_theta: f64) -> Color {
+        let [r, g, b] =
+            RGB_WAVELENGTHS.map(|wavelength| self.reflectance_at(cos_theta, wavelength));
+        Color::new(r, g, b)
+    }
+
+    /// Airy reflectance of the film at one wavelength, averaged over s and p polarization.
+    fn reflectance_at(&self, cos_theta: f64, wavelength: f64) -> f64 {
+        let sin_squared = 1.0 - cos_theta * cos_theta;
+        let cos_in = |ior: f64| (1.0 - sin_squared / (ior * ior)).max(0.0).sqrt();
+        let (cos_film, cos_substrate) = (cos_in(self.ior), cos_in(self.substrate_ior));
+
+        // Fresnel amplitude coefficients (s, p) at an interface
+        let fresnel = |n1: f64, cos1: f64, n2: f64, cos2: f64| {
+            (
+                (n1 * cos1 - n2 * cos2) / (n1 * cos1 + n2 * cos2),
+                (n2 * cos1 - n1 * cos2) / (n2 * cos1 + n1 * cos2),
+            )
+        };
+        let top = fresnel(1.0, cos_theta, self.ior, cos_film);
+        let bottom = fresnel(self.ior, cos_film, self.substrate_ior, cos_substrate);
+
+        // Phase difference between the two reflected waves
+        let cos_delta = (4.0 * PI * self.ior * self.thickness * cos_film / wavelength).cos();
+        let airy = |r12: f64, r23: f64| {
+            let cross = 2.0 * r12 * r23 * cos_delta;
+            (r12 * r12 + r23 * r23 + cross) / (1.0 + r12 * r12 * r23 * r23 + cross)
+        };
+        (airy(top.0, bottom.0) + airy(top.1, bottom.1)) / 2.0
+    }
+}
+
+impl Material for ThinFilm {
+    fn interact<'a>(&self, ray: &Ray, record: &'a HitRecord) -> RayInteraction<'a> {
+        let cos_theta = (-ray.dir()).dot(&record.normal).clamp(0.0, 1.0);
+        let attenuation = match ray.wavelength() {
+            Some(wavelength) => {
+                let reflectance = self.reflectance_at(cos_theta, wavelength);
+                Color::new(reflectance, reflectance, reflectance)
+            }
+            None => self.reflectance(cos_theta),
+        };
+
+        RayInteraction::Scattered(EmergentRay {
+            inner: ray.child(&record.point, ray.dir().reflect(&record.normal)),
+            attenuation,
+        })
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "ThinFilm",
+            self.thickness.to_bits(),
+            self.ior.to_bits(),
+            self.substrate_ior.to_bits(),
+        )))
+    }
+}
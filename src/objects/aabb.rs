@@ -0,0 +1,101 @@
+use crate::ray::Ray;
+use crate::utils::interval::Interval;
+use crate::vector::Point;
+
+/// An axis-aligned bounding box.
+#[derive(Clone, Copy, Debug)]
+pub struct Aabb {
+    min: Point,
+    max: Point,
+}
+
+impl Aabb {
+    /// The box enclosing nothing, with every minimum above every maximum.
+    ///
+    /// It is the identity of [`Aabb::union`] and no ray hits it.
+    pub const EMPTY: Aabb = Aabb {
+        min: Point::new(f64::INFINITY, f64::INFINITY, f64::INFINITY),
+        max: Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY),
+    };
+
+    /// Create the box spanned by two opposite corners, in any order.
+    pub fn new(a: Point, b: Point) -> Self {
+        Self {
+            min: Point::new(a.x().min(b.x()), a.y().min(b.y()), a.z().min(b.z())),
+            max: Point::new(a.x().max(b.x()), a.y().max(b.y()), a.z().max(b.z())),
+        }
+    }
+
+    /// Whether this box encloses no points, like [`Aabb::EMPTY`].
+    pub fn is_empty(&self) -> bool {
+        self.min.x() > self.max.x() || self.min.y() > self.max.y() || self.min.z() > self.max.z()
+    }
+
+    /// The corner with the smallest coordinates.
+    pub fn min(&self) -> Point {
+        self.min
+    }
+
+    /// The corner with the largest coordinates.
+    pub fn max(&self) -> Point {
+        self.max
+    }
+
+    /// The smallest box enclosing both `self` and `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: Point::new(
+                self.min.x().min(other.min.x()),
+                self.min.y().min(other.min.y()),
+                self.min.z().min(other.min.z()),
+            ),
+            max: Point::new(
+                self.max.x().max(other.max.x()),
+                self.max.y().max(other.max.y()),
+                self.max.z().max(other.max.z()),
+            ),
+        }
+    }
+
+    /// The center of the box.
+    pub fn centroid(&self) -> Point {
+        Point::new(
+            (self.min.x() + self.max.x()) * 0.5,
+            (self.min.y() + self.max.y()) * 0.5,
+            (self.min.z() + self.max.z()) * 0.5,
+        )
+    }
+
+    /// Total area of the box's six faces.
+    pub fn surface_area(&self) -> f64 {
+        let d = self.max - self.min;
+        2.0 * (d.x() * d.y() + d.y() * d.z() + d.z() * d.x())
+    }
+
+    /// Slab test for whether `ray` passes through the box within `ray_t`.
+    ///
+    /// Rays that only touch the box still count, so flat boxes, such as those
+    /// around axis-aligned triangles, are hit where the ray crosses them.
+    pub fn hit(&self, ray_t: Interval, ray: &Ray) -> bool {
+        let origin = ray.origin();
+        let dir = ray.dir_v();
+        let axes = [
+            (origin.x(), dir.x(), self.min.x(), self.max.x()),
+            (origin.y(), dir.y(), self.min.y(), self.max.y()),
+            (origin.z(), dir.z(), self.min.z(), self.max.z()),
+        ];
+
+        let (mut t_min, mut t_max) = (ray_t.min, ray_t.max);
+        for (origin, dir, min, max) in axes {
+            let inv = 1.0 / dir;
+            let (t0, t1) = ((min - origin) * inv, (max - origin) * inv);
+            let (t0, t1) = if inv < 0.0 { (t1, t0) } else { (t0, t1) };
+            t_min = t_min.max(t0);
+            t_max = t_max.min(t1);
+            if t_max < t_min {
+                return false;
+            }
+        }
+        true
+    }
+}
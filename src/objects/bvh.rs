@@ -0,0 +1,357 @@
+use std::sync::Arc;
+
+use super::{Aabb, HitRecord, Hittable};
+use crate::HittableList;
+use crate::ray::Ray;
+use crate::utils;
+use crate::utils::interval::Interval;
+use crate::vector::{Point, Vector};
+
+/// Number of buckets candidate SAH splits are evaluated at, per axis.
+const SAH_BUCKETS: usize = 12;
+
+/// How [`BvhNode::build_with`] partitions objects at each node.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BvhSplit {
+    /// Split at the median centroid along the longest axis. Cheap to build.
+    #[default]
+    Median,
+    /// Split where the Surface Area Heuristic estimates the cheapest traversal.
+    /// Slower to build, but gives faster trees for unevenly spread scenes.
+    Sah,
+}
+
+/// A bounding volume hierarchy over bounded objects.
+///
+/// Rays skip whole subtrees whose boxes they miss, so hits cost roughly
+/// logarithmic rather than linear time in the number of objects.
+pub struct BvhNode {
+    bbox: Aabb,
+    left: BvhChild,
+    right: Option<BvhChild>,
+}
+
+/// Either an inner node, owned so it can be refit in place, or an object.
+enum BvhChild {
+    Node(Box<BvhNode>),
+    Leaf(Arc<dyn Hittable>),
+}
+
+impl BvhChild {
+    fn as_hittable(&self) -> &dyn Hittable {
+        match self {
+            Self::Node(node) => node.as_ref(),
+            Self::Leaf(object) => object.as_ref(),
+        }
+    }
+
+    fn transformed(&self, transform: &Transform) -> Option<Self> {
+        Some(match self {
+            Self::Node(node) => Self::Node(Box::new(node.transformed(transform)?)),
+            Self::Leaf(object) => Self::Leaf(transform.object(object.as_ref())?),
+        })
+    }
+
+    /// Refit below this child and return its new box, `None` if a leaf
+    /// became unbounded.
+    fn refit(&mut self) -> Option<Aabb> {
+        match self {
+            Self::Node(node) => node.refit().then_some(node.bbox),
+            Self::Leaf(object) => object.bounding_box(),
+        }
+    }
+
+    fn collect_objects_mut<'a>(&'a mut self, objects: &mut Vec<&'a mut Arc<dyn Hittable>>) {
+        match self {
+            Self::Node(node) => node.collect_objects_mut(objects),
+            Self::Leaf(object) => objects.push(object),
+        }
+    }
+}
+
+impl BvhNode {
+    /// Build a hierarchy over `objects` with median splits.
+    ///
+    /// Returns `None` if `objects` is empty or contains an unbounded object
+    /// (such as a [`Plane`](super::Plane)), which should stay outside the tree.
+    pub fn build(objects: &HittableList) -> Option<Self> {
+        Self::build_with(objects, BvhSplit::Median)
+    }
+
+    /// Build a hierarchy over `objects`, partitioning nodes with `split`.
+    pub fn build_with(objects: &HittableList, split: BvhSplit) -> Option<Self> {
+        let mut entries = objects
+            .objects()
+            .iter()
+            .enumerate()
+            .map(|(index, object)| Some((Arc::clone(object), object.bounding_box()?, index)))
+            .collect::<Option<Vec<_>>>()?;
+        if entries.is_empty() {
+            return None;
+        }
+        Some(Self::build_node(&mut entries, split))
+    }
+
+    fn build_node(entries: &mut [Entry], split: BvhSplit) -> Self {
+        let bbox = entries
+            .iter()
+            .skip(1)
+            .fold(entries[0].1, |bbox, (_, other, _)| bbox.union(other));
+
+        match entries {
+            [(only, _, _)] => Self {
+                bbox,
+                left: BvhChild::Leaf(Arc::clone(only)),
+                right: None,
+            },
+            [(left, _, _), (right, _, _)] => Self {
+                bbox,
+                left: BvhChild::Leaf(Arc::clone(left)),
+                right: Some(BvhChild::Leaf(Arc::clone(right))),
+            },
+            _ => {
+                let mid = match split {
+                    BvhSplit::Median => median_split(entries),
+                    BvhSplit::Sah => sah_split(entries).unwrap_or_else(|| median_split(entries)),
+                };
+                let (left, right) = entries.split_at_mut(mid);
+                Self {
+                    bbox,
+                    left: BvhChild::Node(Box::new(Self::build_node(left, split))),
+                    right: Some(BvhChild::Node(Box::new(Self::build_node(right, split)))),
+                }
+            }
+        }
+    }
+
+    /// The objects at the leaves, in tree order, for replacing moved objects
+    /// before a [`refit`](Self::refit).
+    pub fn objects_mut(&mut self) -> Vec<&mut Arc<dyn Hittable>> {
+        let mut objects = Vec::new();
+        self.collect_objects_mut(&mut objects);
+        objects
+    }
+
+    fn collect_objects_mut<'a>(&'a mut self, objects: &mut Vec<&'a mut Arc<dyn Hittable>>) {
+        self.left.collect_objects_mut(objects);
+        if let Some(right) = &mut self.right {
+            right.collect_objects_mut(objects);
+        }
+    }
+
+    /// Recompute every bounding box bottom-up, keeping the tree's shape.
+    ///
+    /// Much cheaper than a rebuild between animation frames, but traversal
+    /// degrades as objects drift far from where the tree was built. Returns
+    /// `false` (leaving boxes stale) if an object has become unbounded.
+    pub fn refit(&mut self) -> bool {
+        let Some(left) = self.left.refit() else {
+            return false;
+        };
+        self.bbox = match &mut self.right {
+            Some(right) => match right.refit() {
+                Some(right) => left.union(&right),
+                None => return false,
+            },
+            None => left,
+        };
+        true
+    }
+
+    /// A copy of the tree with every object and box transformed, keeping its
+    /// shape; `None` if any object below cannot be transformed.
+    fn transformed(&self, transform: &Transform) -> Option<Self> {
+        let right = match &self.right {
+            Some(right) => Some(right.transformed(transform)?),
+            None => None,
+        };
+        Some(Self {
+            bbox: Aabb::new(
+                transform.point(self.bbox.min()),
+                transform.point(self.bbox.max()),
+            ),
+            left: self.left.transformed(transform)?,
+            right,
+        })
+    }
+}
+
+impl Hittable for BvhNode {
+    fn hit(&self, ray_t: Interval, ray: &Ray) -> Option<HitRecord> {
+        if !self.bbox.hit(ray_t, ray) {
+            return None;
+        }
+
+        let left = self.left.as_hittable().hit(ray_t, ray);
+        let closest = left.as_ref().map_or(ray_t.max, |record| record.t);
+        let right = self.right.as_ref().and_then(|right| {
+            right
+                .as_hittable()
+                .hit(Interval::new(ray_t.min, closest), ray)
+        });
+        right.or(left)
+    }
+
+    fn hit_p(&self, ray_t: Interval, ray: &Ray) -> bool {
+        self.bbox.hit(ray_t, ray)
+            && (self.left.as_hittable().hit_p(ray_t, ray)
+                || self
+                    .right
+                    .as_ref()
+                    .is_some_and(|right| right.as_hittable().hit_p(ray_t, ray)))
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(self.bbox)
+    }
+
+    /// Keeps the tree's shape; `None` if any object below cannot be rescaled.
+    fn scaled(&self, factor: f64, about: &Point) -> Option<Arc<dyn Hittable>> {
+        Some(Arc::new(
+            self.transformed(&Transform::Scale(factor, about))?,
+        ))
+    }
+
+    /// Keeps the tree's shape; `None` if any object below cannot be moved.
+    fn translated(&self, offset: &Vector) -> Option<Arc<dyn Hittable>> {
+        Some(Arc::new(self.transformed(&Transform::Translate(offset))?))
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        let right = match &self.right {
+            Some(right) => Some(right.as_hittable().fingerprint()?),
+            None => None,
+        };
+        Some(utils::fingerprint((
+            "BvhNode",
+            self.left.as_hittable().fingerprint()?,
+            right,
+        )))
+    }
+}
+
+/// A change applied to every object and box in a tree by
+/// [`BvhNode::transformed`].
+enum Transform<'a> {
+    Scale(f64, &'a Point),
+    Translate(&'a Vector),
+}
+
+impl Transform<'_> {
+    fn object(&self, object: &dyn Hittable) -> Option<Arc<dyn Hittable>> {
+        match *self {
+            Self::Scale(factor, about) => object.scaled(factor, about),
+            Self::Translate(offset) => object.translated(offset),
+        }
+    }
+
+    fn point(&self, point: Point) -> Point {
+        match *self {
+            Self::Scale(factor, about) => point.scaled_about(factor, about),
+            Self::Translate(offset) => point + *offset,
+        }
+    }
+}
+
+/// An object being placed in the tree, with its box and its index in the
+/// original list.
+type Entry = (Arc<dyn Hittable>, Aabb, usize);
+
+/// Sort by centroid along `axis`, breaking ties by original index so that
+/// building the same list always gives the same tree.
+fn sort_along(entries: &mut [Entry], axis: usize) {
+    entries.sort_by(|(_, a, a_index), (_, b, b_index)| {
+        axis_value(a.centroid(), axis)
+            .total_cmp(&axis_value(b.centroid(), axis))
+            .then(a_index.cmp(b_index))
+    });
+}
+
+fn axis_value(p: Point, axis: usize) -> f64 {
+    match axis {
+        0 => p.x(),
+        1 => p.y(),
+        _ => p.z(),
+    }
+}
+
+/// Sort along the axis where centroids spread the most and split in half.
+fn median_split(entries: &mut [Entry]) -> usize {
+    let centroids = centroid_bounds(entries);
+    let axis = (0..3)
+        .max_by(|&a, &b| {
+            let extent =
+                |axis| axis_value(centroids.max(), axis) - axis_value(centroids.min(), axis);
+            extent(a).total_cmp(&extent(b))
+        })
+        .unwrap_or(0);
+    sort_along(entries, axis);
+    entries.len() / 2
+}
+
+/// Sort along the axis and at the bucket boundary with the lowest SAH cost,
+/// returning the split index. `None` if all centroids coincide.
+fn sah_split(entries: &mut [Entry]) -> Option<usize> {
+    let centroids = centroid_bounds(entries);
+    let mut best: Option<(f64, usize, f64)> = None;
+
+    for axis in 0..3 {
+        let min = axis_value(centroids.min(), axis);
+        let extent = axis_value(centroids.max(), axis) - min;
+        if extent <= 0.0 {
+            continue;
+        }
+        let bucket_of = |bbox: &Aabb| {
+            let offset = (axis_value(bbox.centroid(), axis) - min) / extent;
+            ((offset * SAH_BUCKETS as f64) as usize).min(SAH_BUCKETS - 1)
+        };
+
+        let mut buckets: [(usize, Option<Aabb>); SAH_BUCKETS] = [(0, None); SAH_BUCKETS];
+        for (_, bbox, _) in entries.iter() {
+            let bucket = &mut buckets[bucket_of(bbox)];
+            bucket.0 += 1;
+            bucket.1 = Some(bucket.1.map_or(*bbox, |b| b.union(bbox)));
+        }
+
+        // Cost of splitting after bucket `b`: objects on each side weighted by
+        // the surface area of their combined box
+        for b in 0..SAH_BUCKETS - 1 {
+            let side_cost = |side: &[(usize, Option<Aabb>)]| {
+                let count: usize = side.iter().map(|(count, _)| count).sum();
+                let bbox = side
+                    .iter()
+                    .filter_map(|(_, bbox)| *bbox)
+                    .reduce(|a, b| a.union(&b));
+                (
+                    count,
+                    bbox.map_or(0.0, |bbox| count as f64 * bbox.surface_area()),
+                )
+            };
+            let (left_count, left_cost) = side_cost(&buckets[..=b]);
+            let (right_count, right_cost) = side_cost(&buckets[b + 1..]);
+            if left_count == 0 || right_count == 0 {
+                continue;
+            }
+            let cost = left_cost + right_cost;
+            if best.is_none_or(|(best_cost, _, _)| cost < best_cost) {
+                let boundary = min + extent * (b + 1) as f64 / SAH_BUCKETS as f64;
+                best = Some((cost, axis, boundary));
+            }
+        }
+    }
+
+    let (_, axis, boundary) = best?;
+    sort_along(entries, axis);
+    let mid = entries.partition_point(|(_, bbox, _)| axis_value(bbox.centroid(), axis) < boundary);
+    (mid > 0 && mid < entries.len()).then_some(mid)
+}
+
+/// The box spanned by the centroids of every entry's box.
+fn centroid_bounds(entries: &[Entry]) -> Aabb {
+    let first = entries[0].1.centroid();
+    entries
+        .iter()
+        .fold(Aabb::new(first, first), |bounds, (_, bbox, _)| {
+            bounds.union(&Aabb::new(bbox.centroid(), bbox.centroid()))
+        })
+}
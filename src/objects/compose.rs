@@ -0,0 +1,108 @@
+//! [`Hittable`] for `Option`s and small tuples of objects, for composing tiny
+//! scenes inline without allocating a [`HittableList`].
+
+use std::sync::Arc;
+
+use super::{Aabb, HitRecord, Hittable, HittableList, closest_hit};
+use crate::ray::Ray;
+use crate::utils::interval::Interval;
+use crate::utils::{self, rand};
+use crate::vector::{Point, UtVector, Vector};
+
+/// `None` is an empty scene: it never hits and has no bounds.
+impl<H: Hittable> Hittable for Option<H> {
+    fn hit(&self, ray_t: Interval, ray: &Ray) -> Option<HitRecord> {
+        self.as_ref()?.hit(ray_t, ray)
+    }
+
+    fn hit_p(&self, ray_t: Interval, ray: &Ray) -> bool {
+        self.as_ref().is_some_and(|object| object.hit_p(ray_t, ray))
+    }
+
+    fn pdf_value(&self, origin: &Point, dir: &UtVector) -> f64 {
+        self.as_ref()
+            .map_or(0.0, |object| object.pdf_value(origin, dir))
+    }
+
+    fn random_direction(&self, origin: &Point) -> Option<UtVector> {
+        self.as_ref()?.random_direction(origin)
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        self.as_ref()?.bounding_box()
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        let object = match self {
+            Some(object) => Some(object.fingerprint()?),
+            None => None,
+        };
+        Some(utils::fingerprint(("Option", object)))
+    }
+
+    fn scaled(&self, factor: f64, about: &Point) -> Option<Arc<dyn Hittable>> {
+        match self {
+            Some(object) => object.scaled(factor, about),
+            None => Some(Arc::new(HittableList::new())),
+        }
+    }
+
+    fn translated(&self, offset: &Vector) -> Option<Arc<dyn Hittable>> {
+        match self {
+            Some(object) => object.translated(offset),
+            None => Some(Arc::new(HittableList::new())),
+        }
+    }
+}
+
+/// Implement [`Hittable`] for a tuple of objects, behaving like a
+/// [`HittableList`] of its elements: hits are the nearest of any element, and
+/// lights are sampled by picking an element uniformly.
+macro_rules! hittable_tuple {
+    ($($name:ident . $index:tt),+) => {
+        impl<$($name: Hittable),+> Hittable for ($($name,)+) {
+            fn hit(&self, ray_t: Interval, ray: &Ray) -> Option<HitRecord> {
+                let objects: [&dyn Hittable; _] = [$(&self.$index),+];
+                closest_hit(objects, ray_t, ray)
+            }
+
+            fn hit_p(&self, ray_t: Interval, ray: &Ray) -> bool {
+                $(self.$index.hit_p(ray_t, ray))||+
+            }
+
+            fn pdf_value(&self, origin: &Point, dir: &UtVector) -> f64 {
+                let densities = [$(self.$index.pdf_value(origin, dir)),+];
+                densities.iter().sum::<f64>() / densities.len() as f64
+            }
+
+            fn random_direction(&self, origin: &Point) -> Option<UtVector> {
+                let objects: [&dyn Hittable; _] = [$(&self.$index),+];
+                let index = (rand::random() * objects.len() as f64) as usize;
+                objects[index.min(objects.len() - 1)].random_direction(origin)
+            }
+
+            fn bounding_box(&self) -> Option<Aabb> {
+                [$(self.$index.bounding_box()?),+]
+                    .into_iter()
+                    .reduce(|acc, bbox| acc.union(&bbox))
+            }
+
+            fn fingerprint(&self) -> Option<u64> {
+                Some(utils::fingerprint(("Tuple", [$(self.$index.fingerprint()?),+])))
+            }
+
+            /// `None` unless every element can be rescaled.
+            fn scaled(&self, factor: f64, about: &Point) -> Option<Arc<dyn Hittable>> {
+                Some(Arc::new(HittableList(vec![$(self.$index.scaled(factor, about)?),+])))
+            }
+
+            /// `None` unless every element can be moved.
+            fn translated(&self, offset: &Vector) -> Option<Arc<dyn Hittable>> {
+                Some(Arc::new(HittableList(vec![$(self.$index.translated(offset)?),+])))
+            }
+        }
+    };
+}
+
+hittable_tuple!(A.0, B.1);
+hittable_tuple!(A.0, B.1, C.2);
@@ -0,0 +1,103 @@
+use std::ops::BitOr;
+use std::sync::Arc;
+
+use super::{Aabb, HitRecord, Hittable, HittableList};
+use crate::ray::{Ray, RayType};
+use crate::utils;
+use crate::utils::interval::Interval;
+use crate::vector::{Point, UtVector, Vector};
+
+/// A set of [`RayType`]s, combined with `|`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RayTypeMask(u8);
+
+impl RayTypeMask {
+    /// No rays at all.
+    pub const NONE: Self = Self(0);
+    /// Primary rays from the camera.
+    pub const CAMERA: Self = Self(1 << 0);
+    /// Shadow and occlusion probes.
+    pub const SHADOW: Self = Self(1 << 1);
+    /// Rays scattered off surfaces.
+    pub const REFLECTION: Self = Self(1 << 2);
+    /// Every kind of ray.
+    pub const ALL: Self = Self(Self::CAMERA.0 | Self::SHADOW.0 | Self::REFLECTION.0);
+
+    /// Whether rays of `ray_type` are in the set.
+    pub fn contains(self, ray_type: RayType) -> bool {
+        let flag = match ray_type {
+            RayType::Camera => Self::CAMERA,
+            RayType::Shadow => Self::SHADOW,
+            RayType::Reflection => Self::REFLECTION,
+        };
+        self.0 & flag.0 != 0
+    }
+}
+
+impl BitOr for RayTypeMask {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Wraps another [`Hittable`] so that only some kinds of rays can hit it.
+///
+/// For example, an object masked to [`RayTypeMask::CAMERA`] is seen directly
+/// but casts no shadows and shows up in no reflections.
+pub struct Masked<T: Hittable> {
+    object: T,
+    mask: RayTypeMask,
+}
+
+impl<T: Hittable> Masked<T> {
+    /// Make `object` visible only to the rays in `mask`.
+    pub fn new(object: T, mask: RayTypeMask) -> Self {
+        Self { object, mask }
+    }
+}
+
+impl<T: Hittable> Hittable for Masked<T> {
+    fn hit(&self, ray_t: Interval, ray: &Ray) -> Option<HitRecord> {
+        if !self.mask.contains(ray.ray_type()) {
+            return None;
+        }
+        self.object.hit(ray_t, ray)
+    }
+
+    fn hit_p(&self, ray_t: Interval, ray: &Ray) -> bool {
+        self.mask.contains(ray.ray_type()) && self.object.hit_p(ray_t, ray)
+    }
+
+    fn pdf_value(&self, origin: &Point, dir: &UtVector) -> f64 {
+        self.object.pdf_value(origin, dir)
+    }
+
+    fn random_direction(&self, origin: &Point) -> Option<UtVector> {
+        self.object.random_direction(origin)
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        self.object.bounding_box()
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "Masked",
+            self.mask,
+            self.object.fingerprint()?,
+        )))
+    }
+
+    /// The rescaled object, under the same mask.
+    fn scaled(&self, factor: f64, about: &Point) -> Option<Arc<dyn Hittable>> {
+        let object = HittableList(vec![self.object.scaled(factor, about)?]);
+        Some(Arc::new(Masked::new(object, self.mask)))
+    }
+
+    /// The moved object, under the same mask.
+    fn translated(&self, offset: &Vector) -> Option<Arc<dyn Hittable>> {
+        let object = HittableList(vec![self.object.translated(offset)?]);
+        Some(Arc::new(Masked::new(object, self.mask)))
+    }
+}
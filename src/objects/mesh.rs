@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use super::triangle::{hit_triangle, triangle_box};
+use super::{Aabb, HitRecord, Hittable};
+use crate::materials::Material;
+use crate::ray::Ray;
+use crate::utils;
+use crate::utils::interval::Interval;
+use crate::vector::{Point, Vector};
+
+/// An indexed triangle mesh sharing one material.
+pub struct Mesh {
+    vertices: Vec<Point>,
+    faces: Vec<[usize; 3]>,
+    material: Arc<dyn Material>,
+}
+
+impl Mesh {
+    /// Create a new [`Mesh`] from shared `vertices` and `faces` indexing into them.
+    ///
+    /// Returns `None` if any face refers to a vertex that does not exist.
+    pub fn new(
+        vertices: Vec<Point>,
+        faces: Vec<[usize; 3]>,
+        material: Arc<dyn Material>,
+    ) -> Option<Self> {
+        if faces.iter().flatten().any(|&i| i >= vertices.len()) {
+            return None;
+        }
+
+        Some(Self {
+            vertices,
+            faces,
+            material,
+        })
+    }
+
+    /// The mesh's vertices.
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// The mesh's faces, as indices into [`Mesh::vertices`].
+    pub fn faces(&self) -> &[[usize; 3]] {
+        &self.faces
+    }
+
+    fn face(&self, [a, b, c]: [usize; 3]) -> [&Point; 3] {
+        [&self.vertices[a], &self.vertices[b], &self.vertices[c]]
+    }
+}
+
+impl Hittable for Mesh {
+    fn hit(&self, ray_t: Interval, ray: &Ray) -> Option<HitRecord> {
+        let mut hit_record = None;
+        let mut closest_so_far = ray_t.max;
+        for &face in &self.faces {
+            let face_t = Interval::new(ray_t.min, closest_so_far);
+            if let Some(rec) = hit_triangle(self.face(face), &self.material, face_t, ray) {
+                closest_so_far = rec.t;
+                hit_record = Some(rec);
+            }
+        }
+
+        // Faces measure height over themselves; measure it over the whole mesh
+        let mut rec = hit_record?;
+        let (low, high) = self
+            .vertices
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), vertex| {
+                (low.min(vertex.y()), high.max(vertex.y()))
+            });
+        rec.height = HitRecord::height_between(rec.point.y(), low, high);
+        Some(rec)
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        self.faces
+            .iter()
+            .map(|&face| triangle_box(self.face(face)))
+            .reduce(|acc, bbox| acc.union(&bbox))
+    }
+
+    fn scaled(&self, factor: f64, about: &Point) -> Option<Arc<dyn Hittable>> {
+        Some(Arc::new(Self {
+            vertices: self
+                .vertices
+                .iter()
+                .map(|vertex| vertex.scaled_about(factor, about))
+                .collect(),
+            faces: self.faces.clone(),
+            material: Arc::clone(&self.material),
+        }))
+    }
+
+    fn translated(&self, offset: &Vector) -> Option<Arc<dyn Hittable>> {
+        Some(Arc::new(Self {
+            vertices: self
+                .vertices
+                .iter()
+                .map(|vertex| vertex + *offset)
+                .collect(),
+            faces: self.faces.clone(),
+            material: Arc::clone(&self.material),
+        }))
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "Mesh",
+            &self.vertices,
+            &self.faces,
+            self.material.fingerprint()?,
+        )))
+    }
+}
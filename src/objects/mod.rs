@@ -2,36 +2,144 @@
 //!
 //! Contains
 //! * [`Sphere`]
+//! * [`MovingSphere`]
 //! * [`Plane`]
+//! * [`Triangle`]
+//! * [`Mesh`]
+//! * [`Segment`]
+//! * [`Obb`]
+//!
+//! Bounded objects can be grouped into a [`BvhNode`] to speed up hits in large worlds,
+//! and any object can be hidden from some kinds of rays with [`Masked`]. Tiny
+//! scenes can also be composed inline, as an `Option` or a tuple of up to three
+//! objects, without a list.
 
 #![warn(missing_docs)]
 
+/// Axis-aligned bounding boxes.
+pub mod aabb;
+/// Bounding volume hierarchies.
+pub mod bvh;
+mod compose;
+/// Visibility masks by ray type.
+pub mod masked;
+/// Indexed triangle meshes.
+pub mod mesh;
+/// Spheres in linear motion.
+pub mod moving_sphere;
+/// Oriented boxes.
+pub mod obb;
 /// Plane primitives.
 pub mod plane;
+/// Uniform scaling of other objects.
+pub mod scale;
+/// Capsule-shaped line segments.
+pub mod segment;
 /// Sphere primitives.
 pub mod sphere;
+/// Triangle primitives.
+pub mod triangle;
 
+pub use aabb::Aabb;
+pub use bvh::{BvhNode, BvhSplit};
+pub use masked::{Masked, RayTypeMask};
+pub use mesh::Mesh;
+pub use moving_sphere::MovingSphere;
+pub use obb::Obb;
 pub use plane::Plane;
+pub use scale::Scale;
+pub use segment::Segment;
 pub use sphere::Sphere;
+pub use triangle::Triangle;
 
+use std::fmt;
 use std::sync::Arc;
 
 use crate::materials::Material;
 use crate::ray::Ray;
 use crate::utils::interval::Interval;
-use crate::vector::{Point, UtVector};
+use crate::utils::{self, rand};
+use crate::vector::{Onb, Point, UtVector, Vector};
 
 /// Information about a ray/object intersection.
+#[derive(Clone)]
 pub struct HitRecord {
     pub(super) point: Point,
     pub(super) normal: UtVector,
     pub(super) t: f64,
+    pub(super) u: f64,
+    pub(super) v: f64,
+    /// (u, v) units spanned by one world unit of surface near the hit: the
+    /// square root of the surface's (u, v) area over its world-space area.
+    pub(super) uv_scale: f64,
+    /// Height of the hit within its object, from 0.0 at the object's lowest
+    /// point to 1.0 at its highest.
+    pub(super) height: f64,
     pub(super) front_face: bool,
     // Could this possibly be reduced down to `Box`? Look into various implementations of `Hittable` trait for objects
     pub(super) material: Arc<dyn Material>,
 }
 
 impl HitRecord {
+    /// World-space point where the ray hit.
+    pub fn point(&self) -> Point {
+        self.point
+    }
+
+    /// Unit surface normal, facing against the incoming ray.
+    pub fn normal(&self) -> UtVector {
+        self.normal
+    }
+
+    /// Ray parameter of the hit.
+    pub fn t(&self) -> f64 {
+        self.t
+    }
+
+    /// Whether the ray hit the outside of the surface.
+    pub fn front_face(&self) -> bool {
+        self.front_face
+    }
+
+    /// Surface coordinates of the hit, both in `[0.0, 1.0]`.
+    pub fn uv(&self) -> (f64, f64) {
+        (self.u, self.v)
+    }
+
+    /// How many (u, v) units one world unit of surface spans near the hit,
+    /// on average over both directions.
+    pub fn uv_scale(&self) -> f64 {
+        self.uv_scale
+    }
+
+    /// How far up its object the hit lies, from 0.0 at the object's lowest
+    /// point to 1.0 at its highest. Unbounded surfaces report 0.5.
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// An orthonormal basis at the hit whose `w` axis is the surface normal.
+    ///
+    /// Records carry no surface derivatives, so the tangents are not aligned
+    /// with the (u, v) directions.
+    pub fn tangent_frame(&self) -> Onb {
+        Onb::new(self.normal)
+    }
+
+    /// Material of the surface that was hit.
+    pub fn material(&self) -> &Arc<dyn Material> {
+        &self.material
+    }
+
+    /// A copy of this record with its shading normal replaced, as done by
+    /// [`NormalMapped`](crate::materials::NormalMapped).
+    pub(crate) fn with_normal(&self, normal: UtVector) -> Self {
+        Self {
+            normal,
+            ..self.clone()
+        }
+    }
+
     /// Flip a surface normal so it always faces against the incoming ray.
     pub fn face_normal(&mut self, ray: &Ray, outward_normal: &UtVector) {
         self.front_face = ray.dir_v().dot(outward_normal) < 0.0;
@@ -42,6 +150,16 @@ impl HitRecord {
             -outward_normal
         };
     }
+
+    /// Where `y` lies between an object's `low` and `high` extents, for
+    /// [`HitRecord::height`].
+    pub(super) fn height_between(y: f64, low: f64, high: f64) -> f64 {
+        if high > low {
+            ((y - low) / (high - low)).clamp(0.0, 1.0)
+        } else {
+            0.5
+        }
+    }
 }
 
 /// A collection of hittable objects treated as a single world.
@@ -60,6 +178,23 @@ impl HittableList {
         Self(Vec::new())
     }
 
+    /// The union of the boxes of every bounded object, skipping unbounded
+    /// ones such as [`Plane`]s, or [`Aabb::EMPTY`] if nothing is bounded.
+    ///
+    /// Unlike [`Hittable::bounding_box`], a floor plane does not stop the rest
+    /// of the scene from having bounds, which suits framing a camera on it.
+    pub fn bounds(&self) -> Aabb {
+        self.0
+            .iter()
+            .filter_map(|hittable| hittable.bounding_box())
+            .fold(Aabb::EMPTY, |acc, bbox| acc.union(&bbox))
+    }
+
+    /// The objects in the list, in insertion order.
+    pub fn objects(&self) -> &[Arc<dyn Hittable>] {
+        &self.0
+    }
+
     /// Add a [`Hittable`] object to a [`HittableList`].
     ///
     /// Returns `self` so calls can be chained when building a world.
@@ -81,28 +216,286 @@ impl HittableList {
         self.0.push(Arc::new(object));
         self
     }
-}
 
-// Treat HittableList like a "world" object: a composition of [`Hittable`]s. Every object in [`HittableList`] is [`Hittable`], so [`HittableList`] is hittable.
-impl Hittable for HittableList {
-    /// Loops through every [`Hittable`] in the underlying [`Vec<Rc<dyn Hittable>>`]
-    fn hit(&self, ray_t: Interval, ray: &Ray) -> Option<HitRecord> {
-        let mut hit_record = None;
-        // Never hit
-        let mut closest_so_far = ray_t.max;
+    /// Add an object already shared elsewhere, e.g. an emitter that is both in
+    /// the world and registered as a light.
+    pub fn add_shared(&mut self, object: Arc<dyn Hittable>) -> &mut Self {
+        self.0.push(object);
+        self
+    }
+
+    /// A list holding only the objects at `indices`, in the order given, for
+    /// rendering part of a scene in isolation.
+    ///
+    /// The objects are shared with this list, not copied. Indices past the end
+    /// are ignored.
+    pub fn view_subset(&self, indices: &[usize]) -> HittableList {
+        Self(
+            indices
+                .iter()
+                .filter_map(|&i| self.0.get(i).map(Arc::clone))
+                .collect(),
+        )
+    }
+
+    /// Scale every object's position and size by `factor` about `about`.
+    ///
+    /// Useful for reconciling scenes authored at different scales. Objects whose
+    /// [`Hittable::scaled`] returns `None` are left unchanged. `factor` should be positive.
+    pub fn scale(&mut self, factor: f64, about: Point) -> &mut Self {
+        for object in &mut self.0 {
+            if let Some(scaled) = object.scaled(factor, &about) {
+                *object = scaled;
+            }
+        }
+        self
+    }
+
+    /// Move each object by its own offset, drawn from `offset` in list order.
+    ///
+    /// Objects whose [`Hittable::translated`] returns `None` are left
+    /// unchanged, though an offset is still drawn for them.
+    pub fn translate_each(&mut self, mut offset: impl FnMut() -> Vector) -> &mut Self {
+        for object in &mut self.0 {
+            if let Some(moved) = object.translated(&offset()) {
+                *object = moved;
+            }
+        }
+        self
+    }
+
+    /// Every intersection of `ray` with the list's objects within `ray_t`,
+    /// sorted by `t`, for stacking transparent layers or CSG.
+    ///
+    /// Each object is searched repeatedly past its previous hit, so closed
+    /// primitives such as spheres report both their entry and exit. Hits of
+    /// one object closer together than a billionth of their distance are
+    /// reported once. This is slower than [`Hittable::hit`], which stops at
+    /// the nearest surface.
+    pub fn hit_all(&self, ray_t: Interval, ray: &Ray) -> Vec<HitRecord> {
+        let mut records = Vec::new();
         for hittable in &self.0 {
-            if let Some(rec) = hittable.hit(Interval::new(ray_t.min, closest_so_far), ray) {
-                // This hit will be (should be; really depending on the implementor of `Hittable`) closer
-                closest_so_far = rec.t;
-                hit_record = Some(rec);
+            let mut after = ray_t.min;
+            // An object that ignores the interval would otherwise be found at
+            // the same hit forever
+            while let Some(rec) = hittable
+                .hit(Interval::new(after, ray_t.max), ray)
+                .filter(|rec| rec.t >= after)
+            {
+                // Some primitives accept hits on the interval's bounds, so
+                // search from clearly past the last hit to avoid finding it
+                // again
+                after = rec.t + 1e-9 * rec.t.abs().max(1.0);
+                records.push(rec);
             }
         }
-        hit_record
+        records.sort_by(|a, b| a.t.total_cmp(&b.t));
+        records
+    }
+}
+
+impl fmt::Debug for HittableList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HittableList")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
+
+/// The nearest hit of any of `objects` within `ray_t`, shared by every
+/// composition of [`Hittable`]s.
+pub(super) fn closest_hit<'a>(
+    objects: impl IntoIterator<Item = &'a dyn Hittable>,
+    ray_t: Interval,
+    ray: &Ray,
+) -> Option<HitRecord> {
+    let mut hit_record = None;
+    // Only hits closer than the best so far, and never past `ray_t.max`, are searched for
+    let mut closest_so_far = ray_t.max;
+    for hittable in objects {
+        if let Some(rec) = hittable.hit(Interval::new(ray_t.min, closest_so_far), ray) {
+            // This hit will be (should be; really depending on the implementor of `Hittable`) closer
+            closest_so_far = rec.t;
+            hit_record = Some(rec);
+        }
+    }
+    hit_record
+}
+
+// A slice of shared objects is a "world" object: a composition of [`Hittable`]s. Every object in it is [`Hittable`], so the slice is hittable, and so are [`HittableList`] and `Vec`s and references wrapping one.
+impl Hittable for [Arc<dyn Hittable>] {
+    /// Loops through every [`Hittable`] in the slice, keeping the closest hit.
+    fn hit(&self, ray_t: Interval, ray: &Ray) -> Option<HitRecord> {
+        // Single-object scenes are common for previews, so skip the bookkeeping
+        if let [only] = self {
+            return only.hit(ray_t, ray);
+        }
+
+        closest_hit(self.iter().map(AsRef::as_ref), ray_t, ray)
+    }
+
+    /// The union of every object's box, or `None` if the slice is empty or holds an unbounded object.
+    fn bounding_box(&self) -> Option<Aabb> {
+        let mut objects = self.iter();
+        let first = objects.next()?.bounding_box()?;
+        objects.try_fold(first, |acc, hittable| {
+            Some(acc.union(&hittable.bounding_box()?))
+        })
+    }
+
+    /// The average of every object's density, matching [`Hittable::random_direction`]'s uniform choice of object.
+    fn pdf_value(&self, origin: &Point, dir: &UtVector) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        let total: f64 = self.iter().map(|h| h.pdf_value(origin, dir)).sum();
+        total / self.len() as f64
+    }
+
+    /// Picks one object uniformly and samples a direction towards it.
+    fn random_direction(&self, origin: &Point) -> Option<UtVector> {
+        if self.is_empty() {
+            return None;
+        }
+        let index = (rand::random() * self.len() as f64) as usize;
+        self[index.min(self.len() - 1)].random_direction(origin)
+    }
+
+    /// Stops at the first object hit, since any hit is enough to answer.
+    fn hit_p(&self, ray_t: Interval, ray: &Ray) -> bool {
+        self.iter().any(|hittable| hittable.hit_p(ray_t, ray))
     }
+
+    fn scaled(&self, factor: f64, about: &Point) -> Option<Arc<dyn Hittable>> {
+        let mut list = HittableList(self.to_vec());
+        list.scale(factor, *about);
+        Some(Arc::new(list))
+    }
+
+    fn translated(&self, offset: &Vector) -> Option<Arc<dyn Hittable>> {
+        let mut list = HittableList(self.to_vec());
+        list.translate_each(|| *offset);
+        Some(Arc::new(list))
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        let objects = self
+            .iter()
+            .map(|hittable| hittable.fingerprint())
+            .collect::<Option<Vec<_>>>()?;
+        Some(utils::fingerprint(("HittableList", objects)))
+    }
+}
+
+/// Implement [`Hittable`] for a wrapper around a slice of objects by
+/// forwarding every method to the slice.
+macro_rules! hittable_via_slice {
+    ($wrapper:ty, |$this:ident| $slice:expr) => {
+        impl Hittable for $wrapper {
+            fn hit(&self, ray_t: Interval, ray: &Ray) -> Option<HitRecord> {
+                let $this = self;
+                $slice.hit(ray_t, ray)
+            }
+
+            fn hit_p(&self, ray_t: Interval, ray: &Ray) -> bool {
+                let $this = self;
+                $slice.hit_p(ray_t, ray)
+            }
+
+            fn pdf_value(&self, origin: &Point, dir: &UtVector) -> f64 {
+                let $this = self;
+                $slice.pdf_value(origin, dir)
+            }
+
+            fn random_direction(&self, origin: &Point) -> Option<UtVector> {
+                let $this = self;
+                $slice.random_direction(origin)
+            }
+
+            fn bounding_box(&self) -> Option<Aabb> {
+                let $this = self;
+                $slice.bounding_box()
+            }
+
+            fn fingerprint(&self) -> Option<u64> {
+                let $this = self;
+                $slice.fingerprint()
+            }
+
+            fn scaled(&self, factor: f64, about: &Point) -> Option<Arc<dyn Hittable>> {
+                let $this = self;
+                $slice.scaled(factor, about)
+            }
+
+            fn translated(&self, offset: &Vector) -> Option<Arc<dyn Hittable>> {
+                let $this = self;
+                $slice.translated(offset)
+            }
+        }
+    };
 }
 
+hittable_via_slice!(HittableList, |list| list.0.as_slice());
+hittable_via_slice!(Vec<Arc<dyn Hittable>>, |objects| objects.as_slice());
+hittable_via_slice!(&[Arc<dyn Hittable>], |objects| *objects);
+
 /// All objects that interact with rays must implement this trait [`Hittable`].
 pub trait Hittable: Send + Sync {
     /// Evaluates whether a [`Ray`] hits an object, returning a `Option<HitRecord>`. Implementing this function for all ray-interacting objects is part of the [`Hittable`] trait.
     fn hit(&self, ray_t: Interval, ray: &Ray) -> Option<HitRecord>;
+
+    /// Evaluates whether a [`Ray`] hits an object at all within `ray_t`, without building a [`HitRecord`].
+    ///
+    /// This is meant for shadow and occlusion rays. The default calls [`Hittable::hit`]; implementors can override it with a cheaper test.
+    fn hit_p(&self, ray_t: Interval, ray: &Ray) -> bool {
+        self.hit(ray_t, ray).is_some()
+    }
+
+    /// Density, per solid angle, with which [`Hittable::random_direction`] picks `dir` from `origin`.
+    ///
+    /// Objects that cannot be sampled as lights return 0.0, which is the default.
+    fn pdf_value(&self, _origin: &Point, _dir: &UtVector) -> f64 {
+        0.0
+    }
+
+    /// Pick a random direction from `origin` towards this object, for sampling it as a light.
+    ///
+    /// Returns `None`, the default, for objects that cannot be sampled.
+    fn random_direction(&self, _origin: &Point) -> Option<UtVector> {
+        None
+    }
+
+    /// An axis-aligned box enclosing the object, or `None` if it is unbounded (like a [`Plane`]).
+    fn bounding_box(&self) -> Option<Aabb> {
+        None
+    }
+
+    /// A hash of everything that affects how the object renders, used to key
+    /// [`Camera::render_cached`](crate::Camera::render_cached).
+    ///
+    /// Returns `None`, the default, for objects that cannot be fingerprinted,
+    /// which makes any world containing them uncacheable.
+    fn fingerprint(&self) -> Option<u64> {
+        None
+    }
+
+    /// A copy of the object scaled uniformly by `factor` about `about`, used by [`HittableList::scale`].
+    ///
+    /// Returns `None`, the default, for objects that cannot be rescaled. `factor` should be positive.
+    fn scaled(&self, _factor: f64, _about: &Point) -> Option<Arc<dyn Hittable>> {
+        None
+    }
+
+    /// A copy of the object moved by `offset`, used by [`HittableList::translate_each`].
+    ///
+    /// Returns `None`, the default, for objects that cannot be moved.
+    fn translated(&self, _offset: &Vector) -> Option<Arc<dyn Hittable>> {
+        None
+    }
+}
+
+/// Objects that enclose a volume, so points can be classified as inside or outside.
+pub trait ClosedSolid {
+    /// Whether `p` lies inside the object or on its surface.
+    fn contains_point(&self, p: &Point) -> bool;
 }
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use super::sphere::{hit_sphere, hit_sphere_p, sphere_box};
+use super::{Aabb, HitRecord, Hittable};
+use crate::materials::Material;
+use crate::ray::Ray;
+use crate::utils;
+use crate::utils::interval::Interval;
+use crate::vector::{Point, Vector};
+
+/// A sphere that moves linearly over the shutter interval, for motion blur.
+///
+/// The sphere is at `center0` at time 0.0 and at `center1` at time 1.0. Rays
+/// see it at the position matching [`Ray::time`].
+pub struct MovingSphere {
+    center0: Point,
+    center1: Point,
+    radius: f64,
+    material: Arc<dyn Material>,
+}
+
+impl MovingSphere {
+    /// Create a new [`MovingSphere`] travelling from `center0` to `center1`.
+    pub fn new(
+        center0: Point,
+        center1: Point,
+        radius: f64,
+        material: impl Material + 'static,
+    ) -> Self {
+        Self {
+            center0,
+            center1,
+            radius,
+            material: Arc::new(material),
+        }
+    }
+
+    /// Position of the center at `time`, interpolated between the endpoints.
+    pub fn center(&self, time: f64) -> Point {
+        self.center0 + (self.center1 - self.center0) * time
+    }
+}
+
+impl Hittable for MovingSphere {
+    fn hit_p(&self, ray_t: Interval, ray: &Ray) -> bool {
+        hit_sphere_p(&self.center(ray.time()), self.radius, ray_t, ray)
+    }
+
+    fn hit(&self, ray_t: Interval, ray: &Ray) -> Option<HitRecord> {
+        hit_sphere(
+            &self.center(ray.time()),
+            self.radius,
+            &self.material,
+            ray_t,
+            ray,
+        )
+    }
+
+    /// Encloses the sphere at both ends of the shutter interval.
+    fn bounding_box(&self) -> Option<Aabb> {
+        let start = sphere_box(&self.center0, self.radius);
+        let end = sphere_box(&self.center1, self.radius);
+        Some(start.union(&end))
+    }
+
+    fn scaled(&self, factor: f64, about: &Point) -> Option<Arc<dyn Hittable>> {
+        Some(Arc::new(Self {
+            center0: self.center0.scaled_about(factor, about),
+            center1: self.center1.scaled_about(factor, about),
+            radius: self.radius * factor,
+            material: Arc::clone(&self.material),
+        }))
+    }
+
+    fn translated(&self, offset: &Vector) -> Option<Arc<dyn Hittable>> {
+        Some(Arc::new(Self {
+            center0: self.center0 + *offset,
+            center1: self.center1 + *offset,
+            radius: self.radius,
+            material: Arc::clone(&self.material),
+        }))
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "MovingSphere",
+            self.center0,
+            self.center1,
+            self.radius.to_bits(),
+            self.material.fingerprint()?,
+        )))
+    }
+}
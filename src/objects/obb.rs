@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use super::{Aabb, ClosedSolid, HitRecord, Hittable};
+use crate::materials::Material;
+use crate::ray::Ray;
+use crate::utils;
+use crate::utils::interval::Interval;
+use crate::vector::{Point, UtVector, Vector};
+
+/// A box with arbitrary orientation: a center, three orthonormal axes, and
+/// the half-extent of the box along each axis.
+///
+/// Rays are moved into the box's frame and slab-tested there, which is
+/// cheaper than wrapping an axis-aligned box in a rotation.
+pub struct Obb {
+    center: Point,
+    axes: [UtVector; 3],
+    half_extents: [f64; 3],
+    material: Arc<dyn Material>,
+}
+
+impl Obb {
+    /// Create a new [`Obb`] around `center` along `axes`.
+    ///
+    /// `axes` must be mutually perpendicular; this is only checked in debug builds.
+    pub fn new(
+        center: Point,
+        axes: [UtVector; 3],
+        half_extents: Vector,
+        material: impl Material + 'static,
+    ) -> Self {
+        Self::with_shared_material(center, axes, half_extents, Arc::new(material))
+    }
+
+    /// Create a new [`Obb`] that shares an existing material, such as one
+    /// taken from a [`MaterialRegistry`](crate::materials::MaterialRegistry).
+    pub fn with_shared_material(
+        center: Point,
+        axes: [UtVector; 3],
+        half_extents: Vector,
+        material: Arc<dyn Material>,
+    ) -> Self {
+        debug_assert!(
+            (0..3).all(|i| axes[i].dot(&axes[(i + 1) % 3]).abs() < 1e-9),
+            "OBB axes must be perpendicular"
+        );
+        Self {
+            center,
+            axes,
+            half_extents: [half_extents.x(), half_extents.y(), half_extents.z()],
+            material,
+        }
+    }
+
+    /// Coordinates of `v` along each of the box's axes.
+    fn local(&self, v: &Vector) -> [f64; 3] {
+        self.axes.map(|axis| axis.dot(v))
+    }
+
+    /// Where the ray enters and leaves the box's slabs, if it passes through.
+    fn slabs(&self, ray: &Ray) -> Option<(FaceCrossing, FaceCrossing)> {
+        let origin = self.local(&(ray.origin() - self.center));
+        let dir = self.local(ray.dir_v());
+
+        let mut enter = (f64::NEG_INFINITY, 0, 0.0);
+        let mut leave = (f64::INFINITY, 0, 0.0);
+        for axis in 0..3 {
+            let half = self.half_extents[axis];
+            if dir[axis] == 0.0 {
+                if origin[axis].abs() > half {
+                    return None;
+                }
+                continue;
+            }
+            // The ray crosses the face facing against it first
+            let side = -dir[axis].signum();
+            let near = (side * half - origin[axis]) / dir[axis];
+            let far = (-side * half - origin[axis]) / dir[axis];
+            if near > enter.0 {
+                enter = (near, axis, side);
+            }
+            if far < leave.0 {
+                leave = (far, axis, -side);
+            }
+        }
+        (enter.0 <= leave.0).then_some((enter, leave))
+    }
+}
+
+/// A ray's `(t, axis, side)` where it crosses a face, with `side` the sign of
+/// that face's outward normal along `axis`.
+type FaceCrossing = (f64, usize, f64);
+
+impl Hittable for Obb {
+    fn hit(&self, ray_t: Interval, ray: &Ray) -> Option<HitRecord> {
+        let (enter, leave) = self.slabs(ray)?;
+        let (t, axis, side) = if ray_t.contains(enter.0) {
+            enter
+        } else if ray_t.contains(leave.0) {
+            leave
+        } else {
+            return None;
+        };
+
+        let point = ray.at(t);
+        let outward_normal = UtVector::new_unchecked(self.axes[axis] * side);
+
+        // Face coordinates along the next two axes, each from 0.0 to 1.0
+        let local = self.local(&(point - self.center));
+        let face = |i: usize| {
+            let i = (axis + i) % 3;
+            (local[i] / self.half_extents[i] + 1.0) * 0.5
+        };
+        let (u, v) = (face(1), face(2));
+        let uv_scale = 1.0
+            / (4.0 * self.half_extents[(axis + 1) % 3] * self.half_extents[(axis + 2) % 3]).sqrt();
+
+        let front_face = ray.dir_v().dot(&outward_normal) < 0.0;
+        let normal = if front_face {
+            outward_normal
+        } else {
+            -outward_normal
+        };
+        let bounds = self.bounding_box()?;
+        let (low, high) = (bounds.min().y(), bounds.max().y());
+        Some(HitRecord {
+            t,
+            u,
+            v,
+            uv_scale,
+            height: HitRecord::height_between(point.y(), low, high),
+            point,
+            front_face,
+            normal,
+            material: Arc::clone(&self.material),
+        })
+    }
+
+    fn hit_p(&self, ray_t: Interval, ray: &Ray) -> bool {
+        self.slabs(ray)
+            .is_some_and(|(enter, leave)| ray_t.contains(enter.0) || ray_t.contains(leave.0))
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let reach = |component: fn(&Vector) -> f64| {
+            (0..3)
+                .map(|i| component(self.axes[i].inner()).abs() * self.half_extents[i])
+                .sum::<f64>()
+        };
+        let r = Vector::new(reach(Vector::x), reach(Vector::y), reach(Vector::z));
+        Some(Aabb::new(self.center - r, self.center + r))
+    }
+
+    fn scaled(&self, factor: f64, about: &Point) -> Option<Arc<dyn Hittable>> {
+        let [x, y, z] = self.half_extents.map(|half| half * factor);
+        Some(Arc::new(Self::with_shared_material(
+            self.center.scaled_about(factor, about),
+            self.axes,
+            Vector::new(x, y, z),
+            Arc::clone(&self.material),
+        )))
+    }
+
+    fn translated(&self, offset: &Vector) -> Option<Arc<dyn Hittable>> {
+        let [x, y, z] = self.half_extents;
+        Some(Arc::new(Self::with_shared_material(
+            self.center + *offset,
+            self.axes,
+            Vector::new(x, y, z),
+            Arc::clone(&self.material),
+        )))
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "Obb",
+            self.center,
+            self.axes,
+            self.half_extents.map(f64::to_bits),
+            self.material.fingerprint()?,
+        )))
+    }
+}
+
+impl ClosedSolid for Obb {
+    fn contains_point(&self, p: &Point) -> bool {
+        self.local(&(p - self.center))
+            .iter()
+            .zip(self.half_extents)
+            .all(|(coordinate, half)| coordinate.abs() <= half)
+    }
+}
@@ -3,8 +3,9 @@ use std::sync::Arc;
 use super::{HitRecord, Hittable};
 use crate::materials::Material;
 use crate::ray::Ray;
+use crate::utils;
 use crate::utils::interval::Interval;
-use crate::vector::UtVector;
+use crate::vector::{Onb, Point, UtVector, Vector};
 
 /// Object representing a plane in three-dimensions.
 pub struct Plane {
@@ -24,9 +25,45 @@ impl Plane {
             material: Arc::new(material),
         }
     }
+
+    /// Create a new [`Plane`] that shares an existing material, such as one
+    /// taken from a [`MaterialRegistry`](crate::materials::MaterialRegistry).
+    pub fn with_shared_material(normal: UtVector, d: f64, material: Arc<dyn Material>) -> Self {
+        Self {
+            normal,
+            d,
+            material,
+        }
+    }
+
+    /// Project `point` onto a tangent basis of the plane, tiling (u, v) once
+    /// per world unit.
+    fn uv(&self, point: &Point) -> (f64, f64) {
+        let frame = Onb::new(self.normal);
+
+        (
+            point.to_vector().dot(&frame.v()).rem_euclid(1.0),
+            point.to_vector().dot(&frame.u()).rem_euclid(1.0),
+        )
+    }
+
+    /// The material used to shade this plane.
+    pub fn material(&self) -> &Arc<dyn Material> {
+        &self.material
+    }
 }
 
 impl Hittable for Plane {
+    fn hit_p(&self, ray_t: Interval, ray: &Ray) -> bool {
+        let denom = self.normal.dot(ray.dir());
+
+        if denom.abs() < 0.001 {
+            return false;
+        }
+
+        ray_t.contains(-(self.normal.dot(&ray.origin().to_vector()) + self.d) / denom)
+    }
+
     fn hit(&self, ray_t: Interval, ray: &Ray) -> Option<HitRecord> {
         let denom = self.normal.dot(ray.dir());
 
@@ -34,7 +71,7 @@ impl Hittable for Plane {
             return None;
         }
 
-        let t = -(self.normal.dot(ray.origin()) + self.d) / denom;
+        let t = -(self.normal.dot(&ray.origin().to_vector()) + self.d) / denom;
 
         if !ray_t.contains(t) {
             return None;
@@ -49,14 +86,49 @@ impl Hittable for Plane {
             -self.normal
         };
 
+        let (u, v) = self.uv(&point);
+
         Some(HitRecord {
             point,
             normal: outward_normal,
             t,
+            u,
+            v,
+            // (u, v) are world coordinates along the plane
+            uv_scale: 1.0,
+            height: 0.5,
             front_face,
             material: Arc::clone(&self.material),
         })
     }
+
+    /// Keeps the normal and moves the offset, so that `p` on the plane maps to
+    /// `about + (p - about) * factor` on the result.
+    fn scaled(&self, factor: f64, about: &Point) -> Option<Arc<dyn Hittable>> {
+        let d = factor * self.d + (factor - 1.0) * self.normal.dot(&about.to_vector());
+        Some(Arc::new(Self::with_shared_material(
+            self.normal,
+            d,
+            Arc::clone(&self.material),
+        )))
+    }
+
+    fn translated(&self, offset: &Vector) -> Option<Arc<dyn Hittable>> {
+        Some(Arc::new(Self::with_shared_material(
+            self.normal,
+            self.d - self.normal.dot(offset),
+            Arc::clone(&self.material),
+        )))
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "Plane",
+            self.normal,
+            self.d.to_bits(),
+            self.material.fingerprint()?,
+        )))
+    }
 }
 
 // Maybe generalize this formula
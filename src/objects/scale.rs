@@ -0,0 +1,91 @@
+use std::sync::Arc;
+
+use super::{Aabb, ClosedSolid, HitRecord, Hittable, HittableList};
+use crate::ray::Ray;
+use crate::utils;
+use crate::utils::interval::Interval;
+use crate::vector::{Point, Vector};
+
+/// Wraps another [`Hittable`], scaling it uniformly about the origin.
+///
+/// Rays are transformed into the object's space on every hit. For spheres,
+/// [`Sphere::transformed`](super::Sphere::transformed) bakes the scale in instead.
+pub struct Scale<T: Hittable> {
+    object: T,
+    factor: f64,
+}
+
+impl<T: Hittable> Scale<T> {
+    /// Scale `object` by `factor`, which should be positive.
+    pub fn new(object: T, factor: f64) -> Self {
+        Self { object, factor }
+    }
+}
+
+impl<T: Hittable> Scale<T> {
+    /// Run `f` with `ray` and `ray_t` carried into the object's unscaled space.
+    ///
+    /// The direction is unit length and unaffected by a uniform scale, so only
+    /// the origin and the distances along the ray change.
+    fn with_local_ray<R>(
+        &self,
+        ray_t: Interval,
+        ray: &Ray,
+        f: impl FnOnce(Interval, &Ray) -> R,
+    ) -> R {
+        let origin = ray.origin().scaled(1.0 / self.factor);
+        let local_ray = ray.child(&origin, *ray.dir()).with_ray_type(ray.ray_type());
+        let local_t = Interval::new(ray_t.min / self.factor, ray_t.max / self.factor);
+        f(local_t, &local_ray)
+    }
+}
+
+impl<T: Hittable> Hittable for Scale<T> {
+    fn hit(&self, ray_t: Interval, ray: &Ray) -> Option<HitRecord> {
+        let mut rec = self.with_local_ray(ray_t, ray, |t, r| self.object.hit(t, r))?;
+        rec.point = rec.point.scaled(self.factor);
+        rec.t *= self.factor;
+        rec.uv_scale /= self.factor;
+        Some(rec)
+    }
+
+    fn hit_p(&self, ray_t: Interval, ray: &Ray) -> bool {
+        self.with_local_ray(ray_t, ray, |t, r| self.object.hit_p(t, r))
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        let bbox = self.object.bounding_box()?;
+        Some(Aabb::new(
+            bbox.min().scaled(self.factor),
+            bbox.max().scaled(self.factor),
+        ))
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "Scale",
+            self.factor.to_bits(),
+            self.object.fingerprint()?,
+        )))
+    }
+
+    /// Rescales the object in its own space, about `about` carried there, and
+    /// keeps this scale around it.
+    fn scaled(&self, factor: f64, about: &Point) -> Option<Arc<dyn Hittable>> {
+        let local_about = about.scaled(1.0 / self.factor);
+        let object = HittableList(vec![self.object.scaled(factor, &local_about)?]);
+        Some(Arc::new(Scale::new(object, self.factor)))
+    }
+
+    /// Moves the object in its own space, by `offset` carried there.
+    fn translated(&self, offset: &Vector) -> Option<Arc<dyn Hittable>> {
+        let object = HittableList(vec![self.object.translated(&(offset / self.factor))?]);
+        Some(Arc::new(Scale::new(object, self.factor)))
+    }
+}
+
+impl<T: Hittable + ClosedSolid> ClosedSolid for Scale<T> {
+    fn contains_point(&self, p: &Point) -> bool {
+        self.object.contains_point(&p.scaled(1.0 / self.factor))
+    }
+}
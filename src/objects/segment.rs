@@ -0,0 +1,196 @@
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+use super::sphere::sphere_box;
+use super::{Aabb, ClosedSolid, HitRecord, Hittable};
+use crate::materials::Material;
+use crate::ray::Ray;
+use crate::utils;
+use crate::utils::interval::Interval;
+use crate::vector::{Onb, Point, Vector};
+
+/// A line segment of some thickness: a cylinder between two endpoints, capped
+/// with hemispheres. Thin segments are handy for drawing axes and plots.
+pub struct Segment {
+    start: Point,
+    end: Point,
+    radius: f64,
+    material: Arc<dyn Material>,
+}
+
+impl Segment {
+    /// Create a new [`Segment`] from `start` to `end` with a radius and material.
+    pub fn new(start: Point, end: Point, radius: f64, material: impl Material + 'static) -> Self {
+        Self::with_shared_material(start, end, radius, Arc::new(material))
+    }
+
+    /// Create a new [`Segment`] that shares an existing material, such as one
+    /// taken from a [`MaterialRegistry`](crate::materials::MaterialRegistry).
+    pub fn with_shared_material(
+        start: Point,
+        end: Point,
+        radius: f64,
+        material: Arc<dyn Material>,
+    ) -> Self {
+        Self {
+            start,
+            end,
+            radius,
+            material,
+        }
+    }
+
+    /// How far along the segment `p` projects, from 0.0 at `start` to 1.0 at
+    /// `end`. Unclamped, so points past the caps fall outside `[0.0, 1.0]`.
+    fn along(&self, p: &Point) -> f64 {
+        let axis = self.end - self.start;
+        let len_squared = axis.len_squared();
+        if len_squared == 0.0 {
+            return 0.0;
+        }
+        (p - self.start).dot(&axis) / len_squared
+    }
+
+    /// The point on the segment's axis closest to `p`.
+    fn closest_on_axis(&self, p: &Point) -> Point {
+        self.start + (self.end - self.start) * self.along(p).clamp(0.0, 1.0)
+    }
+
+    /// Every ray parameter where the ray crosses the surface, in no particular order.
+    fn crossings(&self, ray: &Ray) -> impl Iterator<Item = f64> {
+        let axis = self.end - self.start;
+        let len_squared = axis.len_squared();
+        let origin = *ray.origin();
+        let dir = *ray.dir_v();
+        let along = move |t: f64| self.along(&(origin + dir * t));
+
+        // The infinite cylinder, kept only between the two caps
+        let side = (len_squared > 0.0)
+            .then(|| {
+                let perpendicular = |v: Vector| v - axis * (v.dot(&axis) / len_squared);
+                let d = perpendicular(dir);
+                let oc = perpendicular(origin - self.start);
+                roots(
+                    d.len_squared(),
+                    d.dot(&oc),
+                    oc.len_squared() - self.radius.powi(2),
+                )
+            })
+            .flatten()
+            .into_iter()
+            .flat_map(|(near, far)| [near, far])
+            .filter(move |&t| (0.0..=1.0).contains(&along(t)));
+
+        // The end spheres, kept only beyond their ends of the segment
+        let cap = move |center: Point, outside: fn(f64) -> bool| {
+            let oc = origin - center;
+            roots(
+                dir.len_squared(),
+                dir.dot(&oc),
+                oc.len_squared() - self.radius.powi(2),
+            )
+            .into_iter()
+            .flat_map(|(near, far)| [near, far])
+            .filter(move |&t| outside(along(t)))
+        };
+
+        side.chain(cap(self.start, |s| s <= 0.0))
+            .chain(cap(self.end, |s| s >= 1.0))
+    }
+}
+
+/// Both roots of `a t^2 + 2 half_b t + c`, nearest first, or `None` if there are none.
+fn roots(a: f64, half_b: f64, c: f64) -> Option<(f64, f64)> {
+    if a == 0.0 {
+        return None;
+    }
+    let discrim = half_b.powi(2) - a * c;
+    if discrim < 0.0 {
+        return None;
+    }
+    let sqrtd = discrim.sqrt();
+    Some(((-half_b - sqrtd) / a, (-half_b + sqrtd) / a))
+}
+
+impl Hittable for Segment {
+    fn hit(&self, ray_t: Interval, ray: &Ray) -> Option<HitRecord> {
+        let t = self
+            .crossings(ray)
+            .filter(|&t| ray_t.contains(t))
+            .min_by(f64::total_cmp)?;
+
+        let point = ray.at(t);
+        let mut normal = ((point - self.closest_on_axis(&point)) / self.radius).unit();
+
+        // `u` runs along the segment and `v` around it
+        let axis = self.end - self.start;
+        let frame = Onb::new(if axis.len_squared() > 0.0 {
+            axis.unit()
+        } else {
+            Vector::new(0.0, 1.0, 0.0).unit()
+        });
+        let u = self.along(&point).clamp(0.0, 1.0);
+        let v = (normal.dot(&frame.v()).atan2(normal.dot(&frame.u())) + PI) / (2.0 * PI);
+
+        let front_face = ray.dir_v().dot(&normal) < 0.0;
+        if !front_face {
+            normal = -normal;
+        }
+        let bounds = self.bounding_box()?;
+        let (low, high) = (bounds.min().y(), bounds.max().y());
+        Some(HitRecord {
+            t,
+            u,
+            v,
+            // (u, v) cover the side, 2π radius around by the segment's length
+            uv_scale: 1.0 / (2.0 * PI * self.radius * axis.len()).sqrt(),
+            height: HitRecord::height_between(point.y(), low, high),
+            point,
+            front_face,
+            normal,
+            material: Arc::clone(&self.material),
+        })
+    }
+
+    fn hit_p(&self, ray_t: Interval, ray: &Ray) -> bool {
+        self.crossings(ray).any(|t| ray_t.contains(t))
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(sphere_box(&self.start, self.radius).union(&sphere_box(&self.end, self.radius)))
+    }
+
+    fn scaled(&self, factor: f64, about: &Point) -> Option<Arc<dyn Hittable>> {
+        Some(Arc::new(Self::with_shared_material(
+            self.start.scaled_about(factor, about),
+            self.end.scaled_about(factor, about),
+            self.radius * factor,
+            Arc::clone(&self.material),
+        )))
+    }
+
+    fn translated(&self, offset: &Vector) -> Option<Arc<dyn Hittable>> {
+        Some(Arc::new(Self::with_shared_material(
+            self.start + *offset,
+            self.end + *offset,
+            self.radius,
+            Arc::clone(&self.material),
+        )))
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "Segment",
+            self.start,
+            self.end,
+            self.radius.to_bits(),
+            self.material.fingerprint()?,
+        )))
+    }
+}
+
+impl ClosedSolid for Segment {
+    fn contains_point(&self, p: &Point) -> bool {
+        (p - self.closest_on_axis(p)).len_squared() <= self.radius * self.radius
+    }
+}
@@ -1,10 +1,12 @@
+use std::f64::consts::PI;
 use std::sync::Arc;
 
-use super::{HitRecord, Hittable};
+use super::{Aabb, ClosedSolid, HitRecord, Hittable};
 use crate::materials::Material;
 use crate::ray::Ray;
 use crate::utils::interval::Interval;
-use crate::vector::Point;
+use crate::utils::{self, rand};
+use crate::vector::{Onb, Point, UtVector, Vector};
 
 /// A sphere hittable by rays.
 pub struct Sphere {
@@ -24,41 +26,211 @@ impl Sphere {
             material: Arc::new(material),
         }
     }
+
+    /// Create a new [`Sphere`] that shares an existing material, such as one
+    /// taken from a [`MaterialRegistry`](crate::materials::MaterialRegistry).
+    pub fn with_shared_material(center: Point, radius: f64, material: Arc<dyn Material>) -> Self {
+        Self {
+            center,
+            radius,
+            material,
+        }
+    }
+
+    /// Create a new [`Sphere`] from its diameter rather than its radius.
+    pub fn from_diameter(center: Point, diameter: f64, material: impl Material + 'static) -> Self {
+        Self::new(center, diameter / 2.0, material)
+    }
+
+    /// Create the smallest [`Sphere`] touching both `a` and `b`: centered at
+    /// their midpoint, with half their distance as radius.
+    pub fn bounding_two_points(a: Point, b: Point, material: impl Material + 'static) -> Self {
+        let half = (b - a) * 0.5;
+        Self::new(a + half, half.len(), material)
+    }
+
+    /// Create a new [`Sphere`] with a uniform `scale` about the origin baked in.
+    ///
+    /// This hits exactly like wrapping the unscaled sphere in a [`Scale`](super::Scale),
+    /// without the per-ray transform. `scale` should be positive.
+    pub fn transformed(
+        center: Point,
+        radius: f64,
+        scale: f64,
+        material: impl Material + 'static,
+    ) -> Self {
+        Self::new(center.scaled(scale), radius * scale, material)
+    }
+
+    /// Map an outward unit normal to spherical (u, v) coordinates.
+    ///
+    /// `u` wraps around the y-axis starting from -x, and `v` runs from the
+    /// bottom pole (0.0) to the top pole (1.0).
+    fn uv(outward_normal: &UtVector) -> (f64, f64) {
+        let theta = (-outward_normal.y()).acos();
+        let phi = (-outward_normal.z()).atan2(outward_normal.x()) + PI;
+        (phi / (2.0 * PI), theta / PI)
+    }
+
+    /// The material used to shade this sphere.
+    pub fn material(&self) -> &Arc<dyn Material> {
+        &self.material
+    }
 }
 
 impl Hittable for Sphere {
+    fn hit_p(&self, ray_t: Interval, ray: &Ray) -> bool {
+        hit_sphere_p(&self.center, self.radius, ray_t, ray)
+    }
+
     fn hit(&self, ray_t: Interval, ray: &Ray) -> Option<HitRecord> {
-        let oc = self.center - ray.origin();
-        let a = ray.dir_v().len_squared();
-        let h = oc.dot(ray.dir_v());
-        let c = oc.len_squared() - self.radius.powi(2);
-        let discrim = h.powi(2) - a * c;
+        hit_sphere(&self.center, self.radius, &self.material, ray_t, ray)
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(sphere_box(&self.center, self.radius))
+    }
+
+    fn scaled(&self, factor: f64, about: &Point) -> Option<Arc<dyn Hittable>> {
+        Some(Arc::new(Self::with_shared_material(
+            self.center.scaled_about(factor, about),
+            self.radius * factor,
+            Arc::clone(&self.material),
+        )))
+    }
+
+    fn translated(&self, offset: &Vector) -> Option<Arc<dyn Hittable>> {
+        Some(Arc::new(Self::with_shared_material(
+            self.center + *offset,
+            self.radius,
+            Arc::clone(&self.material),
+        )))
+    }
+
+    /// Uniform over the cone of directions from `origin` that hit the sphere.
+    fn pdf_value(&self, origin: &Point, dir: &UtVector) -> f64 {
+        let probe = Ray::new(origin, *dir);
+        if !self.hit_p(Interval::new(0.001, f64::MAX), &probe) {
+            return 0.0;
+        }
+        match self.cos_theta_max(origin) {
+            Some(cos_theta_max) => 1.0 / (2.0 * PI * (1.0 - cos_theta_max)),
+            None => 0.0,
+        }
+    }
 
-        if discrim < 0.0 {
+    fn random_direction(&self, origin: &Point) -> Option<UtVector> {
+        let cos_theta_max = self.cos_theta_max(origin)?;
+        let axis = (self.center - origin).unit();
+
+        let phi = 2.0 * PI * rand::random();
+        let cos_theta = 1.0 + rand::random() * (cos_theta_max - 1.0);
+        let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+
+        let local = Vector::new(sin_theta * phi.cos(), sin_theta * phi.sin(), cos_theta);
+        Some(Onb::new(axis).transform(&local).unit())
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "Sphere",
+            self.center,
+            self.radius.to_bits(),
+            self.material.fingerprint()?,
+        )))
+    }
+}
+
+impl ClosedSolid for Sphere {
+    fn contains_point(&self, p: &Point) -> bool {
+        (p - self.center).len_squared() <= self.radius * self.radius
+    }
+}
+
+impl Sphere {
+    /// Cosine of the half-angle of the cone the sphere subtends from `origin`,
+    /// or `None` when `origin` is inside the sphere.
+    fn cos_theta_max(&self, origin: &Point) -> Option<f64> {
+        let dist_squared = (self.center - origin).len_squared();
+        let radius_squared = self.radius * self.radius;
+        if dist_squared <= radius_squared {
             return None;
         }
+        Some((1.0 - radius_squared / dist_squared).sqrt())
+    }
+}
+
+/// Axis-aligned box enclosing a sphere. Negative radii (hollow spheres) are treated by magnitude.
+pub(super) fn sphere_box(center: &Point, radius: f64) -> Aabb {
+    let r = Vector::new(radius.abs(), radius.abs(), radius.abs());
+    Aabb::new(center - r, center + r)
+}
+
+/// Record-free intersection test shared by [`Sphere`] and [`MovingSphere`](super::MovingSphere).
+pub(super) fn hit_sphere_p(center: &Point, radius: f64, ray_t: Interval, ray: &Ray) -> bool {
+    let oc = center - ray.origin();
+    let a = ray.dir_v().len_squared();
+    let h = oc.dot(ray.dir_v());
+    let c = oc.len_squared() - radius.powi(2);
+    let discrim = h.powi(2) - a * c;
+
+    if discrim < 0.0 {
+        return false;
+    }
+
+    let sqrtd = discrim.sqrt();
+    ray_t.contains_inclusive((h - sqrtd) / a) || ray_t.contains_inclusive((h + sqrtd) / a)
+}
+
+/// Intersection shared by [`Sphere`] and [`MovingSphere`](super::MovingSphere).
+pub(super) fn hit_sphere(
+    center: &Point,
+    radius: f64,
+    material: &Arc<dyn Material>,
+    ray_t: Interval,
+    ray: &Ray,
+) -> Option<HitRecord> {
+    let oc = center - ray.origin();
+    let a = ray.dir_v().len_squared();
+    let h = oc.dot(ray.dir_v());
+    let c = oc.len_squared() - radius.powi(2);
+    let discrim = h.powi(2) - a * c;
+
+    if discrim < 0.0 {
+        return None;
+    }
 
-        let mut t = (h - discrim.sqrt()) / a;
+    let mut t = (h - discrim.sqrt()) / a;
+    if t < ray_t.min || t > ray_t.max {
+        t = (h + discrim.sqrt()) / a;
         if t < ray_t.min || t > ray_t.max {
-            t = (h + discrim.sqrt()) / a;
-            if t < ray_t.min || t > ray_t.max {
-                return None;
-            }
+            return None;
         }
+    }
 
-        // Even though the vector seems to emanate from the center of the circle, it is still a normal vector to the sphere's surface. Keep that in mind. Also, we divide by `radius` because of negative-radii spheres apparently instead of normalizing by length.
-        let mut normal = ((ray.at(t) - self.center) / self.radius).assert_unit_unsafe();
+    // Even though the vector seems to emanate from the center of the circle, it is still a normal vector to the sphere's surface. Keep that in mind. Also, we divide by `radius` because of negative-radii spheres apparently instead of normalizing by length.
+    let mut normal = UtVector::new_unchecked((ray.at(t) - center) / radius);
 
-        let front_face = ray.dir_v().dot(&normal) < 0.0;
-        if !front_face {
-            normal = -normal;
-        }
-        Some(HitRecord {
-            t,
-            point: ray.at(t),
-            front_face,
-            normal,
-            material: Arc::clone(&self.material),
-        })
+    let (u, v) = Sphere::uv(&normal);
+
+    let front_face = ray.dir_v().dot(&normal) < 0.0;
+    if !front_face {
+        normal = -normal;
     }
+    Some(HitRecord {
+        t,
+        u,
+        v,
+        // The unit (u, v) square covers the whole surface, 4π radius²
+        uv_scale: 1.0 / (2.0 * radius.abs() * PI.sqrt()),
+        height: HitRecord::height_between(
+            ray.at(t).y(),
+            center.y() - radius.abs(),
+            center.y() + radius.abs(),
+        ),
+        point: ray.at(t),
+        front_face,
+        normal,
+        material: Arc::clone(material),
+    })
 }
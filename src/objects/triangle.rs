@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use super::{Aabb, HitRecord, Hittable};
+use crate::materials::Material;
+use crate::ray::Ray;
+use crate::utils::interval::Interval;
+use crate::utils::{self, rand};
+use crate::vector::{Point, UtVector, Vector};
+
+/// A single triangle with vertices `a`, `b`, `c`.
+///
+/// The front face is the one from which the vertices appear counter-clockwise.
+pub struct Triangle {
+    a: Point,
+    b: Point,
+    c: Point,
+    material: Arc<dyn Material>,
+}
+
+impl Triangle {
+    /// Create a new [`Triangle`] from its three vertices.
+    pub fn new(a: Point, b: Point, c: Point, material: impl Material + 'static) -> Self {
+        Self {
+            a,
+            b,
+            c,
+            material: Arc::new(material),
+        }
+    }
+}
+
+impl Hittable for Triangle {
+    fn hit(&self, ray_t: Interval, ray: &Ray) -> Option<HitRecord> {
+        hit_triangle([&self.a, &self.b, &self.c], &self.material, ray_t, ray)
+    }
+
+    fn bounding_box(&self) -> Option<Aabb> {
+        Some(triangle_box([&self.a, &self.b, &self.c]))
+    }
+
+    /// Uniform over the triangle's area, converted to solid angle from `origin`.
+    fn pdf_value(&self, origin: &Point, dir: &UtVector) -> f64 {
+        let probe = Ray::new(origin, *dir);
+        let Some(record) = self.hit(Interval::new(0.001, f64::MAX), &probe) else {
+            return 0.0;
+        };
+        let area = 0.5 * (self.b - self.a).cross(&(self.c - self.a)).len();
+        let cosine = dir.dot(&record.normal).abs();
+        if area <= 0.0 || cosine <= 0.0 {
+            return 0.0;
+        }
+        record.t * record.t / (cosine * area)
+    }
+
+    /// Towards a uniformly chosen point of the triangle.
+    fn random_direction(&self, origin: &Point) -> Option<UtVector> {
+        let (mut r1, mut r2) = (rand::random(), rand::random());
+        // Fold the far half of the parallelogram back onto the triangle
+        if r1 + r2 > 1.0 {
+            (r1, r2) = (1.0 - r1, 1.0 - r2);
+        }
+        let target = self.a + (self.b - self.a) * r1 + (self.c - self.a) * r2;
+        let towards = target - origin;
+        (towards.len_squared() > 0.0).then(|| towards.unit())
+    }
+
+    fn scaled(&self, factor: f64, about: &Point) -> Option<Arc<dyn Hittable>> {
+        Some(Arc::new(Self {
+            a: self.a.scaled_about(factor, about),
+            b: self.b.scaled_about(factor, about),
+            c: self.c.scaled_about(factor, about),
+            material: Arc::clone(&self.material),
+        }))
+    }
+
+    fn translated(&self, offset: &Vector) -> Option<Arc<dyn Hittable>> {
+        Some(Arc::new(Self {
+            a: self.a + *offset,
+            b: self.b + *offset,
+            c: self.c + *offset,
+            material: Arc::clone(&self.material),
+        }))
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "Triangle",
+            [self.a, self.b, self.c],
+            self.material.fingerprint()?,
+        )))
+    }
+}
+
+pub(super) fn triangle_box([a, b, c]: [&Point; 3]) -> Aabb {
+    Aabb::new(*a, *b).union(&Aabb::new(*c, *c))
+}
+
+/// Möller–Trumbore intersection shared by [`Triangle`] and [`Mesh`](super::Mesh).
+///
+/// The hit's (u, v) are the barycentric weights of `b` and `c`.
+pub(super) fn hit_triangle(
+    [a, b, c]: [&Point; 3],
+    material: &Arc<dyn Material>,
+    ray_t: Interval,
+    ray: &Ray,
+) -> Option<HitRecord> {
+    let edge1 = b - a;
+    let edge2 = c - a;
+    let p = ray.dir_v().cross(&edge2);
+    let det = edge1.dot(&p);
+
+    // Ray is parallel to the triangle's plane
+    if det.abs() < 1e-12 {
+        return None;
+    }
+
+    let inv_det = 1.0 / det;
+    let s = ray.origin() - a;
+    let u = s.dot(&p) * inv_det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let q = s.cross(&edge1);
+    let v = ray.dir_v().dot(&q) * inv_det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    let t = edge2.dot(&q) * inv_det;
+    if !ray_t.contains(t) {
+        return None;
+    }
+
+    let outward_normal = edge1.cross(&edge2).unit();
+    let front_face = ray.dir_v().dot(&outward_normal) < 0.0;
+    let normal = if front_face {
+        outward_normal
+    } else {
+        -outward_normal
+    };
+
+    let low = a.y().min(b.y()).min(c.y());
+    let high = a.y().max(b.y()).max(c.y());
+    Some(HitRecord {
+        point: ray.at(t),
+        normal,
+        t,
+        u,
+        v,
+        // Barycentric (u, v) cover half the unit square
+        uv_scale: 1.0 / edge1.cross(&edge2).len().sqrt(),
+        height: HitRecord::height_between(ray.at(t).y(), low, high),
+        front_face,
+        material: Arc::clone(material),
+    })
+}
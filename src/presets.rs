@@ -0,0 +1,32 @@
+//! Ready-made objects for common scene setups.
+
+use crate::color::Color;
+use crate::materials::Lambertian;
+use crate::objects::Plane;
+use crate::textures::{Checker, SolidColor};
+use crate::vector::Vector;
+
+/// Height of the floor created by [`checkered_floor`].
+pub const FLOOR_HEIGHT: f64 = -0.5;
+
+/// An infinite, upward-facing floor at `y = `[`FLOOR_HEIGHT`], checkered in
+/// dark green and off-white squares half a unit wide.
+///
+/// ```ignore
+/// use ray_tracing_rs::{presets, HittableList};
+///
+/// let mut world = HittableList::new();
+/// world.add(presets::checkered_floor());
+/// ```
+pub fn checkered_floor() -> Plane {
+    let checker = Checker::new(
+        2.0,
+        SolidColor::new(Color::new(0.2, 0.3, 0.1)),
+        SolidColor::new(Color::new(0.9, 0.9, 0.9)),
+    );
+    Plane::new(
+        Vector::new(0.0, 1.0, 0.0).unit(),
+        -FLOOR_HEIGHT,
+        Lambertian::textured(checker),
+    )
+}
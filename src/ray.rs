@@ -2,10 +2,16 @@
 //!
 //! Closely related to [`crate::material`] module. That module exports the type [`EmergentRay`] (design decisions on this might need to be reviewed).
 
+use log::debug;
+
+use crate::HittableList;
+use crate::background::{Background, EnvironmentMap};
 use crate::color::Color;
 use crate::materials::RayInteraction;
-use crate::objects::Hittable;
+use crate::objects::{HitRecord, Hittable};
+use crate::scene::DirectLighting;
 use crate::utils::interval::Interval;
+use crate::utils::rand;
 use crate::vector::{Point, UtVector, Vector};
 
 /// A struct for representing rays
@@ -15,12 +21,311 @@ use crate::vector::{Point, UtVector, Vector};
 pub struct Ray<'o> {
     origin: &'o Point,
     dir: UtVector,
+    time: f64,
+    differential: Option<RayDifferential>,
+    wavelength: Option<f64>,
+    ray_type: RayType,
+}
+
+/// How far [`spawn_point`] moves a ray's origin off a surface, relative to
+/// the largest coordinate of the hit point. Intersection errors grow with
+/// coordinate magnitude, at around one part in 10^16 for `f64`.
+const ADAPTIVE_OFFSET_SCALE: f64 = 1e-9;
+
+/// Where a ray leaving the surface at `record` towards `dir` starts.
+///
+/// With `surroundings` asking for adaptive offsets, the hit point is pushed along the geometric
+/// normal to the side `dir` leaves by, in proportion to its distance from the
+/// world origin, so the ray cannot hit the same surface again through rounding
+/// error however large the scene's coordinates. Otherwise this is the hit
+/// point itself, and only the flat 0.001 minimum distance guards against that.
+fn spawn_point(record: &HitRecord, dir: &UtVector, surroundings: &Surroundings) -> Point {
+    if !surroundings.adaptive_offset {
+        return record.point;
+    }
+    let p = record.point;
+    let magnitude = p.x().abs().max(p.y().abs()).max(p.z().abs()).max(1.0);
+    let offset = record.normal * (ADAPTIVE_OFFSET_SCALE * magnitude);
+    if dir.dot(&record.normal) < 0.0 {
+        p - offset
+    } else {
+        p + offset
+    }
+}
+
+/// Log what happened where a path hit `record`, if `surroundings` asks for
+/// bounce logging.
+fn log_bounce(
+    bounce: u32,
+    record: &HitRecord,
+    interaction: &RayInteraction,
+    surroundings: &Surroundings,
+) {
+    if !surroundings.log_bounces {
+        return;
+    }
+    let material = record.material.type_name();
+    match interaction {
+        RayInteraction::Absorbed => debug!(
+            "bounce {bounce}: hit {:?} on {material}, absorbed",
+            record.point
+        ),
+        RayInteraction::Scattered(emergent_ray) => debug!(
+            "bounce {bounce}: hit {:?} on {material}, attenuation {:?}",
+            record.point, emergent_ray.attenuation
+        ),
+    }
+}
+
+/// Light reaching paths from outside the scene's geometry, along with the
+/// per-render settings paths are traced with.
+#[derive(Clone, Debug)]
+pub(crate) struct Surroundings {
+    /// Constant fill light reflected at every surface a path scatters off.
+    pub(crate) ambient: Color,
+    /// Seen by camera rays that escape, if set.
+    pub(crate) primary_background: Option<Background>,
+    /// Seen by every other escaping ray.
+    pub(crate) background: Background,
+    /// Whether every bounce is logged, as with
+    /// [`RenderOptions::log_bounces`](crate::scene::RenderOptions::log_bounces).
+    pub(crate) log_bounces: bool,
+    /// Whether rays leaving a surface start off it, see [`spawn_point`].
+    pub(crate) adaptive_offset: bool,
+}
+
+impl Surroundings {
+    fn background_seen_by(&self, ray: &Ray) -> &Background {
+        match (&self.primary_background, ray.ray_type) {
+            (Some(primary), RayType::Camera) => primary,
+            _ => &self.background,
+        }
+    }
+}
+
+impl Default for Surroundings {
+    fn default() -> Self {
+        Self {
+            ambient: Color::new(0.0, 0.0, 0.0),
+            primary_background: None,
+            background: Background::default(),
+            log_bounces: false,
+            adaptive_offset: false,
+        }
+    }
+}
+
+/// Where next event estimation looks for direct light: the registered
+/// lights, the environment map escaping rays see, or an even mixture of both.
+#[derive(Clone, Copy)]
+pub(crate) struct LightSampler<'a> {
+    lights: Option<&'a dyn Hittable>,
+    environment: Option<&'a EnvironmentMap>,
+}
+
+impl<'a> LightSampler<'a> {
+    /// Sample `lights` only.
+    fn lights(lights: &'a dyn Hittable) -> Self {
+        Self {
+            lights: Some(lights),
+            environment: None,
+        }
+    }
+
+    /// What paths traced with `lights` and `surroundings` sample directly, or
+    /// `None` if they only find light by following material-sampled rays.
+    ///
+    /// An environment map background that gives off light is sampled along
+    /// with `lights`, and on its own if there are none but `direct_lighting`
+    /// asks for light sampling.
+    pub(crate) fn for_paths(
+        lights: Option<&'a HittableList>,
+        direct_lighting: DirectLighting,
+        surroundings: &'a Surroundings,
+    ) -> Option<Self> {
+        let environment = match &surroundings.background {
+            Background::Environment(map) if !map.is_black() => Some(&**map),
+            _ => None,
+        };
+        match lights {
+            None if environment.is_none() || direct_lighting == DirectLighting::MaterialOnly => {
+                None
+            }
+            lights => Some(Self {
+                lights: lights
+                    .filter(|lights| !lights.objects().is_empty())
+                    .map(|lights| lights as &dyn Hittable),
+                environment,
+            }),
+        }
+    }
+
+    /// Density, per solid angle, with which [`LightSampler::random_direction`]
+    /// picks `dir` from `origin`.
+    fn pdf_value(&self, origin: &Point, dir: &UtVector) -> f64 {
+        let lights = || {
+            self.lights
+                .map_or(0.0, |lights| lights.pdf_value(origin, dir))
+        };
+        match (self.lights, self.environment) {
+            (Some(_), Some(map)) => 0.5 * (lights() + map.pdf_value(dir)),
+            (_, Some(map)) => map.pdf_value(dir),
+            _ => lights(),
+        }
+    }
+
+    /// Pick a direction from `origin` towards a light or the bright parts of
+    /// the environment, or `None` if there is nothing to sample.
+    fn random_direction(&self, origin: &Point) -> Option<UtVector> {
+        match (self.lights, self.environment) {
+            (Some(lights), Some(map)) => {
+                if rand::random() < 0.5 {
+                    Some(map.random_direction())
+                } else {
+                    lights.random_direction(origin)
+                }
+            }
+            (_, Some(map)) => Some(map.random_direction()),
+            (Some(lights), None) => lights.random_direction(origin),
+            (None, None) => None,
+        }
+    }
+
+    /// Radiance a shadow ray sampled towards `dir` receives if it escapes the
+    /// world: the environment's if it is sampled, and none otherwise, since
+    /// material-sampled rays already account for the rest of the background.
+    fn escaped(&self, dir: &UtVector) -> Color {
+        self.environment
+            .map_or(Color::new(0.0, 0.0, 0.0), |map| map.radiance(dir))
+    }
+}
+
+/// What a ray is being traced for, so objects can choose which rays see them.
+///
+/// See [`Masked`](crate::objects::Masked).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RayType {
+    /// A primary ray from the camera.
+    #[default]
+    Camera,
+    /// A visibility probe, such as a shadow ray towards a light or an ambient occlusion ray.
+    Shadow,
+    /// A ray scattered off a surface by its material.
+    Reflection,
+}
+
+/// How a ray's direction changes when moving one pixel over in the image.
+///
+/// Primary rays carry these so textures can be filtered over the area a pixel
+/// covers, instead of being point-sampled and aliasing.
+#[derive(Clone, Copy, Debug)]
+pub struct RayDifferential {
+    pub dx: Vector,
+    pub dy: Vector,
 }
 
 impl<'o> Ray<'o> {
-    /// Creates a new [`Ray`].
+    /// Creates a new [`Ray`] at time 0.0.
     pub fn new(origin: &'o Point, dir: UtVector) -> Self {
-        Self { origin, dir }
+        Self::with_time(origin, dir, 0.0)
+    }
+
+    /// Creates a new [`Ray`] cast at `time`, for time-dependent scenes.
+    pub fn with_time(origin: &'o Point, dir: UtVector, time: f64) -> Self {
+        Self {
+            origin,
+            dir,
+            time,
+            differential: None,
+            wavelength: None,
+            ray_type: RayType::Camera,
+        }
+    }
+
+    /// Continue this ray from `origin` towards `dir`, keeping its time and wavelength.
+    ///
+    /// Materials use this to spawn scattered rays, so the child is a
+    /// [`RayType::Reflection`] ray.
+    pub fn child<'a>(&self, origin: &'a Point, dir: UtVector) -> Ray<'a> {
+        Ray {
+            origin,
+            dir,
+            time: self.time,
+            differential: None,
+            wavelength: self.wavelength,
+            ray_type: RayType::Reflection,
+        }
+    }
+
+    /// The same ray starting from `origin` instead.
+    fn with_origin<'a>(&self, origin: &'a Point) -> Ray<'a> {
+        Ray {
+            origin,
+            dir: self.dir,
+            time: self.time,
+            differential: self.differential,
+            wavelength: self.wavelength,
+            ray_type: self.ray_type,
+        }
+    }
+
+    /// Mark this ray as being traced for `ray_type`.
+    pub fn with_ray_type(mut self, ray_type: RayType) -> Self {
+        self.ray_type = ray_type;
+        self
+    }
+
+    /// What this ray is being traced for. Rays from [`Ray::new`] are camera rays.
+    pub fn ray_type(&self) -> RayType {
+        self.ray_type
+    }
+
+    /// Make this a monochromatic ray of the given wavelength, in nanometers,
+    /// for spectral rendering.
+    pub fn with_wavelength(mut self, wavelength: f64) -> Self {
+        self.wavelength = Some(wavelength);
+        self
+    }
+
+    /// The wavelength in nanometers this ray carries, or `None` for RGB rays.
+    pub fn wavelength(&self) -> Option<f64> {
+        self.wavelength
+    }
+
+    /// Attach direction differentials towards the neighbouring pixels.
+    pub fn with_differential(mut self, differential: RayDifferential) -> Self {
+        self.differential = Some(differential);
+        self
+    }
+
+    pub fn differential(&self) -> Option<&RayDifferential> {
+        self.differential.as_ref()
+    }
+
+    /// Approximate world-space width of the pixel footprint at `record`.
+    ///
+    /// The spread between neighbouring rays grows with distance and stretches
+    /// as the surface is seen at a grazing angle. Rays without differentials
+    /// (e.g. scattered rays) have a footprint of 0.0, meaning point sampling.
+    pub fn footprint(&self, record: &HitRecord) -> f64 {
+        let Some(differential) = self.differential else {
+            return 0.0;
+        };
+
+        let spread = differential.dx.len().max(differential.dy.len());
+        let cos_theta = self.dir.dot(&record.normal).abs().max(1e-3);
+        record.t * spread / cos_theta
+    }
+
+    /// [`Ray::footprint`] converted to a width in the surface's (u, v)
+    /// coordinates, as textures are filtered in.
+    pub fn uv_footprint(&self, record: &HitRecord) -> f64 {
+        self.footprint(record) * record.uv_scale
+    }
+
+    /// The time this ray was cast at. Child rays inherit it from their parent.
+    pub fn time(&self) -> f64 {
+        self.time
     }
 
     pub fn origin(&self) -> &Point {
@@ -36,10 +341,51 @@ impl<'o> Ray<'o> {
     }
 
     pub fn at(&self, t: f64) -> Point {
-        self.origin + (self.dir.inner() * t)
+        self.origin + (self.dir * t)
+    }
+
+    /// Whether anything in `world` lies along this ray closer than `t_max`.
+    ///
+    /// Meant for shadow rays toward a light at a known distance: occluders
+    /// beyond `t_max` are ignored and the search stops at the first hit.
+    pub fn hits_before(&self, world: &dyn Hittable, t_max: f64) -> bool {
+        world.hit_p(Interval::new(0.001, t_max), self)
     }
 
     pub fn color(&self, world: &dyn Hittable, bounce: u32) -> Color {
+        self.color_in(world, bounce, &Surroundings::default())
+    }
+
+    /// Like [`Ray::color`], but also returns the point of every surface the
+    /// path hit, in order, for visualizing it. A ray that escapes at once has
+    /// an empty path.
+    pub fn trace_path(&self, world: &dyn Hittable, bounce: u32) -> (Color, Vec<Point>) {
+        let mut path = Vec::new();
+        let color = self.trace(world, bounce, &Surroundings::default(), Some(&mut path));
+        (color, path)
+    }
+
+    /// Like [`Ray::color`], but every surface the path scatters off also
+    /// reflects the ambient light of `surroundings`, scaled by the material's
+    /// attenuation, and escaping rays see its backgrounds.
+    pub(crate) fn color_in(
+        &self,
+        world: &dyn Hittable,
+        bounce: u32,
+        surroundings: &Surroundings,
+    ) -> Color {
+        self.trace(world, bounce, surroundings, None)
+    }
+
+    /// [`Ray::color_in`], pushing the point of every surface hit onto `path`
+    /// if given.
+    fn trace(
+        &self,
+        world: &dyn Hittable,
+        bounce: u32,
+        surroundings: &Surroundings,
+        mut path: Option<&mut Vec<Point>>,
+    ) -> Color {
         // Limit the number of child rays
         if bounce == 0 {
             return Color::new(0.0, 0.0, 0.0);
@@ -47,23 +393,352 @@ impl<'o> Ray<'o> {
 
         // Use 0.001 instead of 0.0 to avoid shadow acne
         match world.hit(Interval::new(0.001, f64::MAX), self) {
+            Some(record) if !record.front_face && !record.material.shade_back_faces() => {
+                Color::new(0.0, 0.0, 0.0)
+            }
             Some(record) => {
                 use RayInteraction::*;
+                if let Some(path) = path.as_deref_mut() {
+                    path.push(record.point);
+                }
+                let emitted = record.material.emitted(self, &record);
                 // Self interacts with material, and send in corresponding record of its interaction (awkward)
-                match record.material.interact(self, &record) {
-                    Absorbed => Color::new(0.0, 0.0, 0.0),
+                let interaction = record.material.interact(self, &record);
+                log_bounce(bounce, &record, &interaction, surroundings);
+                match interaction {
+                    Absorbed => emitted,
                     Scattered(emergent_ray) => {
-                        emergent_ray.attenuation * emergent_ray.inner.color(world, bounce - 1)
+                        let origin = spawn_point(&record, emergent_ray.inner.dir(), surroundings);
+                        let incoming = emergent_ray.inner.with_origin(&origin).trace(
+                            world,
+                            bounce - 1,
+                            surroundings,
+                            path,
+                        );
+                        let ambient = surroundings.ambient;
+                        emitted + emergent_ray.attenuation * (incoming + ambient)
                     }
                 }
             }
             // Render the sky instead
-            None => {
-                let unit_direction = self.dir_v().unit();
-                let a = (unit_direction.y() + 1.0) * 0.5;
-                let b = Color::new(0.5, 0.70196, 1.0);
-                Color::new(1.0, 1.0, 1.0) * (1.0 - a) + b * a
+            None => self.escape(bounce, surroundings),
+        }
+    }
+
+    /// [`Ray::color`] split by the number of times light scattered before
+    /// reaching this ray: entry 0 is emission and sky seen directly, entry 1
+    /// is light reflected once, and so on.
+    ///
+    /// The entries sum to [`Ray::color`]'s estimate. Trailing depths that
+    /// received nothing are omitted, so the result may be shorter than `bounce`.
+    pub fn color_by_depth(&self, world: &dyn Hittable, bounce: u32) -> Vec<Color> {
+        let mut contributions = Vec::new();
+        let white = Color::new(1.0, 1.0, 1.0);
+        let surroundings = Surroundings::default();
+        self.collect_by_depth(world, bounce, 0, white, &surroundings, &mut contributions);
+        contributions
+    }
+
+    /// Add the light carried by this ray, `depth` scatterings from the
+    /// camera and weighted by `throughput`, to `contributions[depth]` and up.
+    fn collect_by_depth(
+        &self,
+        world: &dyn Hittable,
+        bounce: u32,
+        depth: usize,
+        throughput: Color,
+        surroundings: &Surroundings,
+        contributions: &mut Vec<Color>,
+    ) {
+        if bounce == 0 {
+            return;
+        }
+
+        let mut add = |light: Color| {
+            if contributions.len() <= depth {
+                contributions.resize(depth + 1, Color::new(0.0, 0.0, 0.0));
+            }
+            contributions[depth] += throughput * light;
+        };
+        let Some(record) = world.hit(Interval::new(0.001, f64::MAX), self) else {
+            add(self.escape(bounce, surroundings));
+            return;
+        };
+        if !record.front_face && !record.material.shade_back_faces() {
+            return;
+        }
+
+        add(record.material.emitted(self, &record));
+        let interaction = record.material.interact(self, &record);
+        log_bounce(bounce, &record, &interaction, surroundings);
+        if let RayInteraction::Scattered(emergent_ray) = interaction {
+            let origin = spawn_point(&record, emergent_ray.inner.dir(), surroundings);
+            emergent_ray.inner.with_origin(&origin).collect_by_depth(
+                world,
+                bounce - 1,
+                depth + 1,
+                throughput * emergent_ray.attenuation,
+                surroundings,
+                contributions,
+            );
+        }
+    }
+
+    /// Like [`Ray::color`], but also samples `lights` directly at surfaces that
+    /// report a [`Material::scattering_pdf`](crate::materials::Material::scattering_pdf).
+    ///
+    /// `strategy` selects how the light-sampled and material-sampled estimates
+    /// of direct light are combined.
+    pub fn color_with_lights(
+        &self,
+        world: &dyn Hittable,
+        lights: &dyn Hittable,
+        bounce: u32,
+        strategy: DirectLighting,
+    ) -> Color {
+        let surroundings = Surroundings::default();
+        let lights = LightSampler::lights(lights);
+        self.color_nee(world, &lights, bounce, strategy, &surroundings, None)
+    }
+
+    /// `surroundings` are used as in [`Ray::color_in`]. `scatter_pdf` is the
+    /// material pdf with which the previous vertex chose this ray, or `None`
+    /// for camera rays and discrete (specular) bounces.
+    pub(crate) fn color_nee(
+        &self,
+        world: &dyn Hittable,
+        lights: &LightSampler,
+        bounce: u32,
+        strategy: DirectLighting,
+        surroundings: &Surroundings,
+        scatter_pdf: Option<f64>,
+    ) -> Color {
+        if bounce == 0 {
+            return Color::new(0.0, 0.0, 0.0);
+        }
+
+        let Some(record) = world.hit(Interval::new(0.001, f64::MAX), self) else {
+            // Light from a sampled environment is weighted like emission
+            let weight = mis_weight(
+                scatter_pdf,
+                lights.pdf_value(self.origin, &self.dir),
+                strategy,
+            );
+            return self.escape(bounce, surroundings) * weight;
+        };
+        if !record.front_face && !record.material.shade_back_faces() {
+            return Color::new(0.0, 0.0, 0.0);
+        }
+        if record.material.catches_shadows() {
+            // The continued ray keeps this ray's type, so the camera still
+            // sees its own background through the catcher
+            let origin = spawn_point(&record, &self.dir, surroundings);
+            let behind = self
+                .child(&origin, self.dir)
+                .with_ray_type(self.ray_type)
+                .color_nee(
+                    world,
+                    lights,
+                    bounce - 1,
+                    strategy,
+                    surroundings,
+                    scatter_pdf,
+                );
+            return behind * self.light_visibility(world, lights, &record, surroundings);
+        }
+
+        // Emission found by material sampling, weighted against the chance that
+        // light sampling at the previous vertex already accounted for it
+        let emitted = record.material.emitted(self, &record);
+        let emitted_weight = mis_weight(
+            scatter_pdf,
+            lights.pdf_value(self.origin, &self.dir),
+            strategy,
+        );
+        let emitted = emitted * emitted_weight;
+
+        let interaction = record.material.interact(self, &record);
+        log_bounce(bounce, &record, &interaction, surroundings);
+        let emergent_ray = match interaction {
+            RayInteraction::Absorbed => return emitted,
+            RayInteraction::Scattered(emergent_ray) => emergent_ray,
+        };
+        let attenuation = emergent_ray.attenuation;
+        let origin = spawn_point(&record, emergent_ray.inner.dir(), surroundings);
+        let scattered = emergent_ray.inner.with_origin(&origin);
+
+        let Some(material_pdf) =
+            record
+                .material
+                .scattering_pdf(self, &record, emergent_ray.inner.dir())
+        else {
+            let indirect =
+                scattered.color_nee(world, lights, bounce - 1, strategy, surroundings, None);
+            return emitted + attenuation * (indirect + surroundings.ambient);
+        };
+
+        let direct = match strategy {
+            DirectLighting::MaterialOnly => Color::new(0.0, 0.0, 0.0),
+            _ => self.sample_light(world, lights, &record, attenuation, strategy, surroundings),
+        };
+        let indirect = scattered.color_nee(
+            world,
+            lights,
+            bounce - 1,
+            strategy,
+            surroundings,
+            Some(material_pdf),
+        );
+
+        emitted + direct + attenuation * (indirect + surroundings.ambient)
+    }
+
+    /// One light-sampled estimate of the direct light arriving at `record`.
+    ///
+    /// `attenuation` is what [`Material::interact`](crate::materials::Material::interact)
+    /// returned, i.e. the material's response divided by its sampling pdf.
+    fn sample_light(
+        &self,
+        world: &dyn Hittable,
+        lights: &LightSampler,
+        record: &HitRecord,
+        attenuation: Color,
+        strategy: DirectLighting,
+        surroundings: &Surroundings,
+    ) -> Color {
+        let black = Color::new(0.0, 0.0, 0.0);
+        let Some(dir) = lights.random_direction(&record.point) else {
+            return black;
+        };
+        let light_pdf = lights.pdf_value(&record.point, &dir);
+        let material_pdf = record
+            .material
+            .scattering_pdf(self, record, &dir)
+            .unwrap_or(0.0);
+        if light_pdf <= 0.0 || material_pdf <= 0.0 {
+            return black;
+        }
+
+        let origin = spawn_point(record, &dir, surroundings);
+        let shadow_ray = self.child(&origin, dir).with_ray_type(RayType::Shadow);
+        let radiance = match shadow_hit(world, &shadow_ray) {
+            Some(light_record) => light_record.material.emitted(&shadow_ray, &light_record),
+            None => lights.escaped(&dir),
+        };
+
+        let weight = match strategy {
+            DirectLighting::Mis => power_heuristic(light_pdf, material_pdf),
+            _ => 1.0,
+        };
+        attenuation * radiance * (material_pdf * weight / light_pdf)
+    }
+
+    /// 1.0 if a shadow ray from the hit at `record` toward a sampled point on
+    /// `lights` reaches an emitter, and 0.0 if something dark blocks it first.
+    ///
+    /// Points with no light to sample, or whose shadow ray escapes the world,
+    /// count as lit.
+    pub(crate) fn light_visibility(
+        &self,
+        world: &dyn Hittable,
+        lights: &LightSampler,
+        record: &HitRecord,
+        surroundings: &Surroundings,
+    ) -> f64 {
+        let Some(dir) = lights.random_direction(&record.point) else {
+            return 1.0;
+        };
+        let origin = spawn_point(record, &dir, surroundings);
+        let shadow_ray = self.child(&origin, dir).with_ray_type(RayType::Shadow);
+        match shadow_hit(world, &shadow_ray) {
+            None => 1.0,
+            Some(blocker) => {
+                let emitted = blocker.material.emitted(&shadow_ray, &blocker);
+                let lit = emitted.r() + emitted.g() + emitted.b() > 0.0;
+                if lit { 1.0 } else { 0.0 }
             }
         }
     }
+
+    /// Grayscale ambient occlusion at this ray's first hit.
+    ///
+    /// Shoots `samples` rays over the hemisphere around the hit normal and
+    /// returns the fraction that travel `radius` without hitting anything.
+    pub fn ambient_occlusion(&self, world: &dyn Hittable, radius: f64, samples: u32) -> Color {
+        self.ambient_occlusion_in(world, radius, samples, &Surroundings::default())
+    }
+
+    /// Like [`Ray::ambient_occlusion`], with occlusion rays leaving the
+    /// surface as `surroundings` asks.
+    pub(crate) fn ambient_occlusion_in(
+        &self,
+        world: &dyn Hittable,
+        radius: f64,
+        samples: u32,
+        surroundings: &Surroundings,
+    ) -> Color {
+        let Some(record) = world.hit(Interval::new(0.001, f64::MAX), self) else {
+            return Color::new(1.0, 1.0, 1.0);
+        };
+        if samples == 0 {
+            return Color::new(1.0, 1.0, 1.0);
+        }
+
+        let unoccluded = (0..samples)
+            .filter(|_| {
+                let dir = Vector::random_on_hemisphere(record.normal.inner()).unit();
+                let origin = spawn_point(&record, &dir, surroundings);
+                let probe = Ray::new(&origin, dir).with_ray_type(RayType::Shadow);
+                !probe.hits_before(world, radius)
+            })
+            .count();
+
+        let visibility = unoccluded as f64 / samples as f64;
+        Color::new(visibility, visibility, visibility)
+    }
+
+    /// The background seen by a path leaving the scene, logged if bounce
+    /// logging is on.
+    fn escape(&self, bounce: u32, surroundings: &Surroundings) -> Color {
+        let sky = surroundings.background_seen_by(self).radiance(&self.dir);
+        if surroundings.log_bounces {
+            debug!("bounce {bounce}: escaped to sky {sky:?}");
+        }
+        sky
+    }
+}
+
+/// The first surface `shadow_ray` meets, seeing through shadow catchers,
+/// which receive shadows but never cast them.
+fn shadow_hit(world: &dyn Hittable, shadow_ray: &Ray) -> Option<HitRecord> {
+    let mut t_min = 0.001;
+    loop {
+        let record = world.hit(Interval::new(t_min, f64::MAX), shadow_ray)?;
+        if !record.material.catches_shadows() {
+            return Some(record);
+        }
+        t_min = record.t.max(t_min) + 0.001;
+    }
+}
+
+/// How much of the light found by a material-sampled ray counts, given the
+/// material pdf it was chosen with (`None` if light sampling could not have
+/// chosen it) and the pdf with which light sampling picks its direction.
+fn mis_weight(scatter_pdf: Option<f64>, light_pdf: f64, strategy: DirectLighting) -> f64 {
+    let Some(material_pdf) = scatter_pdf else {
+        return 1.0;
+    };
+    match strategy {
+        _ if light_pdf <= 0.0 => 1.0,
+        DirectLighting::MaterialOnly => 1.0,
+        DirectLighting::LightOnly => 0.0,
+        DirectLighting::Mis => power_heuristic(material_pdf, light_pdf),
+    }
+}
+
+/// Power heuristic (with exponent 2) weight for a sample drawn with density `pdf`
+/// when another strategy could have drawn it with density `other_pdf`.
+fn power_heuristic(pdf: f64, other_pdf: f64) -> f64 {
+    let (a, b) = (pdf * pdf, other_pdf * other_pdf);
+    a / (a + b)
 }
@@ -6,23 +6,51 @@
 //! 2. Assemble them into a [`CameraConfig`].
 //! 3. Build a reusable [`Camera`].
 //! 4. Render that camera against any world implementing [`Hittable`].
+//!
+//! With the `gltf` feature enabled, worlds can also be imported from glTF files
+//! through [`gltf::load`]. Procedural worlds live in [`generate`], and custom
+//! shading algorithms plug in through [`integrator`].
+
+pub mod generate;
+#[cfg(feature = "gltf")]
+pub mod gltf;
+pub mod integrator;
 
 use std::{
+    fmt,
     fs::{self, OpenOptions},
     io::{self, Write},
     path::Path,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU32, Ordering},
+    },
+    time::{Duration, Instant},
 };
 
-use log::info;
+use log::{debug, info};
 use rayon::prelude::*;
 use thiserror::Error;
 
-use crate::color::Color;
+use crate::HittableList;
+use crate::background::Background;
+use crate::color::{Color, ColorSum, GamutMapping, VISIBLE_WAVELENGTHS};
+use crate::image::{Image, write_ppm_header, write_ppm_pixel};
 use crate::objects::Hittable;
-use crate::ray::Ray;
+use crate::ray::{LightSampler, Ray, RayDifferential, Surroundings};
+use crate::utils::interval::Interval;
 use crate::utils::{self, rand};
 use crate::vector::{Point, UtVector, Vector};
 
+use integrator::{Integrator, PathTracer};
+
+/// Number of bounces a primary ray may take before it is cut off.
+const MAX_DEPTH: u32 = 50;
+
+/// Fewest pixels per thread for which [`RenderOptions::parallel_samples`]
+/// leaves the samples of each pixel sequential.
+const PIXELS_PER_THREAD: usize = 32;
+
 /// Output image dimensions and sampling settings used by a [`Camera`].
 ///
 /// Dimensions are validated up front so a camera can safely accept fresh
@@ -32,6 +60,7 @@ use crate::vector::{Point, UtVector, Vector};
 pub struct ImageOptions {
     width: u32,
     height: u32,
+    max_pixels: u64,
     antialias: AntialiasOptions,
 }
 
@@ -43,9 +72,14 @@ enum AntialiasOptions {
 }
 
 impl ImageOptions {
+    /// Largest image, in pixels, accepted by [`ImageOptions::new`] (256M pixels).
+    pub const DEFAULT_MAX_PIXELS: u64 = 256 * 1024 * 1024;
+
     /// Create a new set of image options.
     ///
-    /// Returns [`ConfigError::InvalidImageDimensions`] when either dimension is 0.
+    /// Returns [`ConfigError::InvalidImageDimensions`] when either dimension is 0,
+    /// and [`ConfigError::ImageTooLarge`] when the image has more than
+    /// [`ImageOptions::DEFAULT_MAX_PIXELS`] pixels.
     ///
     /// ```rs
     /// # use ray_tracing_rs::ImageOptions;
@@ -53,13 +87,25 @@ impl ImageOptions {
     /// # Ok::<(), ray_tracing_rs::ConfigError>(())
     /// ```
     pub fn new(width: u32, height: u32) -> Result<Self, ConfigError> {
+        Self::with_max_pixels(width, height, Self::DEFAULT_MAX_PIXELS)
+    }
+
+    /// Create a new set of image options with a custom cap on `width * height`.
+    ///
+    /// Pixels are indexed with `u32`, so the cap can never exceed `u32::MAX`.
+    pub fn with_max_pixels(width: u32, height: u32, max_pixels: u64) -> Result<Self, ConfigError> {
         if width == 0 || height == 0 {
             return Err(ConfigError::InvalidImageDimensions);
         }
+        let max_pixels = max_pixels.min(u32::MAX as u64);
+        if width as u64 * height as u64 > max_pixels {
+            return Err(ConfigError::ImageTooLarge);
+        }
 
         Ok(Self {
             width,
             height,
+            max_pixels,
             antialias: AntialiasOptions::Disabled,
         })
     }
@@ -69,6 +115,29 @@ impl ImageOptions {
         self.width as f64 / self.height as f64
     }
 
+    /// Image width in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Image height in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The cap on `width * height` these options were validated against.
+    pub fn max_pixels(&self) -> u64 {
+        self.max_pixels
+    }
+
+    /// Antialiasing samples per pixel, or 0 when antialiasing is disabled.
+    pub fn samples_per_pixel(&self) -> u32 {
+        match self.antialias {
+            AntialiasOptions::Disabled => 0,
+            AntialiasOptions::Enabled(spp) => spp,
+        }
+    }
+
     /// Configure antialiasing samples per pixel.
     ///
     /// Specifying 0 disables antialiasing, which is also the default.
@@ -88,13 +157,92 @@ impl ImageOptions {
     }
 }
 
-/// Render-time scheduling options.
+/// Render-time options.
 ///
 /// These do not change the rays a camera emits, only how the pixel work is
-/// scheduled and written.
+/// scheduled and written, and how each primary ray is shaded.
 #[derive(Clone, Debug)]
 pub struct RenderOptions {
     parallel: ParallelOptions,
+    pass: RenderPass,
+    lights: Option<HittableList>,
+    direct_lighting: DirectLighting,
+    transparent_background: bool,
+    spectral: bool,
+    sample_map: Option<SampleMap>,
+    flag_invalid: bool,
+    gamut_mapping: GamutMapping,
+    embed_metadata: bool,
+    clamp: Option<f64>,
+    surroundings: Surroundings,
+    integrator: Option<CustomIntegrator>,
+    parallel_samples: bool,
+    seed: Option<u64>,
+    cancel: Option<Arc<AtomicBool>>,
+    #[cfg(feature = "indicatif")]
+    progress_bar: Option<indicatif::ProgressBar>,
+}
+
+/// Per-pixel sample counts set through [`RenderOptions::sample_map`].
+#[derive(Clone)]
+struct SampleMap(Arc<dyn Fn(u32, u32) -> u32 + Send + Sync>);
+
+impl fmt::Debug for SampleMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SampleMap(..)")
+    }
+}
+
+/// An integrator set through [`RenderOptions::integrator`].
+#[derive(Clone)]
+struct CustomIntegrator(Arc<dyn Integrator>);
+
+impl fmt::Debug for CustomIntegrator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CustomIntegrator(..)")
+    }
+}
+
+/// How direct light is estimated at diffuse surfaces when lights are registered
+/// through [`RenderOptions::lights`] or the background is an environment map.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DirectLighting {
+    /// Only find lights by following material-sampled rays (no next event estimation).
+    #[default]
+    MaterialOnly,
+    /// Only sample lights directly; emission hit by material-sampled rays is ignored.
+    LightOnly,
+    /// Combine both strategies with multiple importance sampling, weighted by the power heuristic.
+    Mis,
+}
+
+/// What a render computes for each primary ray.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum RenderPass {
+    /// Full path-traced shading through materials.
+    #[default]
+    Beauty,
+    /// Grayscale ambient occlusion. For each primary hit, `samples` hemisphere
+    /// rays are shot and the pixel is the fraction that travel `radius` without
+    /// hitting geometry. Primary rays that miss are white.
+    AmbientOcclusion { radius: f64, samples: u32 },
+    /// Surface normals at primary hits, mapped from `[-1, 1]` to `[0, 1]` per
+    /// component. Primary rays that miss are black.
+    Normals { space: NormalSpace },
+    /// A matte of the geometry: white where primary rays hit anything and
+    /// black where they miss, without shading. Antialiased edges are gray.
+    Coverage,
+}
+
+/// The coordinate frame normals are expressed in by [`RenderPass::Normals`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NormalSpace {
+    /// World axes, so a surface's color depends only on its orientation in the scene.
+    #[default]
+    World,
+    /// The camera's right, up and backward axes, so surfaces facing the camera
+    /// are always `(0.5, 0.5, 1.0)` wherever it points.
+    Camera,
 }
 
 #[derive(Clone, Debug)]
@@ -112,6 +260,23 @@ impl RenderOptions {
     pub fn new() -> Self {
         Self {
             parallel: ParallelOptions::ByRows,
+            pass: RenderPass::default(),
+            lights: None,
+            direct_lighting: DirectLighting::default(),
+            transparent_background: false,
+            spectral: false,
+            sample_map: None,
+            flag_invalid: false,
+            gamut_mapping: GamutMapping::default(),
+            embed_metadata: false,
+            clamp: None,
+            surroundings: Surroundings::default(),
+            integrator: None,
+            parallel_samples: false,
+            seed: None,
+            cancel: None,
+            #[cfg(feature = "indicatif")]
+            progress_bar: None,
         }
     }
 
@@ -120,6 +285,218 @@ impl RenderOptions {
         self.parallel = config;
         self
     }
+
+    /// Also split each pixel's antialiasing samples across threads when the
+    /// image has too few pixels to keep every thread busy, such as small
+    /// previews at a high sample count.
+    pub fn parallel_samples(mut self, parallel_samples: bool) -> Self {
+        self.parallel_samples = parallel_samples;
+        self
+    }
+
+    /// Make renders repeat exactly, however their work is split across
+    /// threads.
+    ///
+    /// Each sample of each pixel draws its random numbers from a generator
+    /// seeded from `seed`, the pixel and the sample's index, instead of from
+    /// whichever thread happens to trace it.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Report [`ParallelOptions::ByRows`] file renders on `bar`, advancing it
+    /// once per row. Its length is reset to the image height.
+    ///
+    /// Without this, by-rows renders draw a default bar to stderr.
+    #[cfg(feature = "indicatif")]
+    pub fn progress_bar(mut self, bar: indicatif::ProgressBar) -> Self {
+        self.progress_bar = Some(bar);
+        self
+    }
+
+    /// Register the emissive objects to sample directly with next event estimation.
+    ///
+    /// `lights` should hold copies of light-emitting objects that are also in the
+    /// world. Without lights, renders only find emitters by material sampling.
+    ///
+    /// An [`Environment`](Background::Environment) background is sampled by
+    /// brightness along with the lights, and on its own when none are
+    /// registered but [`RenderOptions::direct_lighting`] asks for light
+    /// sampling.
+    pub fn lights(mut self, lights: HittableList) -> Self {
+        self.lights = Some(lights);
+        self
+    }
+
+    /// Register one more light, as with [`RenderOptions::lights`]. Lights are
+    /// only sampled directly with a [`RenderOptions::direct_lighting`] other
+    /// than the default [`DirectLighting::MaterialOnly`].
+    ///
+    /// Pass the same `Arc` that was added to the world with
+    /// [`HittableList::add_shared`], so the light is stored once. Spheres and
+    /// triangles can be sampled, so a quad light can be registered as two
+    /// triangles.
+    pub fn add_light(mut self, light: Arc<dyn Hittable>) -> Self {
+        self.lights
+            .get_or_insert_with(HittableList::new)
+            .add_shared(light);
+        self
+    }
+
+    /// Choose how direct light from [`RenderOptions::lights`] is estimated.
+    pub fn direct_lighting(mut self, direct_lighting: DirectLighting) -> Self {
+        self.direct_lighting = direct_lighting;
+        self
+    }
+
+    /// Make pixels whose primary rays miss all geometry transparent.
+    ///
+    /// File renders are then written as RGBA PAM (`P7`) instead of P3 PPM, with
+    /// alpha set to the fraction of a pixel's primary rays that hit something.
+    pub fn transparent_background(mut self, transparent: bool) -> Self {
+        self.transparent_background = transparent;
+        self
+    }
+
+    /// Trace each primary ray at a single random wavelength.
+    ///
+    /// This lets [`Dielectric::cauchy`](crate::materials::Dielectric::cauchy)
+    /// glass disperse light, at the cost of extra color noise.
+    pub fn spectral(mut self, spectral: bool) -> Self {
+        self.spectral = spectral;
+        self
+    }
+
+    /// Choose the number of samples for each pixel `(x, y)`, overriding the
+    /// image's antialiasing setting.
+    ///
+    /// Useful for previews that spend samples only on a region of interest.
+    /// Samples are jittered only when antialiasing is enabled on the image.
+    pub fn sample_map(mut self, map: impl Fn(u32, u32) -> u32 + Send + Sync + 'static) -> Self {
+        self.sample_map = Some(SampleMap(Arc::new(map)));
+        self
+    }
+
+    /// Debug aid: render pixels whose color is NaN or infinite as bright
+    /// magenta, instead of letting them clamp to black unnoticed.
+    pub fn flag_invalid(mut self, flag_invalid: bool) -> Self {
+        self.flag_invalid = flag_invalid;
+        self
+    }
+
+    /// Bring pixels outside the displayable range into it with `mapping` when
+    /// file renders convert them to 8-bit channels. Defaults to
+    /// [`GamutMapping::Clip`], which clamps each channel.
+    ///
+    /// Pixels are mapped only once they are final, so in-memory renders and
+    /// accumulated passes keep the values as computed.
+    pub fn gamut_mapping(mut self, mapping: GamutMapping) -> Self {
+        self.gamut_mapping = mapping;
+        self
+    }
+
+    /// Debug aid: log every bounce of every path at debug level, with the hit
+    /// point, material type and attenuation. Far too verbose for whole images;
+    /// meant for [`Camera::debug_pixel_with_options`].
+    pub fn log_bounces(mut self, log_bounces: bool) -> Self {
+        self.surroundings.log_bounces = log_bounces;
+        self
+    }
+
+    /// Start rays leaving a surface slightly off it, by a distance that grows
+    /// with the hit point's coordinates, instead of relying only on the flat
+    /// 0.001 minimum hit distance.
+    ///
+    /// This removes shadow acne from scenes with very large coordinates, where
+    /// rounding error in the hit point exceeds 0.001. Off by default.
+    pub fn adaptive_ray_offset(mut self, adaptive: bool) -> Self {
+        self.surroundings.adaptive_offset = adaptive;
+        self
+    }
+
+    /// Override what is computed per primary ray.
+    pub fn pass(mut self, pass: RenderPass) -> Self {
+        self.pass = pass;
+        self
+    }
+
+    /// Clamp every channel of each sample's radiance to at most `max` before
+    /// it is averaged into its pixel.
+    ///
+    /// This removes fireflies, the isolated bright pixels left by rare
+    /// high-energy paths such as caustics, at the cost of bias: clamped energy
+    /// is lost, so bright highlights and lights seen directly come out darker
+    /// than they should, and the render no longer converges to the true image.
+    pub fn clamp_indirect(mut self, max: f64) -> Self {
+        self.clamp = Some(max);
+        self
+    }
+
+    /// Add a constant `ambient` light at every surface a path scatters off,
+    /// scaled by the material's attenuation. Defaults to black (none).
+    ///
+    /// This is non-physical fill light: it lifts shadows evenly, which suits
+    /// product-style renders, but flattens the image as it grows.
+    pub fn ambient(mut self, ambient: Color) -> Self {
+        self.surroundings.ambient = ambient;
+        self
+    }
+
+    /// Set what rays escaping the scene see. Defaults to [`Background::Sky`].
+    pub fn background(mut self, background: Background) -> Self {
+        self.surroundings.background = background;
+        self
+    }
+
+    /// Show camera rays a different background from the one set with
+    /// [`RenderOptions::background`], which reflections and lighting then
+    /// keep using.
+    ///
+    /// This puts a detailed backdrop behind the scene without tinting it.
+    pub fn primary_background(mut self, background: Background) -> Self {
+        self.surroundings.primary_background = Some(background);
+        self
+    }
+
+    /// Shade every primary ray with `integrator` instead of the built-in
+    /// passes.
+    ///
+    /// This replaces [`RenderOptions::pass`], and with it the path tracer's
+    /// [`lights`](RenderOptions::lights), [`direct_lighting`](RenderOptions::direct_lighting)
+    /// and [`ambient`](RenderOptions::ambient) settings; configure a
+    /// [`PathTracer`](integrator::PathTracer) directly to combine them.
+    pub fn integrator(mut self, integrator: impl Integrator + 'static) -> Self {
+        self.integrator = Some(CustomIntegrator(Arc::new(integrator)));
+        self
+    }
+
+    /// Record the render settings in a `# ray-tracing-rs spp=.. depth=..`
+    /// comment after the PPM magic number. PPM readers skip comments.
+    ///
+    /// `spp` is the image's antialiasing setting, even when a
+    /// [`RenderOptions::sample_map`] overrides it per pixel.
+    pub fn embed_metadata(mut self, embed_metadata: bool) -> Self {
+        self.embed_metadata = embed_metadata;
+        self
+    }
+
+    /// Stop file renders early once `flag` is set, such as from another
+    /// thread or a UI, returning [`RenderError::Cancelled`] instead of
+    /// finishing the image.
+    ///
+    /// Pixels already being shaded when the flag is set are finished, so the
+    /// render stops within about a row's worth of work.
+    pub fn cancel_flag(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.cancel = Some(flag);
+        self
+    }
+
+    fn cancelled(&self) -> bool {
+        self.cancel
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Relaxed))
+    }
 }
 
 impl Default for RenderOptions {
@@ -131,10 +508,13 @@ impl Default for RenderOptions {
 /// Validated camera orientation and basis vectors.
 ///
 /// This stores the camera position along with the orthonormal basis derived
-/// from `look_from`, `look_at`, and `up`.
+/// from `look_from`, `look_at`, and `up`, turned by the camera's roll.
 #[derive(Clone, Copy, Debug)]
 pub struct CameraPose {
     center: Point,
+    target: Point,
+    up: Vector,
+    roll: f64,
     u: UtVector,
     v: UtVector,
     w: UtVector,
@@ -165,13 +545,37 @@ impl CameraPose {
         let u = u_direction.unit();
         let v = w.cross(&u).unit();
 
+        // Finite inputs can still overflow while normalizing, e.g. when the
+        // points are so far apart that their difference is infinite
+        if !u.is_finite() || !v.is_finite() || !w.is_finite() {
+            return Err(ConfigError::DegenerateBasis);
+        }
+
         Ok(Self {
             center: look_from,
+            target: look_at,
+            up,
+            roll: 0.0,
             u,
             v,
             w,
         })
     }
+
+    /// The same pose rolled `degrees` about the view direction (a Dutch
+    /// angle), replacing any earlier roll.
+    ///
+    /// Positive angles tilt the camera counter-clockwise, so the scene appears
+    /// turned clockwise. The view direction and position are unchanged.
+    pub fn rolled(mut self, degrees: f64) -> Self {
+        // Undo the current roll to get back to the basis levelled by `up`
+        let (sin, cos) = utils::degrees_to_radians(degrees - self.roll).sin_cos();
+        let (u, v) = (self.u.relax(), self.v.relax());
+        self.u = (u * cos + v * sin).unit();
+        self.v = (v * cos - u * sin).unit();
+        self.roll = degrees;
+        self
+    }
 }
 
 /// Validated perspective projection settings for a camera.
@@ -191,6 +595,25 @@ impl PerspectiveProjection {
 
         Ok(Self { vfov: vfov_degrees })
     }
+
+    /// Create a perspective projection matching a physical lens of
+    /// `focal_mm` on a sensor `sensor_mm` tall, both in millimeters.
+    ///
+    /// `sensor_mm` is the sensor's extent along the image's vertical axis, so a
+    /// landscape shot on a 36x24mm full-frame sensor uses 24. Returns
+    /// [`ConfigError::InvalidLens`] unless both are finite and greater than zero.
+    pub fn lens(focal_mm: f64, sensor_mm: f64) -> Result<Self, ConfigError> {
+        if !focal_mm.is_finite() || !sensor_mm.is_finite() || focal_mm <= 0.0 || sensor_mm <= 0.0 {
+            return Err(ConfigError::InvalidLens);
+        }
+
+        Self::new((2.0 * (sensor_mm / (2.0 * focal_mm)).atan()).to_degrees())
+    }
+
+    /// The vertical field of view in degrees.
+    pub fn vfov_degrees(&self) -> f64 {
+        self.vfov
+    }
 }
 
 /// Optical camera model settings.
@@ -263,6 +686,14 @@ pub struct CameraConfig {
     image: ImageOptions,
     projection: PerspectiveProjection,
     model: CameraModel,
+    viewport: Option<ViewportSize>,
+}
+
+/// Explicit viewport dimensions, in world units on the projection plane.
+#[derive(Clone, Copy, Debug)]
+struct ViewportSize {
+    width: f64,
+    height: f64,
 }
 
 impl CameraConfig {
@@ -278,7 +709,25 @@ impl CameraConfig {
             image,
             projection,
             model,
+            viewport: None,
+        }
+    }
+
+    /// Override the viewport dimensions instead of deriving them from the
+    /// vertical field of view and image aspect ratio.
+    ///
+    /// When the viewport aspect differs from the image aspect, the render is
+    /// stretched accordingly, which allows anamorphic renders.
+    ///
+    /// Returns [`ConfigError::InvalidViewportSize`] unless both dimensions are
+    /// finite and greater than zero.
+    pub fn viewport_size(mut self, width: f64, height: f64) -> Result<Self, ConfigError> {
+        if !width.is_finite() || !height.is_finite() || width <= 0.0 || height <= 0.0 {
+            return Err(ConfigError::InvalidViewportSize);
         }
+
+        self.viewport = Some(ViewportSize { width, height });
+        Ok(self)
     }
 }
 
@@ -292,6 +741,7 @@ pub struct Camera {
     pose: CameraPose,
     projection: PerspectiveProjection,
     model: CameraModel,
+    viewport: Option<ViewportSize>,
     viewport_u: Vector,
     viewport_v: Vector,
     pixel_delta_u: Vector,
@@ -300,7 +750,6 @@ pub struct Camera {
     defocus_disk_v: Vector,
     viewport_upper_left: Point,
     pixel00_loc: Point,
-    pixel_samples_scale: Option<f64>,
     image_options: ImageOptions,
 }
 
@@ -311,6 +760,7 @@ impl Camera {
             pose: config.pose,
             projection: config.projection,
             model: config.model,
+            viewport: config.viewport,
             viewport_u: Vector::new(0.0, 0.0, 0.0),
             viewport_v: Vector::new(0.0, 0.0, 0.0),
             pixel_delta_u: Vector::new(0.0, 0.0, 0.0),
@@ -319,7 +769,6 @@ impl Camera {
             defocus_disk_v: Vector::new(0.0, 0.0, 0.0),
             viewport_upper_left: Point::new(0.0, 0.0, 0.0),
             pixel00_loc: Point::new(0.0, 0.0, 0.0),
-            pixel_samples_scale: None,
             image_options: config.image,
         };
         camera.recompute_geometry();
@@ -332,37 +781,342 @@ impl Camera {
         self.recompute_geometry();
     }
 
+    /// Turn the camera to face `target`, keeping its position and up vector.
+    ///
+    /// Fails like [`CameraPose::look_at`], leaving the camera unchanged.
+    pub fn look_at(&mut self, target: Point) -> Result<(), ConfigError> {
+        self.pose =
+            CameraPose::look_at(self.pose.center, target, self.pose.up)?.rolled(self.pose.roll);
+        self.recompute_geometry();
+        Ok(())
+    }
+
+    /// Move the camera to `position`, keeping the direction it faces.
+    ///
+    /// The point it looks at moves along with it.
+    pub fn move_to(&mut self, position: Point) -> Result<(), ConfigError> {
+        if !position.is_finite() {
+            return Err(ConfigError::NonFinitePose);
+        }
+        self.pose.target += position - self.pose.center;
+        self.pose.center = position;
+        self.recompute_geometry();
+        Ok(())
+    }
+
+    /// Roll the camera `degrees` about its view direction, as with
+    /// [`CameraPose::rolled`], and recompute the viewport.
+    ///
+    /// The roll is absolute: setting 0.0 levels the camera with its up vector
+    /// again. It is kept when the camera is turned, moved or orbited.
+    pub fn set_roll(&mut self, degrees: f64) {
+        self.pose = self.pose.rolled(degrees);
+        self.recompute_geometry();
+    }
+
+    /// The camera's roll in degrees; see [`Camera::set_roll`].
+    pub fn roll(&self) -> f64 {
+        self.pose.roll
+    }
+
+    /// Where the camera is.
+    pub fn position(&self) -> Point {
+        self.pose.center
+    }
+
+    /// The point the camera looks at, which it orbits around.
+    pub fn target(&self) -> Point {
+        self.pose.target
+    }
+
+    /// Orbit the camera around its target by `angle_degrees` about the up
+    /// vector, keeping its distance and still facing the target.
+    ///
+    /// Positive angles turn counter-clockwise when seen from above.
+    pub fn orbit_step(&mut self, angle_degrees: f64) -> Result<(), ConfigError> {
+        self.orbit_about(self.pose.up, angle_degrees)
+    }
+
+    /// Orbit the camera over its target by `angle_degrees` about its right
+    /// axis. Positive angles raise the camera.
+    ///
+    /// Fails like [`CameraPose::look_at`] when the camera would end up looking
+    /// along its up vector, leaving the camera unchanged.
+    pub fn orbit_tilt(&mut self, angle_degrees: f64) -> Result<(), ConfigError> {
+        self.orbit_about(-self.pose.u.relax(), angle_degrees)
+    }
+
+    /// Rotate the camera's offset from its target about `axis` by Rodrigues' formula.
+    fn orbit_about(&mut self, axis: Vector, angle_degrees: f64) -> Result<(), ConfigError> {
+        let axis = axis.unit();
+        let (sin, cos) = utils::degrees_to_radians(angle_degrees).sin_cos();
+        let offset = self.pose.center - self.pose.target;
+        let rotated =
+            offset * cos + axis.cross(&offset) * sin + axis * (axis.dot(&offset) * (1.0 - cos));
+
+        self.pose =
+            CameraPose::look_at(self.pose.target + rotated, self.pose.target, self.pose.up)?
+                .rolled(self.pose.roll);
+        self.recompute_geometry();
+        Ok(())
+    }
+
+    /// A copy of this camera moved to frame `world`, looking along the same
+    /// direction at the center of the world's [`bounds`](HittableList::bounds).
+    ///
+    /// The camera backs off until the bounding sphere of those bounds fits in
+    /// both the vertical and horizontal field of view; image, projection and
+    /// model settings are kept. Returns [`ConfigError::UnboundedScene`] if no
+    /// object in `world` is bounded, or fails like [`CameraPose::look_at`] if
+    /// `up` is parallel to the view direction.
+    pub fn frame_scene(&self, world: &HittableList, up: Vector) -> Result<Camera, ConfigError> {
+        let bounds = world.bounds();
+        if bounds.is_empty() {
+            return Err(ConfigError::UnboundedScene);
+        }
+        let center = bounds.centroid();
+        let radius = (bounds.max() - bounds.min()).len() / 2.0;
+
+        let plane_dist = self.model.projection_plane_dist();
+        let half_fov = |extent: f64| (extent / 2.0 / plane_dist).atan();
+        let half_fov = half_fov(self.viewport_u.len()).min(half_fov(self.viewport_v.len()));
+        let distance = radius / half_fov.sin();
+
+        let mut camera = self.clone();
+        camera.pose = CameraPose::look_at(center + self.pose.w.inner() * distance, center, up)?
+            .rolled(self.pose.roll);
+        camera.recompute_geometry();
+        Ok(camera)
+    }
+
+    /// Render a side-by-side stereo pair to a double-width image file, saved
+    /// with [`Image::save`].
+    ///
+    /// See [`Camera::render_stereo_in_memory`]. Returns
+    /// [`RenderError::ImageTooLarge`] without rendering if the double-width
+    /// image would have more than the camera's [`ImageOptions::max_pixels`].
+    pub fn render_stereo<T: AsRef<Path>>(
+        &self,
+        world: &dyn Hittable,
+        ipd: f64,
+        path: T,
+    ) -> Result<(), RenderError> {
+        let (width, height) = (self.image_options.width, self.image_options.height);
+        if 2 * width as u64 * height as u64 > self.image_options.max_pixels {
+            return Err(RenderError::ImageTooLarge);
+        }
+        Ok(self.render_stereo_in_memory(world, ipd).save(path)?)
+    }
+
+    /// Render the views of two eyes `ipd` apart into one double-width image,
+    /// left eye on the left.
+    ///
+    /// The eyes are offset by `ipd / 2` either way along the camera's right
+    /// axis and keep its orientation, so the views are parallel.
+    pub fn render_stereo_in_memory(&self, world: &dyn Hittable, ipd: f64) -> Image {
+        let eye = |offset: f64| {
+            let mut camera = self.clone();
+            camera.pose.center = self.pose.center + self.pose.u.inner() * offset;
+            camera.recompute_geometry();
+            camera.render_in_memory(world)
+        };
+        let (left, right) = (eye(-ipd / 2.0), eye(ipd / 2.0));
+
+        let width = self.image_options.width as usize;
+        let pixels = left
+            .chunks(width)
+            .zip(right.chunks(width))
+            .flat_map(|(left_row, right_row)| left_row.iter().chain(right_row).copied())
+            .collect();
+        Image::from_pixels(2 * width as u32, self.image_options.height, pixels)
+            .expect("two views fill a double-width image")
+    }
+
+    /// Render how much light arrives after each number of bounces, as one
+    /// image per depth (light path expansion).
+    ///
+    /// Image 0 holds emission and sky seen directly, image 1 light that
+    /// scattered once, and so on; the last of the `depths` images also collects
+    /// all deeper light. The images sum to a plain path-traced render with
+    /// default options, as in [`Ray::color_by_depth`].
+    pub fn render_depth_aovs(&self, world: &dyn Hittable, depths: usize) -> Vec<Image> {
+        let (width, height) = (self.image_options.width, self.image_options.height);
+        let samples = self.samples_per_pixel();
+        let black = Color::new(0.0, 0.0, 0.0);
+
+        let per_pixel: Vec<Vec<Color>> = (0..width * height)
+            .into_par_iter()
+            .map(|index| {
+                let (i, j) = (index % width, index / width);
+                let mut pixel = vec![black; depths];
+                for _ in 0..samples {
+                    let (ray_origin, ray_dir) = self.primary_ray_components(i, j);
+                    let r = Ray::new(&ray_origin, ray_dir)
+                        .with_differential(self.differential_at(i, j));
+                    for (depth, light) in r.color_by_depth(world, MAX_DEPTH).into_iter().enumerate()
+                    {
+                        if let Some(slot) = pixel.get_mut(depth.min(depths.saturating_sub(1))) {
+                            *slot += light / samples as f64;
+                        }
+                    }
+                }
+                pixel
+            })
+            .collect();
+
+        (0..depths)
+            .map(|depth| {
+                let pixels = per_pixel.iter().map(|pixel| pixel[depth]).collect();
+                Image::from_pixels(width, height, pixels).expect("one color per pixel")
+            })
+            .collect()
+    }
+
     /// Render the camera to a P3 PPM file using default render options.
     ///
     /// The scene is passed in explicitly so camera configuration stays separate
     /// from world ownership.
-    pub fn render<T: AsRef<Path>>(&self, world: &dyn Hittable, path: T) -> io::Result<()> {
+    pub fn render<T: AsRef<Path>>(&self, world: &dyn Hittable, path: T) -> Result<(), RenderError> {
         self.render_with_options(world, path, &RenderOptions::default())
     }
 
+    /// Render once and save the result to every path in `paths`, each in the
+    /// format named by its extension (see [`Image::save`]).
+    ///
+    /// All outputs come from the same samples, so they differ only by encoding.
+    pub fn render_multi<P: AsRef<Path>>(
+        &self,
+        world: &dyn Hittable,
+        paths: &[P],
+    ) -> Result<(), RenderError> {
+        let pixels = self.render_in_memory(world);
+        let image = Image::from_pixels(self.image_options.width, self.image_options.height, pixels)
+            .expect("one color per pixel");
+        for path in paths {
+            image.save(path)?;
+        }
+        Ok(())
+    }
+
     /// Render the camera to a P3 PPM file using an explicit render policy.
+    ///
+    /// Returns [`RenderError::Cancelled`] if the render is stopped through
+    /// [`RenderOptions::cancel_flag`]; the file is then left incomplete.
     pub fn render_with_options<T: AsRef<Path>>(
         &self,
         world: &dyn Hittable,
         path: T,
         render_options: &RenderOptions,
-    ) -> io::Result<()> {
-        let mut file = OpenOptions::new()
-            .write(true)
-            .truncate(true)
-            .create(true)
-            .open(path)?;
+    ) -> Result<(), RenderError> {
+        // Pixels are written a line at a time, so buffer them rather than
+        // making a system call for each
+        let mut file = io::BufWriter::new(
+            OpenOptions::new()
+                .write(true)
+                .truncate(true)
+                .create(true)
+                .open(path)?,
+        );
+
+        if render_options.transparent_background {
+            self.render_pam(world, &mut file, render_options)?;
+        } else {
+            self.write_ppm_p3_header(&mut file, render_options)?;
+
+            use ParallelOptions::*;
+            match render_options.parallel {
+                AllAtOnce => self.render_parallel_all(world, &mut file, render_options)?,
+                ByRows => self.render_parallel_by_rows(world, &mut file, render_options)?,
+                Series => self.render_sequential(world, &mut file, render_options)?,
+            };
+        }
 
-        self.write_ppm_p3_header(&mut file)?;
+        if render_options.cancelled() {
+            return Err(RenderError::Cancelled);
+        }
+        // Dropping the writer would flush too, but silently drop any error
+        Ok(file.flush()?)
+    }
 
-        use ParallelOptions::*;
-        match render_options.parallel {
-            AllAtOnce => self.render_parallel_all(world, &mut file)?,
-            ByRows => self.render_parallel_by_rows(world, &mut file)?,
-            Series => self.render_sequential(world, &mut file)?,
+    /// A hash of this camera's view together with `world`, or `None` if the
+    /// world cannot be fingerprinted (see [`Hittable::fingerprint`]).
+    ///
+    /// Two renders with the same fingerprint differ only by sampling noise.
+    /// Fingerprints are stable between runs of the same build, not across
+    /// compiler versions.
+    pub fn fingerprint(&self, world: &dyn Hittable) -> Option<u64> {
+        let image = &self.image_options;
+        Some(utils::fingerprint((
+            (image.width(), image.height(), image.samples_per_pixel()),
+            self.pose.center,
+            self.model.uses_defocus(),
+            [
+                self.pixel00_loc.to_vector(),
+                self.pixel_delta_u,
+                self.pixel_delta_v,
+                self.defocus_disk_u,
+                self.defocus_disk_v,
+            ],
+            world.fingerprint()?,
+        )))
+    }
+
+    /// Render to `path` like [`Camera::render`], reusing an earlier render
+    /// from `cache_dir` when the [`Camera::fingerprint`] matches.
+    ///
+    /// Renders are cached as `<fingerprint>.ppm` in `cache_dir`, which is
+    /// created if needed. Worlds without a fingerprint are always rendered.
+    pub fn render_cached<T: AsRef<Path>, D: AsRef<Path>>(
+        &self,
+        world: &dyn Hittable,
+        cache_dir: D,
+        path: T,
+    ) -> Result<CacheStatus, RenderError> {
+        let path = path.as_ref();
+        let Some(fingerprint) = self.fingerprint(world) else {
+            self.render(world, path)?;
+            return Ok(CacheStatus::Uncacheable);
         };
 
-        Ok(())
+        let cached = cache_dir.as_ref().join(format!("{fingerprint:016x}.ppm"));
+        if cached.is_file() {
+            info!("Reusing cached render {}", cached.display());
+            fs::copy(&cached, path)?;
+            return Ok(CacheStatus::Hit);
+        }
+
+        fs::create_dir_all(cache_dir)?;
+        self.render(world, path)?;
+        fs::copy(path, &cached)?;
+        Ok(CacheStatus::Miss)
+    }
+
+    /// Render the camera to `path`, then open the result in the OS default image viewer.
+    #[cfg(feature = "open")]
+    pub fn render_and_open<T: AsRef<Path>>(
+        &self,
+        world: &dyn Hittable,
+        path: T,
+    ) -> Result<(), RenderError> {
+        self.render_and_open_with(world, path, open_in_viewer)
+    }
+
+    /// Like [`Camera::render_and_open`], but hands the finished file to `open`
+    /// instead of the OS viewer. `open` is only called once the file is fully written.
+    #[cfg(feature = "open")]
+    pub fn render_and_open_with<T, F>(
+        &self,
+        world: &dyn Hittable,
+        path: T,
+        open: F,
+    ) -> Result<(), RenderError>
+    where
+        T: AsRef<Path>,
+        F: FnOnce(&Path) -> io::Result<()>,
+    {
+        let path = path.as_ref();
+        self.render(world, path)?;
+        Ok(open(path)?)
     }
 
     /// Render the camera into memory without writing a file using default render options.
@@ -381,21 +1135,7 @@ impl Camera {
     ) -> Vec<Color> {
         use ParallelOptions::*;
         match render_options.parallel {
-            AllAtOnce => {
-                let mut pixels = vec![
-                    Color::new(0.0, 0.0, 0.0);
-                    (self.image_options.height * self.image_options.width)
-                        as usize
-                ];
-
-                pixels.par_iter_mut().enumerate().for_each(|(i, v)| {
-                    let x = (i as u32) % self.image_options.width;
-                    let y = (i as u32) / self.image_options.width;
-                    *v = self.pixel_color_at(world, x, y);
-                });
-
-                pixels
-            }
+            AllAtOnce => self.compute_all_pixels(world, render_options),
             ByRows => {
                 let mut pixels = Vec::with_capacity(
                     (self.image_options.height * self.image_options.width) as usize,
@@ -404,7 +1144,7 @@ impl Camera {
                 for j in 0..self.image_options.height {
                     let row_pixels: Vec<_> = (0..self.image_options.width)
                         .into_par_iter()
-                        .map(|i| self.pixel_color_at(world, i, j))
+                        .map(|i| self.pixel_color_at(world, i, j, render_options))
                         .collect();
                     pixels.extend(row_pixels);
                 }
@@ -418,7 +1158,7 @@ impl Camera {
 
                 for j in 0..self.image_options.height {
                     for i in 0..self.image_options.width {
-                        pixels.push(self.pixel_color_at(world, i, j));
+                        pixels.push(self.pixel_color_at(world, i, j, render_options));
                     }
                 }
 
@@ -427,59 +1167,453 @@ impl Camera {
         }
     }
 
-    fn recompute_geometry(&mut self) {
-        let theta = (self.projection.vfov / 180.0) * std::f64::consts::PI;
-        let h = (theta / 2.0).tan();
-        let projection_plane_dist = self.model.projection_plane_dist();
-        let viewport_height = 2.0 * h * projection_plane_dist;
-        let viewport_width = viewport_height * self.image_options.aspect_ratio();
-
-        self.viewport_u = self.pose.u.inner() * viewport_width;
-        self.viewport_v = -self.pose.v.inner() * viewport_height;
-        self.pixel_delta_u = self.viewport_u / self.image_options.width as f64;
-        self.pixel_delta_v = self.viewport_v / self.image_options.height as f64;
-        self.viewport_upper_left = self.pose.center
-            - (self.pose.w.inner() * projection_plane_dist)
-            - self.viewport_u / 2.0
-            - self.viewport_v / 2.0;
-        self.pixel00_loc =
-            self.viewport_upper_left + (self.pixel_delta_u + self.pixel_delta_v) * 0.5;
-
-        let defocus_radius = projection_plane_dist
-            * (utils::degrees_to_radians(self.model.defocus_angle() / 2.0)).tan();
-        self.defocus_disk_u = self.pose.u.inner() * defocus_radius;
-        self.defocus_disk_v = self.pose.v.inner() * defocus_radius;
+    /// Render the camera into a flat buffer of linear RGB floats, 3 per pixel
+    /// in row-major order, for HDR output such as OpenEXR.
+    ///
+    /// Values are neither gamma-encoded nor clamped, so highlights brighter
+    /// than 1.0 are kept.
+    pub fn render_to_f32(&self, world: &dyn Hittable) -> Vec<f32> {
+        self.render_in_memory(world)
+            .iter()
+            .flat_map(|pixel| [pixel.r() as f32, pixel.g() as f32, pixel.b() as f32])
+            .collect()
+    }
 
-        self.pixel_samples_scale = match self.image_options.antialias {
-            AntialiasOptions::Disabled => None,
-            AntialiasOptions::Enabled(samples_per_pixel) => Some(1.0 / samples_per_pixel as f64),
-        };
+    /// Render the camera into memory with per-pixel coverage.
+    ///
+    /// Each pixel is paired with its alpha: the fraction of its primary rays
+    /// that hit geometry, so 0.0 where only the background is seen. Scheduling
+    /// options are ignored; pixels are always computed in parallel.
+    pub fn render_rgba_in_memory(
+        &self,
+        world: &dyn Hittable,
+        render_options: &RenderOptions,
+    ) -> Vec<(Color, f64)> {
+        let (width, height) = (self.image_options.width, self.image_options.height);
+        (0..width * height)
+            .into_par_iter()
+            .map(|i| self.pixel_rgba_at(world, i % width, i / width, render_options))
+            .collect()
     }
 
-    /// Internal function to write P3 PPM header.
-    fn write_ppm_p3_header(&self, file: &mut fs::File) -> io::Result<()> {
-        writeln!(file, "P3")?;
-        writeln!(
-            file,
-            "{} {}",
-            self.image_options.width, self.image_options.height
-        )?;
-        writeln!(file, "255")?;
+    /// Render a single jittered sample for every pixel, regardless of the
+    /// image's antialiasing setting.
+    ///
+    /// Averaging `n` passes converges to an `antialias(n)` render. A
+    /// [`ParallelOptions::Series`] pass traces every pixel in order on the
+    /// calling thread; any other setting traces them in parallel. With
+    /// [`RenderOptions::seed`] set, every call draws the same samples.
+    pub fn render_sample_pass(
+        &self,
+        world: &dyn Hittable,
+        render_options: &RenderOptions,
+    ) -> Image {
+        self.sample_pass(world, render_options, 0)
+    }
+
+    /// [`Camera::render_sample_pass`] as pass number `pass` of a longer
+    /// render, so seeded passes draw different samples.
+    fn sample_pass(
+        &self,
+        world: &dyn Hittable,
+        render_options: &RenderOptions,
+        pass: u32,
+    ) -> Image {
+        let (width, height) = (self.image_options.width, self.image_options.height);
+        let sample = |index: u32| {
+            let (i, j) = (index % width, index / width);
+            Self::seed_sample(i, j, pass, render_options);
+            let (ray_origin, ray_dir) = self.get_antialiasing_ray_components(i, j);
+            let r = Ray::new(&ray_origin, ray_dir).with_differential(self.differential_at(i, j));
+            Self::finish_pixel(self.shade(&r, world, render_options), render_options)
+        };
+        let pixels = match render_options.parallel {
+            ParallelOptions::Series => (0..width * height).map(sample).collect(),
+            _ => (0..width * height).into_par_iter().map(sample).collect(),
+        };
+        Image::from_pixels(width, height, pixels).expect("one color per pixel")
+    }
+
+    /// Refine a render pass by pass, yielding the running average after each of
+    /// `passes` sample passes.
+    ///
+    /// Every yielded [`Image`] is a full frame, so it can be shown immediately.
+    pub fn progressive<'a>(
+        &'a self,
+        world: &'a dyn Hittable,
+        passes: u32,
+    ) -> impl Iterator<Item = Image> + 'a {
+        let render_options = RenderOptions::default();
+        let (width, height) = (self.image_options.width, self.image_options.height);
+        let mut sum = vec![ColorSum::new(); (width * height) as usize];
+
+        (1..=passes).map(move |pass| {
+            let sample = self.render_sample_pass(world, &render_options);
+            for (total, pixel) in sum.iter_mut().zip(sample.pixels()) {
+                total.add(*pixel);
+            }
+            let average = sum
+                .iter()
+                .map(|total| total.total() / pass as f64)
+                .collect();
+            Image::from_pixels(width, height, average).expect("one color per pixel")
+        })
+    }
+
+    /// Accumulate sample passes until `budget` has elapsed, then save the
+    /// averaged image to `path` with [`Image::save`].
+    ///
+    /// At least one pass is always rendered, and the pass running when the
+    /// budget runs out is finished, so the render may overrun by up to one
+    /// pass. Returns the number of passes averaged.
+    pub fn render_for<P: AsRef<Path>>(
+        &self,
+        world: &dyn Hittable,
+        budget: Duration,
+        path: P,
+    ) -> Result<u32, RenderError> {
+        self.render_for_with_options(world, budget, path, &RenderOptions::default())
+    }
+
+    /// [`Camera::render_for`], rendering each pass with `render_options`.
+    ///
+    /// Raising the [`RenderOptions::cancel_flag`] stops after the pass in
+    /// flight and returns [`RenderError::Cancelled`] without saving.
+    pub fn render_for_with_options<P: AsRef<Path>>(
+        &self,
+        world: &dyn Hittable,
+        budget: Duration,
+        path: P,
+        render_options: &RenderOptions,
+    ) -> Result<u32, RenderError> {
+        let start = Instant::now();
+        let (width, height) = (self.image_options.width, self.image_options.height);
+        let mut sum = vec![ColorSum::new(); (width * height) as usize];
+
+        let mut passes = 0;
+        while passes == 0 || start.elapsed() < budget {
+            let sample = self.sample_pass(world, render_options, passes);
+            if render_options.cancelled() {
+                return Err(RenderError::Cancelled);
+            }
+            for (total, pixel) in sum.iter_mut().zip(sample.pixels()) {
+                total.add(*pixel);
+            }
+            passes += 1;
+        }
+        info!("Rendered {passes} passes in {:.2?}", start.elapsed());
+
+        let average = sum
+            .iter()
+            .map(|total| total.total() / passes as f64)
+            .collect();
+        Image::from_pixels(width, height, average)
+            .expect("one color per pixel")
+            .save_mapped(path, render_options.gamut_mapping)?;
+        Ok(passes)
+    }
+
+    /// Add `additional_passes` sample passes to the accumulator saved at
+    /// `accum_path`, save it back, and return the averaged image so far.
+    ///
+    /// The accumulator holds the sum of every pass with
+    /// [`Image::save_raw`], so a long render can be stopped and resumed across
+    /// runs. A missing file starts a fresh accumulator. Returns
+    /// [`RenderError::Io`] with an [`io::ErrorKind::InvalidInput`] error if the
+    /// saved accumulator has a different size than this camera's image.
+    pub fn resume_render<P: AsRef<Path>>(
+        &self,
+        world: &dyn Hittable,
+        accum_path: P,
+        additional_passes: u32,
+    ) -> Result<Image, RenderError> {
+        self.resume_render_with_options(
+            world,
+            accum_path,
+            additional_passes,
+            &RenderOptions::default(),
+        )
+    }
+
+    /// [`Camera::resume_render`], rendering each pass with `render_options`.
+    pub fn resume_render_with_options<P: AsRef<Path>>(
+        &self,
+        world: &dyn Hittable,
+        accum_path: P,
+        additional_passes: u32,
+        render_options: &RenderOptions,
+    ) -> Result<Image, RenderError> {
+        let accum_path = accum_path.as_ref();
+        let (width, height) = (self.image_options.width, self.image_options.height);
+        let (sum, samples) = match Image::load_raw(accum_path) {
+            Ok(loaded) => loaded,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => (Image::new(width, height), 0),
+            Err(error) => return Err(error.into()),
+        };
+        if (sum.width(), sum.height()) != (width, height) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "saved accumulator does not match the camera's image size",
+            )
+            .into());
+        }
+
+        let mut sum: Vec<_> = sum
+            .pixels()
+            .iter()
+            .map(|&pixel| {
+                let mut total = ColorSum::new();
+                total.add(pixel);
+                total
+            })
+            .collect();
+        for pass in samples..samples + additional_passes {
+            let sample = self.sample_pass(world, render_options, pass);
+            for (total, pixel) in sum.iter_mut().zip(sample.pixels()) {
+                total.add(*pixel);
+            }
+        }
+        let samples = samples + additional_passes;
+        let sum = Image::from_pixels(width, height, sum.iter().map(ColorSum::total).collect())
+            .expect("one color per pixel");
+        sum.save_raw(accum_path, samples)?;
+
+        let average = sum
+            .pixels()
+            .iter()
+            .map(|total| *total / samples.max(1) as f64)
+            .collect();
+        Ok(Image::from_pixels(width, height, average).expect("one color per pixel"))
+    }
+
+    /// Render only a rectangular `region` of the image, given as
+    /// `(x, y, width, height)` in pixels.
+    ///
+    /// Separate regions can be rendered independently (e.g. on different
+    /// machines) and reassembled with [`stitch_regions`]. Returns
+    /// [`ConfigError::InvalidRegion`] if the region is empty or does not fit
+    /// inside the image.
+    pub fn render_region(
+        &self,
+        world: &dyn Hittable,
+        region: Region,
+    ) -> Result<Image, ConfigError> {
+        self.render_region_with_options(world, region, &RenderOptions::default())
+    }
+
+    /// Render only a rectangular `region` using an explicit render policy.
+    ///
+    /// With [`RenderOptions::seed`] set, every pixel matches the same pixel of
+    /// a full render seeded the same way, so regions rendered on different
+    /// machines stitch together seamlessly. Scheduling options are ignored;
+    /// pixels are always computed in parallel.
+    pub fn render_region_with_options(
+        &self,
+        world: &dyn Hittable,
+        region: Region,
+        render_options: &RenderOptions,
+    ) -> Result<Image, ConfigError> {
+        validate_region(region, self.image_options.width, self.image_options.height)?;
+
+        let (x0, y0, width, height) = region;
+        let pixels: Vec<_> = (0..width * height)
+            .into_par_iter()
+            .map(|i| {
+                let x = x0 + i % width;
+                let y = y0 + i / width;
+                self.pixel_color_at(world, x, y, render_options)
+            })
+            .collect();
+
+        Ok(Image::from_pixels(width, height, pixels).expect("region pixel count matches its size"))
+    }
+
+    /// Render the image as square tiles of `tile_size` pixels, each saved to
+    /// `dir` as `tile_{x}_{y}.ppm` where `(x, y)` is its top-left pixel.
+    ///
+    /// Tiles on the right and bottom edges are cut to fit the image. The
+    /// directory is created if missing; reassemble the tiles with
+    /// [`stitch_tiles`]. Returns [`RenderError::Build`] with
+    /// [`ConfigError::InvalidRegion`] if `tile_size` is 0.
+    pub fn render_tiles_to_dir<P: AsRef<Path>>(
+        &self,
+        world: &dyn Hittable,
+        dir: P,
+        tile_size: u32,
+    ) -> Result<(), RenderError> {
+        self.render_tiles_to_dir_with_options(world, dir, tile_size, &RenderOptions::default())
+    }
 
+    /// [`Camera::render_tiles_to_dir`], rendering each tile with
+    /// [`Camera::render_region_with_options`].
+    ///
+    /// Tiles rendered with the same [`RenderOptions::seed`], even by separate
+    /// processes, stitch into exactly the seeded full render.
+    pub fn render_tiles_to_dir_with_options<P: AsRef<Path>>(
+        &self,
+        world: &dyn Hittable,
+        dir: P,
+        tile_size: u32,
+        render_options: &RenderOptions,
+    ) -> Result<(), RenderError> {
+        if tile_size == 0 {
+            return Err(ConfigError::InvalidRegion.into());
+        }
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+
+        let (width, height) = (self.image_options.width, self.image_options.height);
+        for y in (0..height).step_by(tile_size as usize) {
+            for x in (0..width).step_by(tile_size as usize) {
+                let region = (x, y, tile_size.min(width - x), tile_size.min(height - y));
+                let tile = self.render_region_with_options(world, region, render_options)?;
+                tile.save(dir.join(format!("tile_{x}_{y}.ppm")))?;
+            }
+        }
         Ok(())
     }
 
-    fn render_parallel_all(&self, world: &dyn Hittable, file: &mut fs::File) -> io::Result<()> {
-        let mut pixels = vec![
-            Color::new(0.0, 0.0, 0.0);
-            (self.image_options.height * self.image_options.width) as usize
-        ];
+    /// Render the mean image along with the per-pixel sample variance.
+    ///
+    /// Each channel's variance is accumulated across the pixel's samples with
+    /// Welford's online algorithm. With antialiasing disabled there is only one
+    /// sample per pixel, so the variance image is all zeros.
+    pub fn render_with_variance(&self, world: &dyn Hittable) -> (Image, Image) {
+        self.render_with_variance_with_options(world, &RenderOptions::default())
+    }
 
-        pixels.par_iter_mut().enumerate().for_each(|(i, v)| {
-            let x = (i as u32) % self.image_options.width;
-            let y = (i as u32) / self.image_options.width;
-            *v = self.pixel_color_at(world, x, y);
+    /// Render the mean and variance images using explicit render options.
+    ///
+    /// Scheduling options are ignored; pixels are always computed in parallel.
+    pub fn render_with_variance_with_options(
+        &self,
+        world: &dyn Hittable,
+        render_options: &RenderOptions,
+    ) -> (Image, Image) {
+        let (width, height) = (self.image_options.width, self.image_options.height);
+
+        let (means, variances): (Vec<_>, Vec<_>) = (0..width * height)
+            .into_par_iter()
+            .map(|index| {
+                let (i, j) = (index % width, index / width);
+                let samples = self.samples_at(i, j, render_options);
+                let mut mean = [0.0; 3];
+                let mut m2 = [0.0; 3];
+                for n in 1..=samples {
+                    let sample = self.sample_color(world, i, j, n - 1, render_options);
+                    for (channel, x) in [sample.r(), sample.g(), sample.b()].into_iter().enumerate()
+                    {
+                        let delta = x - mean[channel];
+                        mean[channel] += delta / n as f64;
+                        m2[channel] += delta * (x - mean[channel]);
+                    }
+                }
+                let variance = |channel: usize| {
+                    if samples > 1 {
+                        m2[channel] / (samples - 1) as f64
+                    } else {
+                        0.0
+                    }
+                };
+                (
+                    Color::new(mean[0], mean[1], mean[2]),
+                    Color::new(variance(0), variance(1), variance(2)),
+                )
+            })
+            .unzip();
+
+        (
+            Image::from_pixels(width, height, means).expect("one mean per pixel"),
+            Image::from_pixels(width, height, variances).expect("one variance per pixel"),
+        )
+    }
+
+    fn recompute_geometry(&mut self) {
+        let projection_plane_dist = self.model.projection_plane_dist();
+        let (viewport_width, viewport_height) = match self.viewport {
+            Some(ViewportSize { width, height }) => (width, height),
+            None => {
+                let theta = (self.projection.vfov / 180.0) * std::f64::consts::PI;
+                let h = (theta / 2.0).tan();
+                let viewport_height = 2.0 * h * projection_plane_dist;
+                (
+                    viewport_height * self.image_options.aspect_ratio(),
+                    viewport_height,
+                )
+            }
+        };
+
+        self.viewport_u = self.pose.u.inner() * viewport_width;
+        self.viewport_v = -self.pose.v.inner() * viewport_height;
+        self.pixel_delta_u = self.viewport_u / self.image_options.width as f64;
+        self.pixel_delta_v = self.viewport_v / self.image_options.height as f64;
+        self.viewport_upper_left = self.pose.center
+            - (self.pose.w.inner() * projection_plane_dist)
+            - self.viewport_u / 2.0
+            - self.viewport_v / 2.0;
+        self.pixel00_loc =
+            self.viewport_upper_left + (self.pixel_delta_u + self.pixel_delta_v) * 0.5;
+
+        let defocus_radius = projection_plane_dist
+            * (utils::degrees_to_radians(self.model.defocus_angle() / 2.0)).tan();
+        self.defocus_disk_u = self.pose.u.inner() * defocus_radius;
+        self.defocus_disk_v = self.pose.v.inner() * defocus_radius;
+    }
+
+    /// Internal function to write P3 PPM header.
+    fn write_ppm_p3_header(
+        &self,
+        file: &mut impl Write,
+        render_options: &RenderOptions,
+    ) -> io::Result<()> {
+        let metadata = render_options.embed_metadata.then(|| {
+            format!(
+                "ray-tracing-rs spp={} depth={}",
+                self.samples_per_pixel(),
+                MAX_DEPTH
+            )
         });
+        write_ppm_header(
+            file,
+            self.image_options.width,
+            self.image_options.height,
+            metadata.as_deref(),
+        )
+    }
+
+    /// Write an RGBA PAM image, with alpha from primary-ray coverage.
+    fn render_pam(
+        &self,
+        world: &dyn Hittable,
+        file: &mut impl Write,
+        render_options: &RenderOptions,
+    ) -> io::Result<()> {
+        let pixels = self.render_rgba_in_memory(world, render_options);
+        info!("Finished calculations!");
+
+        writeln!(file, "P7")?;
+        writeln!(file, "WIDTH {}", self.image_options.width)?;
+        writeln!(file, "HEIGHT {}", self.image_options.height)?;
+        writeln!(file, "DEPTH 4")?;
+        writeln!(file, "MAXVAL 255")?;
+        writeln!(file, "TUPLTYPE RGB_ALPHA")?;
+        writeln!(file, "ENDHDR")?;
+
+        let mut bytes = Vec::with_capacity(pixels.len() * 4);
+        for (color, alpha) in pixels {
+            bytes.extend(color.to_rgb8_mapped(render_options.gamut_mapping));
+            bytes.push((255.0 * alpha.clamp(0.0, 1.0)).round() as u8);
+        }
+        file.write_all(&bytes)
+    }
+
+    fn render_parallel_all(
+        &self,
+        world: &dyn Hittable,
+        file: &mut impl Write,
+        render_options: &RenderOptions,
+    ) -> io::Result<()> {
+        let pixels = self.compute_all_pixels(world, render_options);
 
         info!("Finished calculations!");
 
@@ -490,66 +1624,404 @@ impl Camera {
                     self.image_options.height - (i / self.image_options.width)
                 );
             }
-            writeln!(file, "{}", pixels[i as usize])?;
+            write_ppm_pixel(file, &pixels[i as usize], render_options.gamut_mapping)?;
         }
         Ok(())
     }
 
-    fn render_parallel_by_rows(&self, world: &dyn Hittable, file: &mut fs::File) -> io::Result<()> {
+    /// Compute every pixel in parallel, logging each time another row's worth
+    /// of pixels has finished.
+    fn compute_all_pixels(
+        &self,
+        world: &dyn Hittable,
+        render_options: &RenderOptions,
+    ) -> Vec<Color> {
+        let (width, height) = (self.image_options.width, self.image_options.height);
+        let mut pixels = vec![Color::new(0.0, 0.0, 0.0); (height * width) as usize];
+        // Pixels finish out of order, so count them rather than tracking rows
+        let completed = AtomicU32::new(0);
+
+        pixels.par_iter_mut().enumerate().for_each(|(i, v)| {
+            if render_options.cancelled() {
+                return;
+            }
+            let x = (i as u32) % width;
+            let y = (i as u32) / width;
+            *v = self.pixel_color_at(world, x, y, render_options);
+
+            let done = completed.fetch_add(1, Ordering::Relaxed) + 1;
+            if done.is_multiple_of(width) {
+                info!("Scanlines computed: {}/{}", done / width, height);
+            }
+        });
+
+        pixels
+    }
+
+    fn render_parallel_by_rows(
+        &self,
+        world: &dyn Hittable,
+        file: &mut impl Write,
+        render_options: &RenderOptions,
+    ) -> io::Result<()> {
+        #[cfg(feature = "indicatif")]
+        let bar = {
+            let height = self.image_options.height as u64;
+            let bar = render_options
+                .progress_bar
+                .clone()
+                .unwrap_or_else(|| indicatif::ProgressBar::new(height));
+            bar.set_length(height);
+            bar
+        };
+
+        // One row buffer, refilled in place for every row
+        let mut row = vec![Color::new(0.0, 0.0, 0.0); self.image_options.width as usize];
         for j in 0..self.image_options.height {
+            if render_options.cancelled() {
+                break;
+            }
             info!("Scanlines remaining: {}", self.image_options.height - j);
             io::stdout().flush().unwrap();
 
-            let row_pixels: Vec<_> = (0..self.image_options.width)
-                .into_par_iter()
-                .map(|i| self.pixel_color_at(world, i, j))
-                .collect();
+            row.par_iter_mut().enumerate().for_each(|(i, pixel)| {
+                *pixel = self.pixel_color_at(world, i as u32, j, render_options);
+            });
 
-            for pixel_color in row_pixels {
-                writeln!(file, "{}", pixel_color)?;
+            for pixel_color in &row {
+                write_ppm_pixel(file, pixel_color, render_options.gamut_mapping)?;
             }
+
+            #[cfg(feature = "indicatif")]
+            bar.inc(1);
         }
+
+        #[cfg(feature = "indicatif")]
+        bar.finish();
+
         Ok(())
     }
 
-    fn render_sequential(&self, world: &dyn Hittable, file: &mut fs::File) -> io::Result<()> {
+    fn render_sequential(
+        &self,
+        world: &dyn Hittable,
+        file: &mut impl Write,
+        render_options: &RenderOptions,
+    ) -> io::Result<()> {
         for j in 0..self.image_options.height {
+            if render_options.cancelled() {
+                break;
+            }
             info!("Scanlines remaining: {}", self.image_options.height - j);
             io::stdout().flush().unwrap();
             for i in 0..self.image_options.width {
-                let pixel_color = self.pixel_color_at(world, i, j);
-                writeln!(file, "{}", pixel_color)?;
+                let pixel_color = self.pixel_color_at(world, i, j, render_options);
+                write_ppm_pixel(file, &pixel_color, render_options.gamut_mapping)?;
             }
         }
 
         Ok(())
     }
 
-    fn pixel_color_at(&self, world: &dyn Hittable, i: u32, j: u32) -> Color {
-        let mut pixel_color = Color::new(0.0, 0.0, 0.0);
+    fn pixel_color_at(
+        &self,
+        world: &dyn Hittable,
+        i: u32,
+        j: u32,
+        render_options: &RenderOptions,
+    ) -> Color {
+        let samples = self.samples_at(i, j, render_options);
+        let scale = 1.0 / samples as f64;
+
+        let mut pixel_sum = ColorSum::new();
+        if render_options.parallel_samples && self.has_idle_threads() {
+            // Summed in sample order, so the result does not depend on which
+            // thread finishes first
+            let colors: Vec<Color> = (0..samples)
+                .into_par_iter()
+                .map(|sample| self.sample_color(world, i, j, sample, render_options))
+                .collect();
+            for color in colors {
+                pixel_sum.add(color);
+            }
+        } else {
+            for sample in 0..samples {
+                pixel_sum.add(self.sample_color(world, i, j, sample, render_options));
+            }
+        }
+        Self::finish_pixel(pixel_sum.total() * scale, render_options)
+    }
+
+    /// Render only pixel `(x, y)`, with the image's samples per pixel, using
+    /// default render options.
+    ///
+    /// To reproduce a pixel of a full render, seed both with
+    /// [`RenderOptions::seed`] and use [`Camera::debug_pixel_with_options`].
+    pub fn debug_pixel(&self, world: &dyn Hittable, x: u32, y: u32) -> Color {
+        self.debug_pixel_with_options(world, x, y, &RenderOptions::default())
+    }
+
+    /// Render only pixel `(x, y)` using an explicit render policy.
+    ///
+    /// With [`RenderOptions::seed`] set, the result is exactly pixel `(x, y)`
+    /// of a full render with the same options, whichever pixel it is.
+    ///
+    /// Combine with [`RenderOptions::log_bounces`] to see where each of the
+    /// pixel's paths went. Scheduling options, including
+    /// [`RenderOptions::parallel_samples`], are ignored.
+    pub fn debug_pixel_with_options(
+        &self,
+        world: &dyn Hittable,
+        x: u32,
+        y: u32,
+        render_options: &RenderOptions,
+    ) -> Color {
+        let samples = self.samples_at(x, y, render_options);
+        let scale = 1.0 / samples as f64;
+
+        let mut pixel_sum = ColorSum::new();
+        for sample in 0..samples {
+            if render_options.surroundings.log_bounces {
+                debug!("pixel ({x}, {y}), sample {}/{samples}", sample + 1);
+            }
+            pixel_sum.add(self.sample_color(world, x, y, sample, render_options));
+        }
+        Self::finish_pixel(pixel_sum.total() * scale, render_options)
+    }
+
+    /// The surface points visited by `samples` paths traced through each of
+    /// `pixels`, for plotting how light reaches the camera.
+    ///
+    /// Paths are returned pixel by pixel in the order given, `samples` per
+    /// pixel, and are traced as by [`Ray::trace_path`]. A path that escapes
+    /// without hitting anything is empty.
+    pub fn trace_paths(
+        &self,
+        world: &dyn Hittable,
+        pixels: &[(u32, u32)],
+        samples: u32,
+    ) -> Vec<Vec<Point>> {
+        pixels
+            .iter()
+            .flat_map(|&(x, y)| (0..samples).map(move |_| (x, y)))
+            .map(|(x, y)| {
+                let (ray_origin, ray_dir) = self.primary_ray_components(x, y);
+                let r =
+                    Ray::new(&ray_origin, ray_dir).with_differential(self.differential_at(x, y));
+                r.trace_path(world, MAX_DEPTH).1
+            })
+            .collect()
+    }
+
+    /// Whether the image has so few pixels that parallelizing over pixels
+    /// alone would leave threads idle.
+    fn has_idle_threads(&self) -> bool {
+        let pixels = self.image_options.width as usize * self.image_options.height as usize;
+        pixels < PIXELS_PER_THREAD * rayon::current_num_threads()
+    }
+
+    /// Final per-pixel step before a color is stored or written.
+    fn finish_pixel(color: Color, render_options: &RenderOptions) -> Color {
+        if render_options.flag_invalid && !color.is_finite() {
+            return Color::new(1.0, 0.0, 1.0);
+        }
+        color
+    }
+
+    /// Number of samples to take for pixel `(i, j)`: the sample map's count if
+    /// one is set, otherwise the image's antialiasing setting. Always at least 1.
+    fn samples_at(&self, i: u32, j: u32, render_options: &RenderOptions) -> u32 {
+        if let Some(sample_map) = &render_options.sample_map {
+            return (sample_map.0)(i, j).max(1);
+        }
+        self.samples_per_pixel()
+    }
+
+    /// Samples taken per pixel according to the image's antialiasing setting.
+    fn samples_per_pixel(&self) -> u32 {
+        self.image_options.samples_per_pixel().max(1)
+    }
+
+    /// Like [`Camera::pixel_color_at`], but also returns the fraction of
+    /// primary rays that hit geometry, with rays landing on a
+    /// [`ShadowCatcher`](crate::materials::ShadowCatcher) counting by how much
+    /// light is blocked there.
+    fn pixel_rgba_at(
+        &self,
+        world: &dyn Hittable,
+        i: u32,
+        j: u32,
+        render_options: &RenderOptions,
+    ) -> (Color, f64) {
+        let samples = self.samples_at(i, j, render_options);
+
+        let mut pixel_sum = ColorSum::new();
+        let mut coverage = 0.0;
+        for sample in 0..samples {
+            Self::seed_sample(i, j, sample, render_options);
+            let (ray_origin, ray_dir) = self.primary_ray_components(i, j);
+            let r = Ray::new(&ray_origin, ray_dir).with_differential(self.differential_at(i, j));
+            match world.hit(Interval::new(0.001, f64::MAX), &r) {
+                // Shadow catchers composite as black, as opaque as their shadow
+                Some(record) if record.material.catches_shadows() => {
+                    let lights = LightSampler::for_paths(
+                        render_options.lights.as_ref(),
+                        render_options.direct_lighting,
+                        &render_options.surroundings,
+                    );
+                    coverage += lights.map_or(0.0, |lights| {
+                        let visibility = r.light_visibility(
+                            world,
+                            &lights,
+                            &record,
+                            &render_options.surroundings,
+                        );
+                        1.0 - visibility
+                    });
+                }
+                Some(_) => {
+                    pixel_sum.add(self.shade(&r, world, render_options));
+                    coverage += 1.0;
+                }
+                None => pixel_sum.add(self.shade(&r, world, render_options)),
+            }
+        }
+
+        let scale = 1.0 / samples as f64;
+        (
+            Self::finish_pixel(pixel_sum.total() * scale, render_options),
+            coverage * scale,
+        )
+    }
+
+    /// Restart this thread's generator for sample `sample` of pixel `(i, j)`
+    /// if [`RenderOptions::seed`] is set.
+    fn seed_sample(i: u32, j: u32, sample: u32, render_options: &RenderOptions) {
+        if let Some(seed) = render_options.seed {
+            rand::seed(utils::fingerprint((seed, i, j, sample)));
+        }
+    }
+
+    /// Shade sample `sample` of pixel `(i, j)`, seeded as
+    /// [`RenderOptions::seed`] asks.
+    fn sample_color(
+        &self,
+        world: &dyn Hittable,
+        i: u32,
+        j: u32,
+        sample: u32,
+        render_options: &RenderOptions,
+    ) -> Color {
+        Self::seed_sample(i, j, sample, render_options);
+        let (ray_origin, ray_dir) = self.primary_ray_components(i, j);
+        let r = Ray::new(&ray_origin, ray_dir).with_differential(self.differential_at(i, j));
+        self.shade(&r, world, render_options)
+    }
 
-        use AntialiasOptions::*;
+    /// Origin and direction of a primary ray for pixel `(i, j)`: through the
+    /// pixel center when antialiasing is disabled, and through a jittered point otherwise.
+    fn primary_ray_components(&self, i: u32, j: u32) -> (Point, UtVector) {
         match self.image_options.antialias {
-            Disabled => {
+            AntialiasOptions::Disabled => {
                 let pixel_center = self.get_pixel_center_coordinates(i, j);
                 let ray_origin = if self.model.uses_defocus() {
                     self.defocus_disk_sample()
                 } else {
                     self.pose.center
                 };
-                let ray_direction = (pixel_center - ray_origin).unit();
-                let r = Ray::new(&ray_origin, ray_direction);
-                pixel_color += r.color(world, 50);
+                (ray_origin, (pixel_center - ray_origin).unit())
             }
-            Enabled(samples_per_pixel) => {
-                for _ in 0..samples_per_pixel {
-                    let (ray_origin, ray_dir) = self.get_antialiasing_ray_components(i, j);
-                    let r = Ray::new(&ray_origin, ray_dir);
-                    pixel_color += r.color(world, 50) * self.pixel_samples_scale.unwrap();
-                }
+            AntialiasOptions::Enabled(_) => self.get_antialiasing_ray_components(i, j),
+        }
+    }
+
+    /// Direction differentials from pixel `(i, j)` to its right and lower neighbours.
+    fn differential_at(&self, i: u32, j: u32) -> RayDifferential {
+        let pixel_center = self.get_pixel_center_coordinates(i, j);
+        let to_pixel = |target: Point| (target - self.pose.center).unit().relax();
+        let dir = to_pixel(pixel_center);
+        RayDifferential {
+            dx: to_pixel(pixel_center + self.pixel_delta_u) - dir,
+            dy: to_pixel(pixel_center + self.pixel_delta_v) - dir,
+        }
+    }
+
+    /// Compute the radiance of one primary ray, at a random wavelength for
+    /// spectral renders, and clamp it if [`RenderOptions::clamp_indirect`] is set.
+    fn shade(&self, ray: &Ray, world: &dyn Hittable, render_options: &RenderOptions) -> Color {
+        // A matte is not light, so it is neither tinted by a wavelength nor
+        // clamped
+        if matches!(render_options.pass, RenderPass::Coverage)
+            && render_options.integrator.is_none()
+        {
+            return self.shade_pass(ray, world, render_options);
+        }
+
+        let sample = if render_options.spectral {
+            let (min, max) = VISIBLE_WAVELENGTHS;
+            let wavelength = rand::random_range(min, max);
+            let ray = ray.with_wavelength(wavelength);
+            self.shade_pass(&ray, world, render_options) * Color::from_wavelength(wavelength)
+        } else {
+            self.shade_pass(ray, world, render_options)
+        };
+
+        match render_options.clamp {
+            Some(max) => Color::new(
+                sample.r().min(max),
+                sample.g().min(max),
+                sample.b().min(max),
+            ),
+            None => sample,
+        }
+    }
+
+    /// Compute the radiance of one primary ray with the custom integrator, or
+    /// else according to the selected [`RenderPass`].
+    fn shade_pass(&self, ray: &Ray, world: &dyn Hittable, render_options: &RenderOptions) -> Color {
+        if let Some(CustomIntegrator(integrator)) = &render_options.integrator {
+            return integrator.radiance(ray, world, MAX_DEPTH);
+        }
+
+        match render_options.pass {
+            RenderPass::Beauty => PathTracer::trace(
+                ray,
+                world,
+                MAX_DEPTH,
+                render_options.lights.as_ref(),
+                render_options.direct_lighting,
+                &render_options.surroundings,
+            ),
+            RenderPass::AmbientOcclusion { radius, samples } => {
+                ray.ambient_occlusion_in(world, radius, samples, &render_options.surroundings)
+            }
+            RenderPass::Normals { space } => self.normal_color(ray, world, space),
+            RenderPass::Coverage => {
+                let hit = world.hit_p(Interval::new(0.001, f64::MAX), ray);
+                let coverage = if hit { 1.0 } else { 0.0 };
+                Color::new(coverage, coverage, coverage)
             }
         }
-        pixel_color
+    }
+
+    /// The outward normal at the ray's first hit in `space`, as a color.
+    fn normal_color(&self, ray: &Ray, world: &dyn Hittable, space: NormalSpace) -> Color {
+        let Some(record) = world.hit(Interval::new(0.001, f64::MAX), ray) else {
+            return Color::new(0.0, 0.0, 0.0);
+        };
+        let normal = if record.front_face() {
+            record.normal()
+        } else {
+            -record.normal()
+        };
+        let [x, y, z] = match space {
+            NormalSpace::World => [normal.x(), normal.y(), normal.z()],
+            NormalSpace::Camera => {
+                let pose = &self.pose;
+                [pose.u, pose.v, pose.w].map(|axis| normal.dot(&axis))
+            }
+        };
+        Color::new(x + 1.0, y + 1.0, z + 1.0) * 0.5
     }
 
     fn get_pixel_center_coordinates(&self, i: u32, j: u32) -> Point {
@@ -584,6 +2056,207 @@ impl Camera {
     }
 }
 
+/// Launch the platform's default viewer for `path` without waiting for it to exit.
+#[cfg(feature = "open")]
+fn open_in_viewer(path: &Path) -> io::Result<()> {
+    use std::process::Command;
+
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(target_os = "windows") {
+        let mut command = Command::new("cmd");
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+    command.arg(path).spawn().map(|_| ())
+}
+
+/// Render several cameras against the same world, writing each view to the
+/// matching path in `paths`.
+///
+/// The world is shared by every view, so any work done to prepare it is only
+/// paid for once. Returns [`RenderError::Io`] with an
+/// [`io::ErrorKind::InvalidInput`] error when `cameras` and `paths` have
+/// different lengths.
+pub fn render_views<T: AsRef<Path>>(
+    world: &dyn Hittable,
+    cameras: &[Camera],
+    paths: &[T],
+) -> Result<(), RenderError> {
+    if cameras.len() != paths.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "each camera must have exactly one output path",
+        )
+        .into());
+    }
+
+    for (view, (camera, path)) in cameras.iter().zip(paths).enumerate() {
+        info!("Rendering view {} of {}", view + 1, cameras.len());
+        camera.render(world, path)?;
+    }
+
+    Ok(())
+}
+
+/// Renders cameras on a thread pool of its own instead of rayon's global pool.
+///
+/// A [`Renderer`] can be shared between threads, so a service rendering many
+/// scenes at once keeps to a fixed number of worker threads in total.
+pub struct Renderer {
+    pool: rayon::ThreadPool,
+}
+
+impl Renderer {
+    /// Create a renderer with `threads` workers, or one per CPU when `threads` is 0.
+    pub fn new(threads: usize) -> io::Result<Self> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .thread_name(|index| format!("renderer-{index}"))
+            .build()
+            .map_err(io::Error::other)?;
+        Ok(Self { pool })
+    }
+
+    /// The number of worker threads in the pool.
+    pub fn threads(&self) -> usize {
+        self.pool.current_num_threads()
+    }
+
+    /// Render `camera` to `path` like [`Camera::render`], on this renderer's pool.
+    pub fn render<T: AsRef<Path>>(
+        &self,
+        camera: &Camera,
+        world: &dyn Hittable,
+        path: T,
+    ) -> Result<(), RenderError> {
+        self.render_with_options(camera, world, path, &RenderOptions::default())
+    }
+
+    /// Render `camera` to `path` like [`Camera::render_with_options`], on this
+    /// renderer's pool.
+    pub fn render_with_options<T: AsRef<Path>>(
+        &self,
+        camera: &Camera,
+        world: &dyn Hittable,
+        path: T,
+        render_options: &RenderOptions,
+    ) -> Result<(), RenderError> {
+        let path = path.as_ref();
+        self.pool
+            .install(|| camera.render_with_options(world, path, render_options))
+    }
+}
+
+/// How [`Camera::render_cached`] produced its output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CacheStatus {
+    /// A matching render was copied from the cache.
+    Hit,
+    /// Nothing matched, so the scene was rendered and added to the cache.
+    Miss,
+    /// The world has no fingerprint, so the scene was rendered without caching.
+    Uncacheable,
+}
+
+/// A rectangular pixel region given as `(x, y, width, height)`.
+pub type Region = (u32, u32, u32, u32);
+
+/// Reassemble regions rendered with [`Camera::render_region`] into one
+/// `width` by `height` image.
+///
+/// Pixels not covered by any region are left black. Returns
+/// [`ConfigError::InvalidRegion`] if a region does not fit inside the output or
+/// does not match the size of its image.
+pub fn stitch_regions(
+    width: u32,
+    height: u32,
+    tiles: Vec<(Region, Image)>,
+) -> Result<Image, ConfigError> {
+    if width == 0 || height == 0 {
+        return Err(ConfigError::InvalidImageDimensions);
+    }
+
+    let mut output = Image::new(width, height);
+    for (region, tile) in tiles {
+        validate_region(region, width, height)?;
+        let (x0, y0, region_width, region_height) = region;
+        if tile.width() != region_width || tile.height() != region_height {
+            return Err(ConfigError::InvalidRegion);
+        }
+
+        for y in 0..region_height {
+            for x in 0..region_width {
+                output.set(
+                    x0 + x,
+                    y0 + y,
+                    tile.pixels()[(y * region_width + x) as usize],
+                );
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+/// Reassemble the tiles written by [`Camera::render_tiles_to_dir`] in `dir`.
+///
+/// The image is just large enough to hold every tile, and other files in the
+/// directory are ignored. As with [`Image::load_ppm`], colors are accurate to
+/// the 8 bits stored. Returns an [`io::ErrorKind::InvalidData`] error if there
+/// are no tiles or they do not fit together.
+pub fn stitch_tiles<P: AsRef<Path>>(dir: P) -> io::Result<Image> {
+    let invalid = |message| io::Error::new(io::ErrorKind::InvalidData, message);
+    let mut tiles = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let Some(offset) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("tile_")?.strip_suffix(".ppm"))
+            .and_then(|offset| offset.split_once('_'))
+            .and_then(|(x, y)| Some((x.parse::<u32>().ok()?, y.parse::<u32>().ok()?)))
+        else {
+            continue;
+        };
+        let tile = Image::load_ppm(&path)?;
+        tiles.push(((offset.0, offset.1, tile.width(), tile.height()), tile));
+    }
+
+    if tiles.is_empty() {
+        return Err(invalid("no tiles found"));
+    }
+
+    let extent = |end: fn(&Region) -> Option<u32>| {
+        tiles
+            .iter()
+            .map(|(region, _)| end(region))
+            .try_fold(0, |acc, end| Some(acc.max(end?)))
+    };
+    let width = extent(|&(x, _, width, _)| x.checked_add(width));
+    let height = extent(|&(_, y, _, height)| y.checked_add(height));
+    let (Some(width), Some(height)) = (width, height) else {
+        return Err(invalid("tile offsets overflow"));
+    };
+    stitch_regions(width, height, tiles).map_err(|_| invalid("tiles do not fit together"))
+}
+
+fn validate_region(region: Region, width: u32, height: u32) -> Result<(), ConfigError> {
+    let (x, y, region_width, region_height) = region;
+    let fits =
+        |start: u32, len: u32, max: u32| start.checked_add(len).is_some_and(|end| end <= max);
+    if region_width == 0
+        || region_height == 0
+        || !fits(x, region_width, width)
+        || !fits(y, region_height, height)
+    {
+        return Err(ConfigError::InvalidRegion);
+    }
+    Ok(())
+}
+
 fn validate_viewport_dist(viewport_dist: f64) -> Result<(), ConfigError> {
     if !viewport_dist.is_finite() || viewport_dist <= 0.0 {
         return Err(ConfigError::InvalidViewportDistance);
@@ -605,15 +2278,36 @@ fn validate_defocus_angle(angle_degrees: f64) -> Result<(), ConfigError> {
     Ok(())
 }
 
+/// Errors returned by renders that write files.
+#[derive(Error, Debug)]
+pub enum RenderError {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    #[error("render was cancelled")]
+    Cancelled,
+    #[error("output image has more pixels than the maximum")]
+    ImageTooLarge,
+    #[error("invalid render setup: {0}")]
+    Build(#[from] ConfigError),
+}
+
 /// Errors returned while validating public camera configuration inputs.
 #[derive(Error, Debug, PartialEq, Eq)]
 pub enum ConfigError {
     #[error("image width and height must both be greater than zero")]
     InvalidImageDimensions,
+    #[error("image has more pixels than the configured maximum")]
+    ImageTooLarge,
+    #[error("region must be non-empty and fit inside the image")]
+    InvalidRegion,
     #[error("vertical field of view must be finite and between 0 and 180 degrees")]
     InvalidFieldOfView,
+    #[error("focal length and sensor size must be finite and greater than zero")]
+    InvalidLens,
     #[error("viewport distance must be finite and greater than zero")]
     InvalidViewportDistance,
+    #[error("viewport width and height must be finite and greater than zero")]
+    InvalidViewportSize,
     #[error("focus distance must be finite and greater than zero")]
     InvalidFocusDistance,
     #[error("defocus angle must be finite and between 0 and 180 degrees")]
@@ -624,4 +2318,8 @@ pub enum ConfigError {
     DegenerateViewDirection,
     #[error("up vector must not be parallel to the view direction")]
     UpVectorParallelToView,
+    #[error("camera basis vectors must be finite")]
+    DegenerateBasis,
+    #[error("scene has no bounded objects to frame")]
+    UnboundedScene,
 }
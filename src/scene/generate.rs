@@ -0,0 +1,94 @@
+//! Procedural scene generators.
+//!
+//! (Named `generate` rather than `gen`, which is a reserved keyword in Rust 2024.)
+
+use std::ops::RangeInclusive;
+
+use rand::Rng;
+
+use crate::HittableList;
+use crate::color::Color;
+use crate::materials::Lambertian;
+use crate::objects::{Aabb, Sphere};
+use crate::vector::{Point, Vector};
+
+/// How many candidates are tried per requested sphere before giving up.
+const ATTEMPTS_PER_SPHERE: usize = 30;
+
+/// Scatter up to `count` non-overlapping matte spheres with centers in `region`.
+///
+/// See [`sphere_placements`] for how positions are chosen. Each sphere gets a
+/// random Lambertian albedo drawn from `rng`.
+pub fn poisson_spheres<R: Rng + ?Sized>(
+    region: &Aabb,
+    count: usize,
+    radius_range: RangeInclusive<f64>,
+    rng: &mut R,
+) -> HittableList {
+    let mut world = HittableList::new();
+    for (center, radius) in sphere_placements(region, count, radius_range, rng) {
+        let albedo = Color::new(rng.random(), rng.random(), rng.random());
+        world.add(Sphere::new(center, radius, Lambertian::new(albedo)));
+    }
+    world
+}
+
+/// Pick up to `count` non-overlapping `(center, radius)` pairs by dart throwing.
+///
+/// Candidates are drawn uniformly from `region` and `radius_range`, and are
+/// rejected if they would intersect an already accepted sphere. Crowded
+/// regions may yield fewer than `count` spheres.
+pub fn sphere_placements<R: Rng + ?Sized>(
+    region: &Aabb,
+    count: usize,
+    radius_range: RangeInclusive<f64>,
+    rng: &mut R,
+) -> Vec<(Point, f64)> {
+    let (min, max) = (region.min(), region.max());
+    let mut placed: Vec<(Point, f64)> = Vec::with_capacity(count);
+
+    for _ in 0..count.saturating_mul(ATTEMPTS_PER_SPHERE) {
+        if placed.len() == count {
+            break;
+        }
+
+        let center = Point::new(
+            rng.random_range(min.x()..=max.x()),
+            rng.random_range(min.y()..=max.y()),
+            rng.random_range(min.z()..=max.z()),
+        );
+        let radius = rng.random_range(radius_range.clone());
+
+        let overlaps = placed
+            .iter()
+            .any(|(other, other_radius)| (center - other).len() <= radius + other_radius);
+        if !overlaps {
+            placed.push((center, radius));
+        }
+    }
+
+    placed
+}
+
+/// Move every object in `world` by its own random offset of length at most
+/// `max_offset`, for natural-looking variation in scattered props.
+///
+/// Offsets are uniform over the ball of radius `max_offset`. Wrappers such as
+/// [`Masked`](crate::objects::Masked), [`Scale`](crate::objects::Scale) and
+/// [`BvhNode`](crate::objects::BvhNode) move whatever they wrap; objects that
+/// cannot be moved (see [`Hittable::translated`](crate::objects::Hittable::translated))
+/// stay put.
+pub fn jitter_positions<R: Rng + ?Sized>(world: &mut HittableList, max_offset: f64, rng: &mut R) {
+    world.translate_each(|| {
+        loop {
+            let offset = Vector::new(
+                rng.random_range(-1.0..=1.0),
+                rng.random_range(-1.0..=1.0),
+                rng.random_range(-1.0..=1.0),
+            );
+            if offset.len_squared() <= 1.0 {
+                break offset * max_offset;
+            }
+        }
+    });
+}
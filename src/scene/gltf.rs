@@ -0,0 +1,84 @@
+//! Minimal glTF 2.0 import.
+//!
+//! Only triangle geometry and the metallic-roughness factors of each
+//! primitive's material are read. Textures, node transforms, and cameras are
+//! ignored, so meshes are placed in their own local space.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use gltf::mesh::Mode;
+use thiserror::Error;
+
+use crate::HittableList;
+use crate::color::Color;
+use crate::materials::{Lambertian, Material, Metal};
+use crate::objects::Mesh;
+use crate::vector::Point;
+
+/// Load every mesh in the glTF file at `path` into a [`HittableList`].
+///
+/// Each primitive becomes a [`Mesh`]. Materials with a metallic factor of at
+/// least 0.5 become [`Metal`] using the roughness factor; everything else
+/// becomes [`Lambertian`]. Only the base color factor is used for albedo.
+pub fn load<P: AsRef<Path>>(path: P) -> Result<HittableList, Error> {
+    let (document, buffers, _images) = gltf::import(path)?;
+
+    let mut world = HittableList::new();
+    for mesh in document.meshes() {
+        for primitive in mesh.primitives() {
+            if primitive.mode() != Mode::Triangles {
+                return Err(Error::UnsupportedPrimitive);
+            }
+
+            let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+            let vertices: Vec<_> = reader
+                .read_positions()
+                .ok_or(Error::MissingPositions)?
+                .map(|[x, y, z]| Point::new(x as f64, y as f64, z as f64))
+                .collect();
+            let indices: Vec<_> = match reader.read_indices() {
+                Some(indices) => indices.into_u32().map(|i| i as usize).collect(),
+                None => (0..vertices.len()).collect(),
+            };
+            let faces = indices
+                .chunks_exact(3)
+                .map(|face| [face[0], face[1], face[2]])
+                .collect();
+
+            let pbr = primitive.material().pbr_metallic_roughness();
+            let [r, g, b, _] = pbr.base_color_factor();
+            let material = pbr_material(
+                Color::new(r as f64, g as f64, b as f64),
+                pbr.metallic_factor() as f64,
+                pbr.roughness_factor() as f64,
+            );
+
+            world.add(Mesh::new(vertices, faces, material).ok_or(Error::InvalidIndices)?);
+        }
+    }
+
+    Ok(world)
+}
+
+/// Map glTF metallic-roughness parameters onto one of the crate's materials.
+fn pbr_material(base_color: Color, metallic: f64, roughness: f64) -> Arc<dyn Material> {
+    if metallic >= 0.5 {
+        Arc::new(Metal::new(base_color, roughness))
+    } else {
+        Arc::new(Lambertian::new(base_color))
+    }
+}
+
+/// Errors returned while importing a glTF file.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("failed to read glTF file: {0}")]
+    Import(#[from] gltf::Error),
+    #[error("only triangle-list primitives are supported")]
+    UnsupportedPrimitive,
+    #[error("primitive has no vertex positions")]
+    MissingPositions,
+    #[error("primitive indices refer to vertices that do not exist")]
+    InvalidIndices,
+}
@@ -0,0 +1,130 @@
+//! Pluggable per-ray shading.
+//!
+//! An [`Integrator`] turns a primary ray into the radiance it carries back to
+//! the camera. Renders use [`PathTracer`] unless
+//! [`RenderOptions::integrator`](super::RenderOptions::integrator) swaps in
+//! another one.
+
+use crate::HittableList;
+use crate::background::Background;
+use crate::color::Color;
+use crate::objects::Hittable;
+use crate::ray::{LightSampler, Ray, Surroundings};
+
+use super::DirectLighting;
+
+/// An algorithm computing the radiance carried along a primary ray.
+pub trait Integrator: Send + Sync {
+    /// Radiance arriving along `ray` from `world`, following at most `depth`
+    /// bounces.
+    fn radiance(&self, ray: &Ray, world: &dyn Hittable, depth: u32) -> Color;
+}
+
+/// Unidirectional path tracing through materials, optionally sampling lights
+/// directly with next event estimation.
+#[derive(Clone, Debug)]
+pub struct PathTracer {
+    lights: Option<HittableList>,
+    direct_lighting: DirectLighting,
+    surroundings: Surroundings,
+}
+
+impl PathTracer {
+    /// A path tracer that only finds lights by following material-sampled rays.
+    pub fn new() -> Self {
+        Self {
+            lights: None,
+            direct_lighting: DirectLighting::default(),
+            surroundings: Surroundings::default(),
+        }
+    }
+
+    /// Sample `lights` directly, as with [`RenderOptions::lights`](super::RenderOptions::lights).
+    pub fn lights(mut self, lights: HittableList) -> Self {
+        self.lights = Some(lights);
+        self
+    }
+
+    /// Choose how direct light from [`PathTracer::lights`] is estimated.
+    pub fn direct_lighting(mut self, direct_lighting: DirectLighting) -> Self {
+        self.direct_lighting = direct_lighting;
+        self
+    }
+
+    /// Add constant fill light, as with [`RenderOptions::ambient`](super::RenderOptions::ambient).
+    pub fn ambient(mut self, ambient: Color) -> Self {
+        self.surroundings.ambient = ambient;
+        self
+    }
+
+    /// Set what escaping rays see, as with [`RenderOptions::background`](super::RenderOptions::background).
+    pub fn background(mut self, background: Background) -> Self {
+        self.surroundings.background = background;
+        self
+    }
+
+    /// Show camera rays a different background, as with
+    /// [`RenderOptions::primary_background`](super::RenderOptions::primary_background).
+    pub fn primary_background(mut self, background: Background) -> Self {
+        self.surroundings.primary_background = Some(background);
+        self
+    }
+
+    /// Shared by [`PathTracer`] and the default beauty pass, which keeps its
+    /// settings on [`RenderOptions`](super::RenderOptions).
+    pub(super) fn trace(
+        ray: &Ray,
+        world: &dyn Hittable,
+        depth: u32,
+        lights: Option<&HittableList>,
+        direct_lighting: DirectLighting,
+        surroundings: &Surroundings,
+    ) -> Color {
+        match LightSampler::for_paths(lights, direct_lighting, surroundings) {
+            Some(lights) => {
+                ray.color_nee(world, &lights, depth, direct_lighting, surroundings, None)
+            }
+            None => ray.color_in(world, depth, surroundings),
+        }
+    }
+}
+
+impl Default for PathTracer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Integrator for PathTracer {
+    fn radiance(&self, ray: &Ray, world: &dyn Hittable, depth: u32) -> Color {
+        Self::trace(
+            ray,
+            world,
+            depth,
+            self.lights.as_ref(),
+            self.direct_lighting,
+            &self.surroundings,
+        )
+    }
+}
+
+/// Grayscale ambient occlusion, as with [`RenderPass::AmbientOcclusion`](super::RenderPass::AmbientOcclusion).
+#[derive(Clone, Copy, Debug)]
+pub struct AmbientOcclusion {
+    radius: f64,
+    samples: u32,
+}
+
+impl AmbientOcclusion {
+    /// Shoot `samples` hemisphere rays of length `radius` from each primary hit.
+    pub fn new(radius: f64, samples: u32) -> Self {
+        Self { radius, samples }
+    }
+}
+
+impl Integrator for AmbientOcclusion {
+    /// Ignores `depth`, since occlusion rays never bounce.
+    fn radiance(&self, ray: &Ray, world: &dyn Hittable, _depth: u32) -> Color {
+        ray.ambient_occlusion(world, self.radius, self.samples)
+    }
+}
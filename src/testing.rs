@@ -0,0 +1,101 @@
+//! Approximate-equality assertions for tests, enabled by the `testing` feature.
+//!
+//! [`assert_vec_eq!`](crate::assert_vec_eq) accepts anything with three
+//! components ([`Vector`], [`UtVector`], [`Point`]) and
+//! [`assert_color_eq!`](crate::assert_color_eq) compares [`Color`]s. Both take
+//! an optional epsilon, defaulting to [`EPSILON`].
+
+use crate::color::Color;
+use crate::vector::{Point, UtVector, Vector};
+
+/// Largest componentwise difference the assertions accept by default.
+pub const EPSILON: f64 = 1e-9;
+
+/// A value that can be compared componentwise.
+pub trait Components {
+    fn components(&self) -> [f64; 3];
+}
+
+impl Components for Vector {
+    fn components(&self) -> [f64; 3] {
+        [self.x(), self.y(), self.z()]
+    }
+}
+
+impl Components for UtVector {
+    fn components(&self) -> [f64; 3] {
+        self.inner().components()
+    }
+}
+
+impl Components for Point {
+    fn components(&self) -> [f64; 3] {
+        [self.x(), self.y(), self.z()]
+    }
+}
+
+impl Components for Color {
+    fn components(&self) -> [f64; 3] {
+        [self.r(), self.g(), self.b()]
+    }
+}
+
+/// The first component where `left` and `right` differ by more than
+/// `epsilon`, with that difference. NaN never compares equal.
+pub fn first_mismatch(left: [f64; 3], right: [f64; 3], epsilon: f64) -> Option<(usize, f64)> {
+    (0..3)
+        .map(|i| (i, (left[i] - right[i]).abs()))
+        .find(|&(_, difference)| difference.is_nan() || difference > epsilon)
+}
+
+/// Assert that two vectors or points are equal to within an epsilon in every
+/// component, which defaults to [`testing::EPSILON`](crate::testing::EPSILON).
+///
+/// ```
+/// # use ray_tracing_rs::{assert_vec_eq, vector::Vector};
+/// assert_vec_eq!(Vector::new(0.1 + 0.2, 0.0, 1.0), Vector::new(0.3, 0.0, 1.0));
+/// assert_vec_eq!(Vector::new(1.0, 0.0, 0.0), Vector::new(1.01, 0.0, 0.0), 0.1);
+/// ```
+#[macro_export]
+macro_rules! assert_vec_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_vec_eq!($left, $right, $crate::testing::EPSILON)
+    };
+    ($left:expr, $right:expr, $epsilon:expr $(,)?) => {
+        $crate::__assert_components_eq!(["x", "y", "z"], $left, $right, $epsilon)
+    };
+}
+
+/// Assert that two colors are equal to within an epsilon in every channel,
+/// which defaults to [`testing::EPSILON`](crate::testing::EPSILON).
+///
+/// ```
+/// # use ray_tracing_rs::{assert_color_eq, color::Color};
+/// assert_color_eq!(Color::new(0.5, 0.5, 0.5) * 2.0, Color::new(1.0, 1.0, 1.0));
+/// ```
+#[macro_export]
+macro_rules! assert_color_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_color_eq!($left, $right, $crate::testing::EPSILON)
+    };
+    ($left:expr, $right:expr, $epsilon:expr $(,)?) => {
+        $crate::__assert_components_eq!(["r", "g", "b"], $left, $right, $epsilon)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_components_eq {
+    ($names:expr, $left:expr, $right:expr, $epsilon:expr) => {{
+        use $crate::testing::Components as _;
+        let (left, right, epsilon): (_, _, f64) = (&$left, &$right, $epsilon);
+        if let Some((i, difference)) =
+            $crate::testing::first_mismatch(left.components(), right.components(), epsilon)
+        {
+            panic!(
+                "assertion `left ≈ right` failed: {} differs by {} (epsilon {})\n  left: {:?}\n right: {:?}",
+                $names[i], difference, epsilon, left, right,
+            );
+        }
+    }};
+}
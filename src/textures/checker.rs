@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use super::Texture;
+
+use crate::color::Color;
+use crate::utils;
+use crate::vector::Point;
+
+/// A checkerboard alternating between two textures in surface (u, v) space.
+#[derive(Clone)]
+pub struct Checker {
+    scale: f64,
+    even: Arc<dyn Texture>,
+    odd: Arc<dyn Texture>,
+}
+
+impl Checker {
+    /// Create a checkerboard with `scale` squares along each of `u` and `v`.
+    pub fn new(scale: f64, even: impl Texture + 'static, odd: impl Texture + 'static) -> Self {
+        Self {
+            scale,
+            even: Arc::new(even),
+            odd: Arc::new(odd),
+        }
+    }
+}
+
+impl Checker {
+    /// Integral of the 1D "odd square" indicator from 0 to `x`, in square units.
+    fn odd_integral(x: f64) -> f64 {
+        let half = x / 2.0;
+        half.floor() + 2.0 * (half - half.floor() - 0.5).max(0.0)
+    }
+
+    /// Fraction of the interval `[x - width / 2, x + width / 2]` on odd squares.
+    fn odd_fraction(x: f64, width: f64) -> f64 {
+        let half = width / 2.0;
+        (Self::odd_integral(x + half) - Self::odd_integral(x - half)) / width
+    }
+}
+
+impl Texture for Checker {
+    /// Box-filters the checkerboard in closed form, blending the two textures
+    /// by how much of the footprint falls on each.
+    fn value_filtered(&self, u: f64, v: f64, point: &Point, footprint: f64) -> Color {
+        let width = footprint * self.scale;
+        if width <= 0.0 {
+            return self.value(u, v, point);
+        }
+
+        let u_odd = Self::odd_fraction(u * self.scale, width);
+        let v_odd = Self::odd_fraction(v * self.scale, width);
+        // A square is odd when exactly one of its u and v indices is odd
+        let odd = u_odd + v_odd - 2.0 * u_odd * v_odd;
+
+        self.even.value(u, v, point) * (1.0 - odd) + self.odd.value(u, v, point) * odd
+    }
+
+    fn value(&self, u: f64, v: f64, point: &Point) -> Color {
+        let u_square = (u * self.scale).floor() as i64;
+        let v_square = (v * self.scale).floor() as i64;
+
+        if (u_square + v_square) % 2 == 0 {
+            self.even.value(u, v, point)
+        } else {
+            self.odd.value(u, v, point)
+        }
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint((
+            "Checker",
+            self.scale.to_bits(),
+            self.even.fingerprint()?,
+            self.odd.fingerprint()?,
+        )))
+    }
+}
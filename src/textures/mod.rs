@@ -0,0 +1,36 @@
+//! Module containing [`Texture`] trait, and implementors of it, such as:
+//! * [`SolidColor`]
+//! * [`Checker`]
+
+pub mod checker;
+pub mod solid_color;
+
+pub use checker::Checker;
+pub use solid_color::SolidColor;
+
+use crate::color::Color;
+use crate::vector::Point;
+
+/// Anything that can be sampled at a surface coordinate to produce a [`Color`].
+///
+/// `u` and `v` are the surface coordinates stored on a
+/// [`HitRecord`](crate::objects::HitRecord), both in `[0.0, 1.0]`. `point` is the
+/// world-space hit point, for textures that are defined in space rather than on
+/// the surface.
+pub trait Texture: Send + Sync {
+    fn value(&self, u: f64, v: f64, point: &Point) -> Color;
+
+    /// Sample the texture averaged over a square `footprint` wide in (u, v)
+    /// space, as given by [`Ray::uv_footprint`](crate::ray::Ray::uv_footprint).
+    ///
+    /// The default ignores the footprint and point-samples.
+    fn value_filtered(&self, u: f64, v: f64, point: &Point, _footprint: f64) -> Color {
+        self.value(u, v, point)
+    }
+
+    /// A hash of the texture's parameters, or `None`, the default, if it cannot
+    /// be fingerprinted. See [`Hittable::fingerprint`](crate::objects::Hittable::fingerprint).
+    fn fingerprint(&self) -> Option<u64> {
+        None
+    }
+}
@@ -0,0 +1,33 @@
+use super::Texture;
+
+use crate::color::Color;
+use crate::utils;
+use crate::vector::Point;
+
+/// A texture with the same value everywhere.
+#[derive(Clone, Copy)]
+pub struct SolidColor {
+    color: Color,
+}
+
+impl SolidColor {
+    pub fn new(color: Color) -> Self {
+        Self { color }
+    }
+
+    /// A gray texture whose channels are all `value`, useful for scalar
+    /// parameters such as roughness.
+    pub fn scalar(value: f64) -> Self {
+        Self::new(Color::new(value, value, value))
+    }
+}
+
+impl Texture for SolidColor {
+    fn value(&self, _u: f64, _v: f64, _point: &Point) -> Color {
+        self.color
+    }
+
+    fn fingerprint(&self) -> Option<u64> {
+        Some(utils::fingerprint(("SolidColor", self.color)))
+    }
+}
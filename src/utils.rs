@@ -1,28 +1,48 @@
 //! General utilities
 
 use std::f64::consts::PI;
+use std::hash::{DefaultHasher, Hash, Hasher};
 
 pub fn degrees_to_radians(degrees: f64) -> f64 {
     (degrees / 180.0) * PI
 }
 
+/// Hash `value` into a fingerprint that is stable between runs of the same build.
+///
+/// Used to key cached renders, so it only needs to change when the scene does.
+pub fn fingerprint(value: impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
 pub mod rand {
+    use std::cell::RefCell;
+
     use rand::prelude::*;
 
+    thread_local! {
+        /// Every random number the renderer draws on this thread comes from here,
+        /// so seeding it makes the thread's work reproducible.
+        static RNG: RefCell<StdRng> = RefCell::new(StdRng::from_os_rng());
+    }
+
+    /// Restart this thread's generator from `seed`.
+    pub fn seed(seed: u64) {
+        RNG.with(|rng| *rng.borrow_mut() = StdRng::seed_from_u64(seed));
+    }
+
     pub fn random_range(min: f64, max: f64) -> f64 {
-        // Might be inefficient, but I couldn't find an easy way around this
-        let mut rng = rand::rng();
-        rng.random_range(min..max)
+        RNG.with(|rng| rng.borrow_mut().random_range(min..max))
     }
     /// Return a random real in [0.0, 1.0)
     pub fn random() -> f64 {
-        // Might be inefficient, but I couldn't find an easy way around this
-        let mut rng = rand::rng();
-        rng.random_range(0.0..1.0)
+        random_range(0.0, 1.0)
     }
 }
 
 pub mod interval {
+    #[derive(Clone, Copy, Debug)]
     pub struct Interval {
         pub min: f64,
         pub max: f64,
@@ -4,6 +4,7 @@ use crate::utils::rand;
 
 use std::{
     array,
+    hash::{Hash, Hasher},
     ops::{self, Deref},
 };
 
@@ -14,6 +15,13 @@ pub struct Vector {
     z: f64,
 }
 
+/// Hashes the exact bit patterns of the components, for render fingerprints.
+impl Hash for Vector {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        [self.x, self.y, self.z].map(f64::to_bits).hash(state);
+    }
+}
+
 impl From<[f64; 3]> for Vector {
     fn from(value: [f64; 3]) -> Self {
         Self {
@@ -26,7 +34,7 @@ impl From<[f64; 3]> for Vector {
 
 impl Vector {
     /// Create a new [`Vector`]
-    pub fn new(x: f64, y: f64, z: f64) -> Self {
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
         Self { x, y, z }
     }
 
@@ -54,6 +62,19 @@ impl Vector {
         ])
     }
 
+    /// The component of this vector parallel to `other`.
+    ///
+    /// Projecting onto a zero vector gives non-finite components.
+    pub fn project_onto(&self, other: &Self) -> Self {
+        other * (self.dot(other) / other.len_squared())
+    }
+
+    /// The component of this vector perpendicular to `other`, so that it adds
+    /// back up with [`Vector::project_onto`] to the original vector.
+    pub fn reject_from(&self, other: &Self) -> Self {
+        self - self.project_onto(other)
+    }
+
     pub fn len_squared(&self) -> f64 {
         self.dot(self)
     }
@@ -79,8 +100,9 @@ impl Vector {
         Ok(UtVector { v: self })
     }
 
+    #[deprecated(note = "use `UtVector::new_unchecked`, which is checked in debug builds")]
     pub fn assert_unit_unsafe(self) -> UtVector {
-        UtVector { v: self }
+        UtVector::new_unchecked(self)
     }
 
     pub fn random() -> Self {
@@ -188,6 +210,14 @@ mod _utils {
     pub(super) fn mul_scalar_with_utvector(lhs: f64, rhs: &UtVector) -> Vector {
         Vector::from([lhs * rhs.x, lhs * rhs.y, lhs * rhs.z])
     }
+
+    pub(super) fn sub_utvector_from_vector(lhs: &Vector, rhs: &UtVector) -> Vector {
+        Vector::from([lhs.x - rhs.x, lhs.y - rhs.y, lhs.z - rhs.z])
+    }
+
+    pub(super) fn sub_vector_from_utvector(lhs: &UtVector, rhs: &Vector) -> Vector {
+        Vector::from([lhs.x - rhs.x, lhs.y - rhs.y, lhs.z - rhs.z])
+    }
 }
 
 use _utils::*;
@@ -330,12 +360,140 @@ impl ops::Neg for &Vector {
     }
 }
 
-/// Type alias for [`Vector`]
-pub type Point = Vector;
+/// A position in space.
+///
+/// Unlike [`Vector`], a [`Point`] has no length or direction, so it only
+/// supports position-appropriate arithmetic: `Point - Point` gives the
+/// [`Vector`] between them, and `Point + Vector` moves a point.
+///
+/// ```compile_fail
+/// # use ray_tracing_rs::Point;
+/// // Points cannot be normalized
+/// let dir = Point::new(1.0, 2.0, 3.0).unit();
+/// ```
+#[derive(Clone, Copy, Debug, Hash)]
+pub struct Point {
+    v: Vector,
+}
+
+impl Point {
+    /// Create a new [`Point`]
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self {
+            v: Vector::new(x, y, z),
+        }
+    }
+
+    /// The point at `(0, 0, 0)`.
+    pub fn origin() -> Self {
+        Self::new(0.0, 0.0, 0.0)
+    }
+
+    /// The point reached by moving `v` away from the origin.
+    pub fn from_vector(v: Vector) -> Self {
+        Self { v }
+    }
+
+    /// The vector from the origin to this point.
+    pub fn to_vector(self) -> Vector {
+        self.v
+    }
+
+    /// Scale this point's distance from the origin by `factor`.
+    pub fn scaled(self, factor: f64) -> Self {
+        Self { v: self.v * factor }
+    }
+
+    /// Scale this point's distance from `about` by `factor`.
+    pub fn scaled_about(self, factor: f64, about: &Point) -> Self {
+        about + (self - about) * factor
+    }
+
+    pub fn x(&self) -> f64 {
+        self.v.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.v.y
+    }
+
+    pub fn z(&self) -> f64 {
+        self.v.z
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.v.is_finite()
+    }
+}
+
+// Point + Vector = Point
+impl ops::Add<Vector> for Point {
+    type Output = Point;
+    fn add(self, rhs: Vector) -> Self::Output {
+        Point::from_vector(add_vectors(&self.v, &rhs))
+    }
+}
+
+impl ops::Add<Vector> for &Point {
+    type Output = Point;
+    fn add(self, rhs: Vector) -> Self::Output {
+        Point::from_vector(add_vectors(&self.v, &rhs))
+    }
+}
+
+impl ops::AddAssign<Vector> for Point {
+    fn add_assign(&mut self, rhs: Vector) {
+        self.v = add_vectors(&self.v, &rhs);
+    }
+}
+
+// Point - Vector = Point
+impl ops::Sub<Vector> for Point {
+    type Output = Point;
+    fn sub(self, rhs: Vector) -> Self::Output {
+        Point::from_vector(sub_vectors(&self.v, &rhs))
+    }
+}
+
+impl ops::Sub<Vector> for &Point {
+    type Output = Point;
+    fn sub(self, rhs: Vector) -> Self::Output {
+        Point::from_vector(sub_vectors(&self.v, &rhs))
+    }
+}
+
+// Point - Point = Vector
+impl ops::Sub<Point> for Point {
+    type Output = Vector;
+    fn sub(self, rhs: Point) -> Self::Output {
+        sub_vectors(&self.v, &rhs.v)
+    }
+}
+
+impl ops::Sub<Point> for &Point {
+    type Output = Vector;
+    fn sub(self, rhs: Point) -> Self::Output {
+        sub_vectors(&self.v, &rhs.v)
+    }
+}
+
+impl ops::Sub<&Point> for Point {
+    type Output = Vector;
+    fn sub(self, rhs: &Point) -> Self::Output {
+        sub_vectors(&self.v, &rhs.v)
+    }
+}
+
+impl ops::Sub<&Point> for &Point {
+    type Output = Vector;
+    fn sub(self, rhs: &Point) -> Self::Output {
+        sub_vectors(&self.v, &rhs.v)
+    }
+}
 
 /// Represents a unit vector
 // I didn't make this `UtVector(Vector)` because I wanted the fields to be private so that it won't be initializable outside this module
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Hash)]
 pub struct UtVector {
     v: Vector,
 }
@@ -348,6 +506,20 @@ impl Deref for UtVector {
 }
 
 impl UtVector {
+    /// Wrap a vector that is already known to have length 1.0, skipping the
+    /// `sqrt` that [`Vector::unit`] would do.
+    ///
+    /// The length is only checked in debug builds, where a non-unit input
+    /// panics. Release builds trust the caller, so passing a non-unit vector
+    /// there silently produces a [`UtVector`] that is not actually unit length.
+    pub fn new_unchecked(v: Vector) -> Self {
+        debug_assert!(
+            (v.len_squared() - 1.0).abs() <= 1e-6,
+            "`UtVector::new_unchecked` called with non-unit vector {v:?}"
+        );
+        Self { v }
+    }
+
     pub fn relax(self) -> Vector {
         self.v
     }
@@ -395,6 +567,49 @@ impl ops::Add<Vector> for UtVector {
     }
 }
 
+impl ops::Add<&UtVector> for &Vector {
+    type Output = Vector;
+    fn add(self, rhs: &UtVector) -> Self::Output {
+        add_vector_to_utvector(self, rhs)
+    }
+}
+
+impl ops::Add<&Vector> for &UtVector {
+    type Output = Vector;
+    fn add(self, rhs: &Vector) -> Self::Output {
+        add_vector_to_utvector(rhs, self)
+    }
+}
+
+// Sub implementations
+impl ops::Sub<UtVector> for Vector {
+    type Output = Vector;
+    fn sub(self, rhs: UtVector) -> Self::Output {
+        sub_utvector_from_vector(&self, &rhs)
+    }
+}
+
+impl ops::Sub<&UtVector> for &Vector {
+    type Output = Vector;
+    fn sub(self, rhs: &UtVector) -> Self::Output {
+        sub_utvector_from_vector(self, rhs)
+    }
+}
+
+impl ops::Sub<Vector> for UtVector {
+    type Output = Vector;
+    fn sub(self, rhs: Vector) -> Self::Output {
+        sub_vector_from_utvector(&self, &rhs)
+    }
+}
+
+impl ops::Sub<&Vector> for &UtVector {
+    type Output = Vector;
+    fn sub(self, rhs: &Vector) -> Self::Output {
+        sub_vector_from_utvector(self, rhs)
+    }
+}
+
 // Neg implementations
 impl ops::Neg for UtVector {
     type Output = UtVector;
@@ -410,6 +625,13 @@ impl ops::Neg for &UtVector {
     }
 }
 
+impl ops::Mul<f64> for UtVector {
+    type Output = Vector;
+    fn mul(self, rhs: f64) -> Self::Output {
+        mul_scalar_with_utvector(rhs, &self)
+    }
+}
+
 impl ops::Mul<f64> for &UtVector {
     type Output = Vector;
     fn mul(self, rhs: f64) -> Self::Output {
@@ -417,6 +639,47 @@ impl ops::Mul<f64> for &UtVector {
     }
 }
 
+/// An orthonormal basis around a unit vector `w`, for moving between local
+/// (tangent-space) and world-space coordinates.
+#[derive(Debug, Clone, Copy)]
+pub struct Onb {
+    u: UtVector,
+    v: UtVector,
+    w: UtVector,
+}
+
+impl Onb {
+    /// Build a basis whose third axis is `w`. The first two axes are some
+    /// perpendicular pair, with no particular orientation around `w`.
+    pub fn new(w: UtVector) -> Self {
+        let helper = if w.x().abs() > 0.9 {
+            Vector::new(0.0, 1.0, 0.0)
+        } else {
+            Vector::new(1.0, 0.0, 0.0)
+        };
+        let v = w.cross(&helper).unit();
+        let u = UtVector::new_unchecked(w.cross(&v));
+        Self { u, v, w }
+    }
+
+    pub fn u(&self) -> UtVector {
+        self.u
+    }
+
+    pub fn v(&self) -> UtVector {
+        self.v
+    }
+
+    pub fn w(&self) -> UtVector {
+        self.w
+    }
+
+    /// The world-space vector with coordinates `local` in this basis.
+    pub fn transform(&self, local: &Vector) -> Vector {
+        self.u * local.x() + self.v * local.y() + self.w * local.z()
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("Length of given `Vector` is not 1.0")]
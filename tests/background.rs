@@ -0,0 +1,212 @@
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+use ray_tracing_rs::background::{Background, EnvironmentMap};
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::image::Image;
+use ray_tracing_rs::materials::{Lambertian, Metal};
+use ray_tracing_rs::objects::{Aabb, BvhNode, Hittable, Sphere};
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::scene::{DirectLighting, ParallelOptions, RenderOptions};
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{
+    CameraPose, HittableList, ImageOptions, Interval, Point, assert_color_eq, assert_vec_eq,
+    seed_thread_rng,
+};
+
+mod common;
+
+/// A dim sky with a tiny, very bright sun.
+fn sunny() -> Background {
+    let (width, height) = (64, 32);
+    let mut image = Image::from_pixels(
+        width,
+        height,
+        vec![Color::new(0.1, 0.1, 0.1); (width * height) as usize],
+    )
+    .unwrap();
+    for (x, y) in [(20, 8), (21, 8), (20, 9), (21, 9)] {
+        image.set(x, y, Color::new(5000.0, 5000.0, 4000.0));
+    }
+    Background::Environment(Arc::new(EnvironmentMap::new(image).unwrap()))
+}
+
+/// Mean and variance of single-sample estimates of the total radiance `∫ L dω`.
+fn estimate(samples: usize, mut sample: impl FnMut() -> f64) -> (f64, f64) {
+    let values: Vec<f64> = (0..samples).map(|_| sample()).collect();
+    let mean = values.iter().sum::<f64>() / samples as f64;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / samples as f64;
+    (mean, variance)
+}
+
+#[test]
+fn importance_sampling_reduces_variance_for_a_sunny_map() {
+    let background = sunny();
+
+    let (uniform_mean, uniform_variance) = estimate(20_000, || {
+        let dir = Vector::random_unit().unit();
+        background.radiance(&dir).r() * 4.0 * PI
+    });
+    let (importance_mean, importance_variance) = estimate(20_000, || {
+        let dir = background.random_direction();
+        background.radiance(&dir).r() / background.pdf_value(&dir)
+    });
+
+    assert!(importance_variance * 100.0 < uniform_variance);
+    // Both estimate the same integral.
+    assert!((importance_mean - uniform_mean).abs() < 0.5 * uniform_mean);
+}
+
+#[test]
+fn next_event_estimation_samples_the_sun() {
+    let mut world = HittableList::new();
+    world.add(Sphere::new(
+        Point::new(0.0, -1000.0, 0.0),
+        999.0,
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    ));
+    let pose = CameraPose::look_at(
+        Point::origin(),
+        Point::new(0.0, -1.0, -1.0),
+        Vector::new(0.0, 1.0, 0.0),
+    )
+    .unwrap();
+    let camera = common::pinhole_camera(pose, ImageOptions::new(4, 4).unwrap().antialias(0), 30.0);
+
+    // Mean and variance of the ground's brightness over repeated renders
+    let ground = |direct_lighting: DirectLighting| {
+        let options = RenderOptions::new()
+            .background(sunny())
+            .direct_lighting(direct_lighting)
+            .parallel(ParallelOptions::Series);
+        seed_thread_rng(11);
+        estimate(400, || {
+            let pixels = camera.render_in_memory_with_options(&world, &options);
+            pixels.iter().map(|pixel| pixel.r()).sum::<f64>() / pixels.len() as f64
+        })
+    };
+
+    // Bounces off the ground rarely find the sun by chance, but sampling the
+    // map by brightness finds it every time
+    let (chance_mean, chance_variance) = ground(DirectLighting::MaterialOnly);
+    let (sampled_mean, sampled_variance) = ground(DirectLighting::Mis);
+    assert!(sampled_variance * 100.0 < chance_variance);
+    assert!((sampled_mean - chance_mean).abs() < 0.5 * chance_mean);
+}
+
+#[test]
+fn empty_maps_are_rejected() {
+    assert!(EnvironmentMap::new(Image::new(0, 0)).is_none());
+}
+
+#[test]
+fn black_maps_are_sampled_uniformly() {
+    let black = EnvironmentMap::new(Image::new(8, 16)).unwrap();
+    assert!(black.is_black());
+
+    let (mean, _) = estimate(20_000, || {
+        let dir = black.random_direction();
+        1.0 / black.pdf_value(&dir)
+    });
+    assert!((mean - 4.0 * PI).abs() < 0.1 * 4.0 * PI);
+    assert_eq!(black.radiance(&Vector::new(0.0, 1.0, 0.0).unit()).r(), 0.0);
+}
+
+#[test]
+fn default_background_is_the_sky() {
+    let up = Vector::new(0.0, 1.0, 0.0).unit();
+    let sky = Background::default().radiance(&up);
+
+    assert_eq!(sky.r(), 0.5);
+    assert_eq!(Background::Sky.pdf_value(&up), 1.0 / (4.0 * PI));
+}
+
+#[test]
+fn primary_background_is_only_seen_by_camera_rays() {
+    let gray = Color::new(0.5, 0.5, 0.5);
+    let environment = EnvironmentMap::new(Image::from_pixels(1, 1, vec![gray]).unwrap()).unwrap();
+    let options = RenderOptions::new()
+        .background(Background::Environment(Arc::new(environment)))
+        .primary_background(Background::Sky);
+
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(0.0, 0.0, -1.0),
+        Vector::new(0.0, 1.0, 0.0),
+    )
+    .unwrap();
+    let camera = common::pinhole_camera(pose, ImageOptions::new(9, 9).unwrap(), 90.0);
+    let mut world = HittableList::new();
+    world.add(Sphere::new(
+        Point::new(0.0, 0.0, -3.0),
+        1.0,
+        Metal::new(Color::new(1.0, 1.0, 1.0), 0.0),
+    ));
+
+    let pixels = camera.render_in_memory_with_options(&world, &options);
+
+    // The mirror reflects the gray environment...
+    assert_color_eq!(pixels[4 * 9 + 4], gray);
+    // ...while the camera sees the sky gradient around it
+    let (top, bottom) = (pixels[4], pixels[8 * 9 + 4]);
+    assert!(top.r() < bottom.r(), "{top:?} {bottom:?}");
+    assert!(top.b() > 0.9 && bottom.b() > 0.9);
+}
+
+#[test]
+fn the_empty_box_is_absorbed_by_union() {
+    let unit = Aabb::new(Point::origin(), Point::new(1.0, 1.0, 1.0));
+    let merged = Aabb::EMPTY.union(&unit);
+    assert!(!unit.is_empty());
+    assert_vec_eq!(merged.min(), unit.min());
+    assert_vec_eq!(merged.max(), unit.max());
+    assert!(Aabb::EMPTY.union(&Aabb::EMPTY).is_empty());
+}
+
+#[test]
+fn empty_worlds_render_only_the_background() {
+    let world = HittableList::new();
+    let ray_t = Interval::new(0.001, f64::MAX);
+    let origin = Point::origin();
+    let ray = Ray::new(&origin, Vector::new(0.0, 0.0, -1.0).unit());
+    assert!(world.hit(ray_t, &ray).is_none());
+    assert!(!world.hit_p(ray_t, &ray));
+    assert!(world.bounds().is_empty());
+    assert!(!world.bounds().hit(ray_t, &ray));
+    assert!(world.bounding_box().is_none());
+    assert!(BvhNode::build(&world).is_none());
+
+    let teal = Color::new(0.2, 0.6, 0.6);
+    let environment = EnvironmentMap::new(Image::from_pixels(1, 1, vec![teal]).unwrap()).unwrap();
+    let background = Background::Environment(Arc::new(environment));
+    let pose = CameraPose::look_at(
+        origin,
+        Point::new(0.0, 0.0, -1.0),
+        Vector::new(0.0, 1.0, 0.0),
+    )
+    .unwrap();
+    let camera = common::pinhole_camera(pose, ImageOptions::new(6, 4).unwrap().antialias(4), 90.0);
+
+    let path = common::temp_path("empty-world.ppm");
+    for parallel in [
+        ParallelOptions::AllAtOnce,
+        ParallelOptions::ByRows,
+        ParallelOptions::Series,
+    ] {
+        let options = RenderOptions::new()
+            .background(background.clone())
+            .parallel(parallel);
+        for pixel in camera.render_in_memory_with_options(&world, &options) {
+            assert_color_eq!(pixel, teal);
+        }
+        camera.render_with_options(&world, &path, &options).unwrap();
+        let written = Image::load_ppm(&path).unwrap();
+        assert!(
+            written
+                .pixels()
+                .iter()
+                .all(|pixel| pixel.to_string() == teal.to_string())
+        );
+    }
+    std::fs::remove_file(&path).unwrap();
+}
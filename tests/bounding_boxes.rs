@@ -0,0 +1,98 @@
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::Lambertian;
+use ray_tracing_rs::objects::{Hittable, MovingSphere, Plane, Scale, Sphere};
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{HittableList, Interval, Point};
+
+fn material() -> Lambertian {
+    Lambertian::new(Color::new(0.5, 0.5, 0.5))
+}
+
+#[test]
+fn moving_sphere_box_spans_shutter_interval() {
+    let sphere = MovingSphere::new(
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(2.0, 0.0, 0.0),
+        0.5,
+        material(),
+    );
+
+    let bbox = sphere.bounding_box().unwrap();
+    assert_eq!(bbox.min().x(), -0.5);
+    assert_eq!(bbox.max().x(), 2.5);
+    assert_eq!(bbox.min().y(), -0.5);
+    assert_eq!(bbox.max().y(), 0.5);
+}
+
+#[test]
+fn list_box_is_union_of_bounded_objects() {
+    let mut world = HittableList::new();
+    assert!(world.bounding_box().is_none());
+
+    world
+        .add(Sphere::new(Point::new(-1.0, 0.0, 0.0), 0.5, material()))
+        .add(Sphere::new(Point::new(1.0, 1.0, 0.0), 0.5, material()));
+    let bbox = world.bounding_box().unwrap();
+    assert_eq!(bbox.min().x(), -1.5);
+    assert_eq!(bbox.max().y(), 1.5);
+
+    world.add(Plane::new(
+        Vector::new(0.0, 1.0, 0.0).unit(),
+        0.0,
+        material(),
+    ));
+    assert!(world.bounding_box().is_none());
+}
+
+#[test]
+fn transformed_sphere_matches_scale_wrapper() {
+    let center = Point::new(1.0, 0.5, -3.0);
+    let baked = Sphere::transformed(center, 0.5, 2.5, material());
+    let wrapped = Scale::new(Sphere::new(center, 0.5, material()), 2.5);
+
+    let origin = Point::origin();
+    for dir in [
+        center - origin,
+        center - origin + Vector::new(0.1, 0.0, 0.0),
+        center - origin + Vector::new(0.0, -0.1, 0.0),
+    ] {
+        let ray = Ray::new(&origin, dir.unit());
+        let interval = Interval::new(0.001, f64::MAX);
+        let a = baked.hit(interval, &ray).unwrap();
+        let b = wrapped.hit(interval, &ray).unwrap();
+
+        assert!((a.t() - b.t()).abs() < 1e-9);
+        assert!((a.point() - b.point()).len() < 1e-9);
+        assert!((a.normal().relax() - b.normal().relax()).len() < 1e-9);
+    }
+
+    let a = baked.bounding_box().unwrap();
+    let b = wrapped.bounding_box().unwrap();
+    assert!((a.min() - b.min()).len() < 1e-12);
+    assert!((a.max() - b.max()).len() < 1e-12);
+}
+
+#[test]
+fn alternate_sphere_constructors_match_new() {
+    let center = Point::new(0.5, 0.0, -4.0);
+    let by_radius = Sphere::new(center, 1.0, material());
+    let by_diameter = Sphere::from_diameter(center, 2.0, material());
+    let by_points = Sphere::bounding_two_points(
+        Point::new(0.5, -1.0, -4.0),
+        Point::new(0.5, 1.0, -4.0),
+        material(),
+    );
+
+    let origin = Point::origin();
+    let interval = Interval::new(0.001, f64::MAX);
+    for dir in [
+        center - origin,
+        center - origin + Vector::new(0.3, 0.2, 0.0),
+    ] {
+        let ray = Ray::new(&origin, dir.unit());
+        let expected = by_radius.hit(interval, &ray).unwrap().t();
+        assert!((by_diameter.hit(interval, &ray).unwrap().t() - expected).abs() < 1e-12);
+        assert!((by_points.hit(interval, &ray).unwrap().t() - expected).abs() < 1e-12);
+    }
+}
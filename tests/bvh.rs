@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use ray_tracing_rs::assert_color_eq;
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::{DiffuseLight, Lambertian, Material};
+use ray_tracing_rs::objects::{Aabb, BvhNode, BvhSplit, Hittable, Mesh, Plane, Sphere, Triangle};
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::scene::generate::poisson_spheres;
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{CameraPose, HittableList, ImageOptions, Interval, Point};
+
+mod common;
+
+/// Uneven scene: a dense cluster of small spheres plus a few spread out.
+fn world(rng: &mut StdRng) -> HittableList {
+    let mut world = poisson_spheres(
+        &Aabb::new(Point::new(-2.0, 0.0, -2.0), Point::new(2.0, 1.0, 2.0)),
+        150,
+        0.05..=0.2,
+        rng,
+    );
+    for x in [-40.0, 25.0, 60.0] {
+        world.add(Sphere::new(
+            Point::new(x, 1.0, -30.0),
+            3.0,
+            Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+        ));
+    }
+    world
+}
+
+#[test]
+fn bvh_hits_match_the_flat_list() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let world = world(&mut rng);
+    let median = BvhNode::build_with(&world, BvhSplit::Median).unwrap();
+    let sah = BvhNode::build_with(&world, BvhSplit::Sah).unwrap();
+
+    let origin = Point::new(0.0, 3.0, 8.0);
+    let interval = Interval::new(0.001, f64::MAX);
+    let mut hits = 0;
+    for _ in 0..2000 {
+        let target = Point::new(
+            rng.random_range(-3.0..3.0),
+            rng.random_range(-0.5..1.5),
+            rng.random_range(-3.0..3.0),
+        );
+        let ray = Ray::new(&origin, (target - origin).unit());
+
+        let expected = world.hit(interval, &ray).map(|record| record.t());
+        for tree in [&median, &sah] {
+            assert_eq!(tree.hit(interval, &ray).map(|record| record.t()), expected);
+            assert_eq!(tree.hit_p(interval, &ray), expected.is_some());
+        }
+        hits += expected.is_some() as usize;
+    }
+    assert!(hits > 100);
+}
+
+#[test]
+fn bvh_rejects_empty_and_unbounded_worlds() {
+    assert!(BvhNode::build(&HittableList::new()).is_none());
+
+    let mut world = HittableList::new();
+    world.add(Plane::new(
+        Vector::new(0.0, 1.0, 0.0).unit(),
+        0.0,
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    ));
+    assert!(BvhNode::build(&world).is_none());
+}
+
+#[test]
+fn refit_tracks_moved_objects_without_reshaping() {
+    let material = Lambertian::new(Color::new(0.5, 0.5, 0.5));
+    let mut world = HittableList::new();
+    for x in [-3.0, -1.0, 1.0, 3.0] {
+        world.add(Sphere::new(Point::new(x, 0.0, 0.0), 0.5, material.clone()));
+    }
+    let mut tree = BvhNode::build(&world).unwrap();
+    let order = |tree: &mut BvhNode| {
+        tree.objects_mut()
+            .into_iter()
+            .map(|object| object.bounding_box().unwrap().centroid().x())
+            .collect::<Vec<_>>()
+    };
+    let before = order(&mut tree);
+
+    // Move the sphere at x = 3 up out of the tree's current bounds
+    let moved = Point::new(3.0, 4.0, 0.0);
+    for object in tree.objects_mut() {
+        if object.bounding_box().unwrap().centroid().x() == 3.0 {
+            *object = Arc::new(Sphere::new(moved, 0.5, material.clone()));
+        }
+    }
+
+    let origin = Point::new(3.0, 4.0, 5.0);
+    let ray = Ray::new(&origin, Vector::new(0.0, 0.0, -1.0).unit());
+    let interval = Interval::new(0.001, f64::MAX);
+    assert!(tree.hit(interval, &ray).is_none());
+
+    assert!(tree.refit());
+    assert!((tree.hit(interval, &ray).unwrap().t() - 4.5).abs() < 1e-9);
+    assert_eq!(order(&mut tree), before);
+    assert_eq!(tree.bounding_box().unwrap().max().y(), 4.5);
+}
+
+#[test]
+fn builds_are_reproducible_when_centroids_tie() {
+    let material = Lambertian::new(Color::new(0.5, 0.5, 0.5));
+    let mut world = HittableList::new();
+    for radius in [0.5, 0.4, 0.3, 0.2, 0.1] {
+        world.add(Sphere::new(Point::origin(), radius, material.clone()));
+    }
+    for x in [-2.0, 2.0, 2.0] {
+        world.add(Sphere::new(Point::new(x, 0.0, 0.0), 0.5, material.clone()));
+    }
+
+    let leaves = |split| {
+        let mut tree = BvhNode::build_with(&world, split).unwrap();
+        tree.objects_mut()
+            .into_iter()
+            .map(|object| Arc::clone(object))
+            .collect::<Vec<_>>()
+    };
+    for split in [BvhSplit::Median, BvhSplit::Sah] {
+        let (first, second) = (leaves(split), leaves(split));
+        assert!(first.iter().zip(&second).all(|(a, b)| Arc::ptr_eq(a, b)));
+    }
+
+    // Tied spheres stay in the order they were added
+    let objects = world.objects();
+    let median = leaves(BvhSplit::Median);
+    assert!(Arc::ptr_eq(&median[0], &objects[5]));
+    assert!(
+        median[1..6]
+            .iter()
+            .zip(&objects[..5])
+            .all(|(a, b)| Arc::ptr_eq(a, b))
+    );
+    assert!(Arc::ptr_eq(&median[6], &objects[6]));
+    assert!(Arc::ptr_eq(&median[7], &objects[7]));
+}
+
+#[test]
+fn bvh_hits_axis_aligned_triangles() {
+    // A strip of triangles in the z = -2 plane, so every box in the tree is flat
+    let material = Lambertian::new(Color::new(0.5, 0.5, 0.5));
+    let mut world = HittableList::new();
+    for x in [-3.0, -1.0, 1.0, 3.0] {
+        world.add(Triangle::new(
+            Point::new(x - 1.0, -1.0, -2.0),
+            Point::new(x + 1.0, -1.0, -2.0),
+            Point::new(x, 1.0, -2.0),
+            material.clone(),
+        ));
+    }
+    let tree = BvhNode::build(&world).unwrap();
+    assert_eq!(tree.bounding_box().unwrap().max().z(), -2.0);
+
+    let interval = Interval::new(0.001, f64::MAX);
+    for x in [-3.0, -1.0, 1.0, 3.0] {
+        let origin = Point::new(x, 0.0, 0.0);
+        let ray = Ray::new(&origin, Vector::new(0.0, 0.0, -1.0).unit());
+        assert_eq!(
+            world.hit(interval, &ray).map(|record| record.t()),
+            Some(2.0)
+        );
+        assert_eq!(tree.hit(interval, &ray).map(|record| record.t()), Some(2.0));
+        assert!(tree.hit_p(interval, &ray));
+    }
+}
+
+#[test]
+fn flat_meshes_render_the_same_through_a_bvh() {
+    // Four floor tiles at y = -0.5, each a quad of two triangles
+    let light: Arc<dyn Material> = Arc::new(DiffuseLight::new(Color::new(0.9, 0.2, 0.2)));
+    let mut world = HittableList::new();
+    for (x, z) in [(-2.0, -2.0), (0.0, -2.0), (-2.0, 0.0), (0.0, 0.0)] {
+        let vertices = vec![
+            Point::new(x, -0.5, z),
+            Point::new(x + 2.0, -0.5, z),
+            Point::new(x + 2.0, -0.5, z + 2.0),
+            Point::new(x, -0.5, z + 2.0),
+        ];
+        let tile = Mesh::new(vertices, vec![[0, 1, 2], [0, 2, 3]], Arc::clone(&light));
+        world.add(tile.unwrap());
+    }
+    let tree = BvhNode::build(&world).unwrap();
+
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 2.0, 0.0),
+        Point::new(0.0, -0.5, 0.0),
+        Vector::new(0.0, 0.0, -1.0),
+    )
+    .unwrap();
+    let image = ImageOptions::new(12, 12).unwrap().antialias(0);
+    let camera = common::pinhole_camera(pose, image, 90.0);
+
+    let listed = camera.render_in_memory(&world);
+    let through_tree = camera.render_in_memory(&tree);
+    let floor = listed.iter().filter(|pixel| pixel.r() == 0.9).count();
+    assert!(floor > 80, "{floor}");
+    for (a, b) in listed.iter().zip(&through_tree) {
+        assert_color_eq!(a, b, 0.0);
+    }
+}
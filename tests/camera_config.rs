@@ -1,12 +1,17 @@
+use ray_tracing_rs::assert_vec_eq;
 use ray_tracing_rs::color::Color;
-use ray_tracing_rs::materials::Lambertian;
-use ray_tracing_rs::objects::Sphere;
+use ray_tracing_rs::materials::{DiffuseLight, Lambertian};
+use ray_tracing_rs::objects::{Hittable, Plane, Sphere};
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::scene::{RenderOptions, render_views};
 use ray_tracing_rs::vector::Vector;
 use ray_tracing_rs::{
     Camera, CameraConfig, CameraModel, CameraPose, ConfigError, HittableList, ImageOptions,
-    PerspectiveProjection, Point,
+    Interval, PerspectiveProjection, Point, RenderError,
 };
 
+mod common;
+
 fn test_camera(look_from: Point, look_at: Point) -> Camera {
     let pose = CameraPose::look_at(look_from, look_at, Vector::new(0.0, 1.0, 0.0)).unwrap();
     let image = ImageOptions::new(8, 4).unwrap();
@@ -44,6 +49,36 @@ fn image_options_reject_zero_dimensions() {
         ImageOptions::new(1, 0).unwrap_err(),
         ConfigError::InvalidImageDimensions
     );
+    assert_eq!(
+        ImageOptions::with_max_pixels(0, 10, 100).unwrap_err(),
+        ConfigError::InvalidImageDimensions
+    );
+
+    // The smallest valid image still has a usable aspect ratio
+    let single = ImageOptions::new(1, 1).unwrap();
+    assert_eq!(single.aspect_ratio(), 1.0);
+}
+
+#[test]
+fn image_options_report_their_settings() {
+    let image = ImageOptions::new(400, 225).unwrap().antialias(25);
+
+    assert_eq!((image.width(), image.height()), (400, 225));
+    assert_eq!(image.samples_per_pixel(), 25);
+    assert_eq!(image.antialias(0).samples_per_pixel(), 0);
+}
+
+#[test]
+fn image_options_reject_oversized_images() {
+    assert_eq!(
+        ImageOptions::new(100_000, 100_000).unwrap_err(),
+        ConfigError::ImageTooLarge
+    );
+    assert_eq!(
+        ImageOptions::with_max_pixels(100, 100, 9_999).unwrap_err(),
+        ConfigError::ImageTooLarge
+    );
+    assert!(ImageOptions::with_max_pixels(100, 100, 10_000).is_ok());
 }
 
 #[test]
@@ -62,6 +97,24 @@ fn perspective_projection_rejects_invalid_values() {
     );
 }
 
+#[test]
+fn lens_converts_focal_length_to_field_of_view() {
+    let normal = PerspectiveProjection::lens(50.0, 36.0).unwrap();
+    assert!((normal.vfov_degrees() - 39.6).abs() < 0.05);
+
+    let wide = PerspectiveProjection::lens(24.0, 36.0).unwrap();
+    assert!(wide.vfov_degrees() > normal.vfov_degrees());
+
+    assert_eq!(
+        PerspectiveProjection::lens(0.0, 36.0).unwrap_err(),
+        ConfigError::InvalidLens
+    );
+    assert_eq!(
+        PerspectiveProjection::lens(50.0, f64::INFINITY).unwrap_err(),
+        ConfigError::InvalidLens
+    );
+}
+
 #[test]
 fn camera_model_rejects_invalid_values() {
     assert_eq!(
@@ -108,6 +161,19 @@ fn camera_pose_rejects_parallel_up_vector() {
     );
 }
 
+#[test]
+fn camera_pose_rejects_non_finite_basis() {
+    assert_eq!(
+        CameraPose::look_at(
+            Point::new(0.0, 0.0, 1e308),
+            Point::new(0.0, 0.0, -1e308),
+            Vector::new(0.0, 1.0, 0.0),
+        )
+        .unwrap_err(),
+        ConfigError::DegenerateBasis
+    );
+}
+
 #[test]
 fn one_camera_can_render_multiple_worlds() {
     let camera = test_camera(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, -1.0));
@@ -135,3 +201,287 @@ fn multiple_cameras_can_render_the_same_world() {
     assert_eq!(pixels_b.len(), 32);
     assert_ne!(pixels_a, pixels_b);
 }
+
+#[test]
+fn camera_config_rejects_invalid_viewport_size() {
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(0.0, 0.0, -1.0),
+        Vector::new(0.0, 1.0, 0.0),
+    )
+    .unwrap();
+    let config = CameraConfig::new(
+        pose,
+        ImageOptions::new(8, 8).unwrap(),
+        PerspectiveProjection::new(60.0).unwrap(),
+        CameraModel::pinhole(1.0).unwrap(),
+    );
+    assert_eq!(
+        config.clone().viewport_size(0.0, 1.0).unwrap_err(),
+        ConfigError::InvalidViewportSize
+    );
+    assert_eq!(
+        config.viewport_size(1.0, f64::INFINITY).unwrap_err(),
+        ConfigError::InvalidViewportSize
+    );
+}
+
+#[test]
+fn viewport_override_stretches_render() {
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(0.0, 0.0, -1.0),
+        Vector::new(0.0, 1.0, 0.0),
+    )
+    .unwrap();
+    let config = CameraConfig::new(
+        pose,
+        ImageOptions::new(16, 16).unwrap(),
+        PerspectiveProjection::new(60.0).unwrap(),
+        CameraModel::pinhole(1.0).unwrap(),
+    )
+    .viewport_size(2.0, 1.0)
+    .unwrap();
+    let camera = Camera::new(config);
+
+    let mut world = HittableList::new();
+    let material = Lambertian::new(Color::new(0.8, 0.3, 0.3));
+    world.add(Sphere::new(Point::new(0.0, 0.0, -1.0), 0.25, material));
+
+    let blank = pixels_to_strings(&camera, &blank_world());
+    let sphere = pixels_to_strings(&camera, &world);
+    let covered = |i: usize| blank[i] != sphere[i];
+
+    let row_coverage = (0..16).filter(|&x| covered(8 * 16 + x)).count();
+    let column_coverage = (0..16).filter(|&y| covered(y * 16 + 8)).count();
+
+    assert!(row_coverage > 0);
+    assert!(column_coverage > row_coverage);
+}
+
+#[test]
+fn render_views_writes_one_file_per_camera() {
+    let world = sphere_world();
+    let cameras = [
+        test_camera(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, -1.0)),
+        test_camera(Point::new(0.5, 0.2, 0.0), Point::new(0.0, 0.0, -1.0)),
+    ];
+    let paths = [
+        common::temp_path("render-views-a.ppm"),
+        common::temp_path("render-views-b.ppm"),
+    ];
+
+    render_views(&world, &cameras, &paths).unwrap();
+
+    let a = std::fs::read_to_string(&paths[0]).unwrap();
+    let b = std::fs::read_to_string(&paths[1]).unwrap();
+    for path in &paths {
+        std::fs::remove_file(path).unwrap();
+    }
+    assert!(a.starts_with("P3\n8 4\n255\n"));
+    assert_ne!(a, b);
+
+    assert!(render_views(&world, &cameras, &paths[..1]).is_err());
+}
+
+#[test]
+fn camera_can_be_moved_and_retargeted_in_place() {
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(0.0, 0.0, -1.0),
+        Vector::new(0.0, 1.0, 0.0),
+    )
+    .unwrap();
+    let image = ImageOptions::new(5, 5).unwrap();
+    let projection = PerspectiveProjection::new(30.0).unwrap();
+    let model = CameraModel::pinhole(1.0).unwrap();
+    let mut camera = Camera::new(CameraConfig::new(pose, image, projection, model));
+
+    let target = Point::new(3.0, 1.0, 2.0);
+    let mut world = HittableList::new();
+    world.add(Sphere::new(
+        target,
+        0.1,
+        DiffuseLight::new(Color::new(7.0, 7.0, 7.0)),
+    ));
+    let center_red = |camera: &Camera| camera.render_in_memory(&world)[12].r();
+
+    assert_ne!(center_red(&camera), 7.0);
+
+    camera.move_to(Point::new(0.0, 1.0, 8.0)).unwrap();
+    camera.look_at(target).unwrap();
+    assert_eq!(center_red(&camera), 7.0);
+
+    assert!(camera.look_at(Point::new(0.0, 1.0, 8.0)).is_err());
+    assert!(camera.move_to(Point::new(f64::NAN, 0.0, 0.0)).is_err());
+    assert_eq!(center_red(&camera), 7.0);
+}
+
+#[test]
+fn orbiting_keeps_distance_and_target() {
+    let mut camera = test_camera(Point::new(0.0, 1.0, 5.0), Point::new(0.0, 1.0, 0.0));
+    let near = |p: Point, x: f64, y: f64, z: f64| (p - Point::new(x, y, z)).len() < 1e-9;
+
+    camera.orbit_step(90.0).unwrap();
+    assert!(near(camera.position(), 5.0, 1.0, 0.0));
+    assert!(near(camera.target(), 0.0, 1.0, 0.0));
+
+    camera.orbit_tilt(30.0).unwrap();
+    let (sin, cos) = 30f64.to_radians().sin_cos();
+    assert!(near(camera.position(), 5.0 * cos, 1.0 + 5.0 * sin, 0.0));
+
+    assert!(camera.orbit_tilt(60.0).is_err());
+    assert!(near(camera.position(), 5.0 * cos, 1.0 + 5.0 * sin, 0.0));
+}
+
+#[test]
+fn stereo_halves_show_parallax() {
+    let camera = test_camera(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, -1.0));
+    let mut world = HittableList::new();
+    world.add(Sphere::new(
+        Point::new(0.0, 0.0, -1.5),
+        0.3,
+        DiffuseLight::new(Color::new(3.0, 3.0, 3.0)),
+    ));
+
+    let pair = camera.render_stereo_in_memory(&world, 0.4);
+    let width = pair.width() / 2;
+    assert_eq!(
+        pair.height(),
+        camera.render_in_memory(&world).len() as u32 / width
+    );
+
+    let lit = |x_range: std::ops::Range<u32>| {
+        x_range
+            .map(|x| {
+                (0..pair.height())
+                    .filter(|&y| pair.get(x, y).unwrap().r() == 3.0)
+                    .count()
+            })
+            .collect::<Vec<_>>()
+    };
+    let (left, right) = (lit(0..width), lit(width..2 * width));
+    assert_ne!(left, right);
+
+    // The sphere sits right of center for the left eye, and left of center for the right eye.
+    let centroid = |columns: &[usize]| {
+        let total: usize = columns.iter().sum();
+        columns
+            .iter()
+            .enumerate()
+            .map(|(x, n)| x * n)
+            .sum::<usize>() as f64
+            / total as f64
+    };
+    let middle = (width - 1) as f64 / 2.0;
+    assert!(centroid(&left) > middle);
+    assert!(centroid(&right) < middle);
+
+    let path = common::temp_path("stereo.ppm");
+    camera.render_stereo(&world, 0.4, &path).unwrap();
+    let header = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(header.starts_with(&format!("P3\n{} {}\n", 2 * width, pair.height())));
+
+    // The pair is held to the camera's own pixel cap
+    let mut capped = camera.clone();
+    capped.set_image_options(ImageOptions::with_max_pixels(8, 4, 48).unwrap());
+    let error = capped.render_stereo(&world, 0.4, &path);
+    assert!(
+        matches!(error, Err(RenderError::ImageTooLarge)),
+        "{error:?}"
+    );
+    assert!(!path.exists());
+    capped.set_image_options(ImageOptions::with_max_pixels(8, 4, 64).unwrap());
+    capped.render_stereo(&world, 0.4, &path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn framing_a_scene_centers_it_in_view() {
+    let camera = test_camera(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, -1.0));
+    let up = Vector::new(0.0, 1.0, 0.0);
+    let mut world = HittableList::new();
+    world
+        .add(Sphere::new(
+            Point::new(5.0, 2.0, -3.0),
+            1.0,
+            DiffuseLight::new(Color::new(1.0, 1.0, 1.0)),
+        ))
+        .add(Plane::new(
+            Vector::new(0.0, 1.0, 0.0).unit(),
+            10.0,
+            Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+        ));
+
+    let framed = camera.frame_scene(&world, up).unwrap();
+    let target = framed.target();
+    assert_eq!((target.x(), target.y(), target.z()), (5.0, 2.0, -3.0));
+
+    // Still looking down -z, backed off so the box's bounding sphere (radius
+    // sqrt(3)) fits the vertical field of view of 60 degrees
+    let offset = framed.position() - target;
+    assert_vec_eq!(offset, Vector::new(0.0, 0.0, 2.0 * 3f64.sqrt()));
+
+    let position = framed.position();
+    let center_ray = Ray::new(&position, (target - position).unit());
+    assert!(
+        world
+            .hit(Interval::new(0.001, f64::MAX), &center_ray)
+            .is_some()
+    );
+    let pixels = framed.render_in_memory(&world);
+    assert_eq!(pixels[(2 * 8 + 4) as usize].r(), 1.0);
+
+    assert_eq!(
+        camera.frame_scene(&HittableList::new(), up).unwrap_err(),
+        ConfigError::UnboundedScene
+    );
+}
+
+#[test]
+fn a_quarter_roll_turns_the_scene_on_its_side() {
+    let pose = CameraPose::look_at(
+        Point::origin(),
+        Point::new(0.0, 0.0, -1.0),
+        Vector::new(0.0, 1.0, 0.0),
+    )
+    .unwrap();
+    let image = ImageOptions::new(9, 9).unwrap();
+    let projection = PerspectiveProjection::new(60.0).unwrap();
+    let model = CameraModel::pinhole(1.0).unwrap();
+    let mut camera = Camera::new(CameraConfig::new(pose, image, projection, model));
+    let mut world = HittableList::new();
+    world.add(Sphere::new(
+        Point::new(1.0, 0.0, -3.0),
+        0.4,
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    ));
+
+    // (column, row) of every pixel covering the sphere
+    let covered = |camera: &Camera| {
+        let options = RenderOptions::new().transparent_background(true);
+        camera
+            .render_rgba_in_memory(&world, &options)
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, alpha))| *alpha == 1.0)
+            .map(|(i, _)| (i % 9, i / 9))
+            .collect::<Vec<_>>()
+    };
+
+    let level = covered(&camera);
+    assert!(!level.is_empty());
+    assert!(level.iter().all(|&(x, y)| x > 4 && y == 4));
+
+    // Tilting the camera counter-clockwise swings the sphere from the right
+    // of the frame to the bottom
+    camera.set_roll(90.0);
+    assert_eq!(camera.roll(), 90.0);
+    let rolled = covered(&camera);
+    assert_eq!(rolled.len(), level.len());
+    assert!(rolled.iter().all(|&(x, y)| x == 4 && y > 4));
+
+    camera.set_roll(0.0);
+    assert_eq!(covered(&camera), level);
+}
@@ -0,0 +1,29 @@
+use ray_tracing_rs::Point;
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::Lambertian;
+use ray_tracing_rs::objects::{ClosedSolid, Scale, Sphere};
+
+fn unit_sphere() -> Sphere {
+    Sphere::new(
+        Point::new(1.0, 2.0, 3.0),
+        1.0,
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    )
+}
+
+#[test]
+fn sphere_contains_points_within_its_radius() {
+    let sphere = unit_sphere();
+
+    assert!(sphere.contains_point(&Point::new(1.5, 2.0, 3.0)));
+    assert!(!sphere.contains_point(&Point::new(2.5, 2.0, 3.0)));
+}
+
+#[test]
+fn scaled_sphere_contains_scaled_points() {
+    let scaled = Scale::new(unit_sphere(), 2.0);
+
+    assert!(scaled.contains_point(&Point::new(2.0, 4.0, 6.0)));
+    assert!(scaled.contains_point(&Point::new(3.5, 4.0, 6.0)));
+    assert!(!scaled.contains_point(&Point::new(1.0, 2.0, 3.0)));
+}
@@ -0,0 +1,127 @@
+use ray_tracing_rs::assert_color_eq;
+use ray_tracing_rs::color::{Color, ColorSum, GamutMapping, VISIBLE_WAVELENGTHS};
+
+#[test]
+fn srgb_converts_to_linear() {
+    let gray = Color::srgb(0.5, 0.5, 0.5);
+    assert!((gray.r() - 0.214).abs() < 1e-3);
+    assert!((gray.g() - 0.214).abs() < 1e-3);
+    assert!((gray.b() - 0.214).abs() < 1e-3);
+
+    let black = Color::srgb(0.0, 0.0, 0.0);
+    let white = Color::srgb(1.0, 1.0, 1.0);
+    assert_eq!(black.r(), 0.0);
+    assert!((white.r() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn wavelength_weights_average_to_white() {
+    let (min, max) = VISIBLE_WAVELENGTHS;
+    let steps = 400;
+    let mut sum = Color::new(0.0, 0.0, 0.0);
+    for k in 0..steps {
+        sum += Color::from_wavelength(min + (k as f64 + 0.5) * (max - min) / steps as f64);
+    }
+    let mean = sum / steps as f64;
+
+    for channel in [mean.r(), mean.g(), mean.b()] {
+        assert!((channel - 1.0).abs() < 1e-2);
+    }
+    let red = Color::from_wavelength(650.0);
+    assert!(red.r() > red.b());
+}
+
+#[test]
+fn kelvin_spans_orange_to_neutral() {
+    let daylight = Color::from_kelvin(6500.0);
+    for channel in [daylight.r(), daylight.g(), daylight.b()] {
+        assert!(channel > 0.9, "{daylight}");
+    }
+
+    let candle = Color::from_kelvin(2000.0);
+    assert_eq!(candle.r(), 1.0);
+    assert!(candle.g() < 0.6 * candle.r());
+    assert!(candle.b() < 0.2 * candle.g());
+
+    let warm = Color::from_kelvin(3000.0);
+    assert!(warm.b() > candle.b() && warm.b() < daylight.b());
+}
+
+#[test]
+fn compensated_sums_stay_accurate_over_many_samples() {
+    let sample = Color::new(0.1, 1e-3, 0.7);
+    let n = 1_000_000;
+
+    let mut naive = Color::new(0.0, 0.0, 0.0);
+    let mut compensated = ColorSum::new();
+    for _ in 0..n {
+        naive += sample;
+        compensated.add(sample);
+    }
+
+    let exact = 0.1 * n as f64;
+    assert!((naive.r() - exact).abs() > 1e-7);
+    assert_color_eq!(
+        compensated.total(),
+        Color::new(exact, 1e-3 * n as f64, 0.7 * n as f64)
+    );
+}
+
+#[test]
+fn compensated_sums_stay_infinite_after_an_infinite_sample() {
+    let mut sum = ColorSum::new();
+    sum.add(Color::new(0.5, 0.5, 0.5));
+    sum.add(Color::new(f64::INFINITY, 0.25, 0.25));
+    sum.add(Color::new(0.5, 0.5, 0.5));
+
+    let total = sum.total();
+    assert_eq!(total.r(), f64::INFINITY);
+    assert_color_eq!(
+        Color::new(0.0, total.g(), total.b()),
+        Color::new(0.0, 1.25, 1.25)
+    );
+}
+
+#[test]
+fn hue_preserving_gamut_mapping_keeps_orange_orange() {
+    // Hue angle in degrees, for colors whose red channel is the largest
+    let hue = |c: Color| {
+        let min = c.g().min(c.b());
+        60.0 * (c.g() - c.b()) / (c.r() - min)
+    };
+    let orange = Color::new(2.0, 0.5, 0.0);
+
+    let clipped = orange.gamut_mapped(GamutMapping::Clip);
+    assert_eq!((clipped.r(), clipped.g(), clipped.b()), (1.0, 0.5, 0.0));
+    assert!((hue(clipped) - 30.0).abs() < 1e-9);
+
+    let mapped = orange.gamut_mapped(GamutMapping::PreserveHue);
+    for channel in [mapped.r(), mapped.g(), mapped.b()] {
+        assert!((0.0..=1.0).contains(&channel));
+    }
+    assert!((mapped.r() - 1.0).abs() < 1e-12);
+    assert!((hue(mapped) - hue(orange)).abs() < 1e-9);
+    assert!((mapped.luminance() - orange.luminance()).abs() < 1e-12);
+
+    // In-gamut colors are untouched, and the byte path uses the mapping
+    let inside = Color::new(0.25, 0.5, 0.75);
+    assert_eq!(
+        inside.to_rgb8_mapped(GamutMapping::PreserveHue),
+        inside.to_rgb8()
+    );
+    assert_ne!(
+        orange.to_rgb8_mapped(GamutMapping::PreserveHue),
+        orange.to_rgb8()
+    );
+}
+
+#[test]
+fn colors_subtract_and_negate_per_channel() {
+    let white = Color::new(1.0, 1.0, 1.0);
+    let red = Color::new(1.0, 0.0, 0.0);
+
+    assert_color_eq!(white - red, Color::new(0.0, 1.0, 1.0));
+    assert_color_eq!(white - 0.25, Color::new(0.75, 0.75, 0.75));
+    assert_color_eq!(-red, Color::new(-1.0, 0.0, 0.0));
+    assert_color_eq!(red + -red, Color::new(0.0, 0.0, 0.0));
+}
@@ -0,0 +1,35 @@
+//! Fixtures shared by the integration tests.
+
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{
+    Camera, CameraConfig, CameraModel, CameraPose, ImageOptions, PerspectiveProjection, Point,
+};
+
+/// A pinhole camera at the origin looking down -z with +y up and a 60 degree
+/// field of view.
+pub fn forward_camera(image: ImageOptions) -> Camera {
+    let pose = CameraPose::look_at(
+        Point::origin(),
+        Point::new(0.0, 0.0, -1.0),
+        Vector::new(0.0, 1.0, 0.0),
+    )
+    .unwrap();
+    pinhole_camera(pose, image, 60.0)
+}
+
+/// A pinhole camera at `pose` with a vertical field of view of `fov` degrees.
+pub fn pinhole_camera(pose: CameraPose, image: ImageOptions, fov: f64) -> Camera {
+    let projection = PerspectiveProjection::new(fov).unwrap();
+    let model = CameraModel::pinhole(1.0).unwrap();
+    Camera::new(CameraConfig::new(pose, image, projection, model))
+}
+
+/// A path in the system temp directory named after `name` and this test
+/// process, so concurrent test runs never share files.
+pub fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("ray-tracing-rs-{}-{name}", std::process::id()))
+}
@@ -0,0 +1,113 @@
+use std::sync::Mutex;
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::{Lambertian, Metal};
+use ray_tracing_rs::objects::{Plane, Sphere};
+use ray_tracing_rs::scene::RenderOptions;
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{Camera, CameraPose, HittableList, ImageOptions, Point, assert_color_eq};
+
+mod common;
+
+/// Collects every message logged at debug level or above.
+struct Capture(Mutex<Vec<String>>);
+
+impl Log for Capture {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Debug
+    }
+
+    fn log(&self, record: &Record) {
+        self.0.lock().unwrap().push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: Capture = Capture(Mutex::new(Vec::new()));
+
+/// A camera looking down at a ball on the ground, so every pixel sees diffuse
+/// surfaces.
+fn ball_on_ground() -> (Camera, HittableList) {
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(0.0, -1.0, -1.0),
+        Vector::new(0.0, 1.0, 0.0),
+    )
+    .unwrap();
+    let camera = common::pinhole_camera(pose, ImageOptions::new(4, 3).unwrap().antialias(8), 60.0);
+
+    let mut world = HittableList::new();
+    world
+        .add(Sphere::new(
+            Point::new(0.0, 0.0, -1.0),
+            0.5,
+            Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+        ))
+        .add(Sphere::new(
+            Point::new(0.0, -100.5, -1.0),
+            100.0,
+            Lambertian::new(Color::new(0.8, 0.8, 0.0)),
+        ));
+    (camera, world)
+}
+
+#[test]
+fn debug_pixel_matches_seeded_render() {
+    let (camera, world) = ball_on_ground();
+    let options = RenderOptions::new().seed(7);
+
+    let render = camera.render_in_memory_with_options(&world, &options);
+    let pixel = camera.debug_pixel_with_options(&world, 2, 1, &options);
+
+    assert_color_eq!(pixel, render[4 + 2]);
+}
+
+#[test]
+fn log_bounces_reports_each_hit() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Debug);
+
+    let (camera, world) = ball_on_ground();
+    let options = RenderOptions::new().log_bounces(true);
+    camera.debug_pixel_with_options(&world, 2, 2, &options);
+
+    let messages = LOGGER.0.lock().unwrap();
+    assert!(messages.iter().any(|m| m == "pixel (2, 2), sample 1/8"));
+    assert!(
+        messages
+            .iter()
+            .any(|m| m.contains(": hit ") && m.contains("Lambertian") && m.contains("attenuation")),
+        "{messages:?}"
+    );
+    assert!(messages.iter().any(|m| m.contains("escaped to sky")));
+}
+
+#[test]
+fn trace_paths_records_a_mirror_bounce_and_what_it_reflects() {
+    let camera = common::forward_camera(ImageOptions::new(3, 3).unwrap().antialias(0));
+
+    // A mirror ahead reflects the center ray back onto a ball behind the camera
+    let mut world = HittableList::new();
+    world
+        .add(Plane::new(
+            Vector::new(0.0, 0.0, 1.0).unit(),
+            2.0,
+            Metal::new(Color::new(0.9, 0.9, 0.9), 0.0),
+        ))
+        .add(Sphere::new(
+            Point::new(0.0, 0.0, 2.0),
+            0.5,
+            Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+        ));
+
+    let paths = camera.trace_paths(&world, &[(1, 1), (0, 0)], 3);
+
+    assert_eq!(paths.len(), 6);
+    for path in &paths[..3] {
+        assert!(path.len() >= 2, "{path:?}");
+        assert!((path[0].z() + 2.0).abs() < 1e-9);
+        assert!((path[1].z() - 1.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,71 @@
+use ray_tracing_rs::materials::{Dielectric, RayInteraction};
+use ray_tracing_rs::objects::{Hittable, Plane};
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::vector::{UtVector, Vector};
+use ray_tracing_rs::{HittableList, Interval, Point};
+
+/// A glass wedge: light enters through `z = 0` and leaves through a face
+/// tilted 30 degrees about the y-axis.
+fn prism() -> HittableList {
+    let (sin, cos) = 30f64.to_radians().sin_cos();
+    let exit_normal = Vector::new(sin, 0.0, -cos);
+    let exit_point = Vector::new(0.0, 0.0, -1.0);
+
+    let mut world = HittableList::new();
+    world
+        .add(Plane::new(
+            Vector::new(0.0, 0.0, 1.0).unit(),
+            0.0,
+            Dielectric::cauchy(1.5046, 0.0042),
+        ))
+        .add(Plane::new(
+            exit_normal.unit(),
+            -exit_normal.dot(&exit_point),
+            Dielectric::cauchy(1.5046, 0.0042),
+        ));
+    world
+}
+
+/// Follow a ray of `wavelength` through the prism, retrying whenever glass
+/// randomly reflects instead of transmitting.
+fn exit_direction(world: &HittableList, wavelength: f64) -> UtVector {
+    let mut origin = Point::new(0.0, 0.0, 1.0);
+    let mut dir = Vector::new(0.0, 0.0, -1.0).unit();
+
+    for _ in 0..2 {
+        let ray = Ray::new(&origin, dir).with_wavelength(wavelength);
+        let record = world.hit(Interval::new(0.001, f64::MAX), &ray).unwrap();
+        let transmitted = (0..1000)
+            .find_map(|_| match record.material().interact(&ray, &record) {
+                RayInteraction::Scattered(emergent) if emergent.ray().dir().dot(&dir) > 0.0 => {
+                    Some(*emergent.ray().dir())
+                }
+                _ => None,
+            })
+            .unwrap();
+        origin = record.point();
+        dir = transmitted;
+    }
+    dir
+}
+
+#[test]
+fn prism_bends_blue_more_than_red() {
+    let world = prism();
+    let straight = Vector::new(0.0, 0.0, -1.0);
+
+    let blue = exit_direction(&world, 450.0);
+    let red = exit_direction(&world, 650.0);
+
+    let deviation = |dir: UtVector| dir.dot(&straight).acos();
+    assert!(deviation(blue) > deviation(red) + 1e-3);
+}
+
+#[test]
+fn cauchy_ior_falls_with_wavelength() {
+    let glass = Dielectric::cauchy(1.5046, 0.0042);
+
+    assert!(glass.ior_at(Some(450.0)) > glass.ior_at(Some(650.0)));
+    assert_eq!(glass.ior_at(None), glass.ior_at(Some(587.6)));
+    assert_eq!(Dielectric::new(1.5).ior_at(Some(450.0)), 1.5);
+}
@@ -0,0 +1,187 @@
+use std::sync::Arc;
+
+use ray_tracing_rs::background::{Background, EnvironmentMap};
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::image::Image;
+use ray_tracing_rs::materials::{DiffuseLight, Lambertian};
+use ray_tracing_rs::objects::{Hittable, Plane, Sphere, Triangle};
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::scene::{DirectLighting, ParallelOptions, RenderOptions};
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{CameraPose, HittableList, ImageOptions, Point, seed_thread_rng};
+
+mod common;
+
+#[test]
+fn animated_light_changes_with_ray_time() {
+    let mut world = HittableList::new();
+    let light = DiffuseLight::animated(|t| Color::new(t, 2.0 * t, 0.5));
+    world.add(Sphere::new(Point::new(0.0, 0.0, -2.0), 0.5, light));
+
+    let origin = Point::new(0.0, 0.0, 0.0);
+    let dir = Vector::new(0.0, 0.0, -1.0).unit();
+
+    let at_start = Ray::with_time(&origin, dir, 0.0).color(&world, 4);
+    let at_end = Ray::with_time(&origin, dir, 1.0).color(&world, 4);
+
+    assert_eq!(at_start.r(), 0.0);
+    assert_eq!(at_end.r(), 1.0);
+    assert_eq!(at_end.g(), 2.0);
+    assert_eq!(at_start.b(), at_end.b());
+}
+
+#[test]
+fn invalid_colors_are_flagged_magenta() {
+    let camera = common::forward_camera(ImageOptions::new(4, 4).unwrap());
+
+    // A light filling the view that emits NaN.
+    let mut world = HittableList::new();
+    world.add(Plane::new(
+        Vector::new(0.0, 0.0, 1.0).unit(),
+        1.0,
+        DiffuseLight::new(Color::new(f64::NAN, 0.5, 0.5)),
+    ));
+
+    let flagged =
+        camera.render_in_memory_with_options(&world, &RenderOptions::new().flag_invalid(true));
+    let unflagged = camera.render_in_memory(&world);
+
+    for pixel in &flagged {
+        assert_eq!((pixel.r(), pixel.g(), pixel.b()), (1.0, 0.0, 1.0));
+    }
+    assert!(unflagged[0].r().is_nan());
+}
+
+#[test]
+fn clamping_samples_tames_fireflies() {
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 1.0, 0.0),
+        Point::new(0.0, 0.0, 0.0),
+        Vector::new(0.0, 0.0, -1.0),
+    )
+    .unwrap();
+    let camera =
+        common::pinhole_camera(pose, ImageOptions::new(16, 16).unwrap().antialias(2), 60.0);
+
+    // A small, very bright light out of view, only found by rare diffuse bounces.
+    let mut world = HittableList::new();
+    world
+        .add(Plane::new(
+            Vector::new(0.0, 1.0, 0.0).unit(),
+            0.0,
+            Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+        ))
+        .add(Sphere::new(
+            Point::new(3.0, 2.0, 0.0),
+            0.5,
+            DiffuseLight::new(Color::new(200.0, 200.0, 200.0)),
+        ));
+
+    let stats = |pixels: Vec<Color>| {
+        let reds: Vec<f64> = pixels.iter().map(Color::r).collect();
+        let mean = reds.iter().sum::<f64>() / reds.len() as f64;
+        let variance = reds.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / reds.len() as f64;
+        (reds.iter().copied().fold(0.0, f64::max), variance)
+    };
+    // Both renders draw the same samples, so only the clamp differs
+    let render = |options: RenderOptions| {
+        seed_thread_rng(17);
+        camera.render_in_memory_with_options(&world, &options.parallel(ParallelOptions::Series))
+    };
+    let (raw_max, raw_variance) = stats(render(RenderOptions::new()));
+    let (clamped_max, clamped_variance) = stats(render(RenderOptions::new().clamp_indirect(1.0)));
+
+    assert!(raw_max > 10.0);
+    assert!(clamped_max <= 1.0);
+    assert!(clamped_variance < raw_variance);
+}
+
+#[test]
+fn ambient_light_lifts_shadows_more_than_lit_areas() {
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 1.0, 0.0),
+        Point::new(0.0, 0.0, 0.0),
+        Vector::new(0.0, 0.0, -1.0),
+    )
+    .unwrap();
+    let camera = common::pinhole_camera(pose, ImageOptions::new(8, 8).unwrap().antialias(16), 30.0);
+
+    // The same floor under open sky, and shadowed by a ceiling above the camera
+    let gray = Lambertian::new(Color::new(0.5, 0.5, 0.5));
+    let mut open = HittableList::new();
+    open.add(Plane::new(
+        Vector::new(0.0, 1.0, 0.0).unit(),
+        0.0,
+        gray.clone(),
+    ));
+    let mut covered = open.clone();
+    covered.add(Plane::new(Vector::new(0.0, -1.0, 0.0).unit(), 2.0, gray));
+
+    let mean = |world: &HittableList, options: &RenderOptions| {
+        let pixels = camera.render_in_memory_with_options(world, options);
+        pixels.iter().map(Color::r).sum::<f64>() / pixels.len() as f64
+    };
+    let (plain, ambient) = (
+        RenderOptions::new(),
+        RenderOptions::new().ambient(Color::new(0.05, 0.05, 0.05)),
+    );
+
+    let (lit, lit_ambient) = (mean(&open, &plain), mean(&open, &ambient));
+    let (shadowed, shadowed_ambient) = (mean(&covered, &plain), mean(&covered, &ambient));
+
+    assert!(shadowed < 0.01);
+    assert!(shadowed_ambient > shadowed + 0.03);
+    assert!(lit_ambient > lit);
+    assert!((lit_ambient - lit) / lit < 0.2);
+}
+
+#[test]
+fn registered_quad_light_reaches_every_point_of_a_wall() {
+    // A small quad light above the view, shining on a wall under a black sky
+    let light = DiffuseLight::new(Color::new(20.0, 20.0, 20.0));
+    let corners =
+        [(-0.1, -2.1), (0.1, -2.1), (0.1, -1.9), (-0.1, -1.9)].map(|(x, z)| Point::new(x, 1.5, z));
+    let halves: [Arc<dyn Hittable>; 2] = [
+        Arc::new(Triangle::new(
+            corners[0],
+            corners[1],
+            corners[2],
+            light.clone(),
+        )),
+        Arc::new(Triangle::new(corners[0], corners[2], corners[3], light)),
+    ];
+    let mut world = HittableList::new();
+    world.add(Plane::new(
+        Vector::new(0.0, 0.0, 1.0).unit(),
+        3.0,
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    ));
+    for half in &halves {
+        world.add_shared(Arc::clone(half));
+    }
+
+    let camera = common::forward_camera(ImageOptions::new(8, 8).unwrap().antialias(0));
+
+    let sky = Background::Environment(Arc::new(EnvironmentMap::new(Image::new(2, 1)).unwrap()));
+    let base = RenderOptions::new()
+        .parallel(ParallelOptions::Series)
+        .background(sky)
+        .direct_lighting(DirectLighting::Mis);
+    let lit_pixels = |options: &RenderOptions| {
+        seed_thread_rng(3);
+        camera
+            .render_in_memory_with_options(&world, options)
+            .iter()
+            .filter(|pixel| pixel.r() > 1e-6)
+            .count()
+    };
+
+    // With one sample per pixel, bounces off the wall rarely find the light
+    // by chance, but sampling it directly lights every pixel
+    let registered = base
+        .clone()
+        .add_light(Arc::clone(&halves[0]))
+        .add_light(Arc::clone(&halves[1]));
+    assert!(lit_pixels(&base) < 8);
+    assert_eq!(lit_pixels(&registered), 64);
+}
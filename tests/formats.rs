@@ -0,0 +1,72 @@
+use std::fs;
+use std::io;
+
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::Lambertian;
+use ray_tracing_rs::objects::Sphere;
+use ray_tracing_rs::{Camera, HittableList, ImageOptions, Point, RenderError};
+
+mod common;
+
+/// A noisy scene, so two separate renders would not match.
+fn scene() -> (Camera, HittableList) {
+    let camera = common::forward_camera(ImageOptions::new(8, 6).unwrap().antialias(2));
+
+    let mut world = HittableList::new();
+    world.add(Sphere::new(
+        Point::new(0.0, 0.0, -2.0),
+        1.0,
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    ));
+    (camera, world)
+}
+
+#[test]
+fn render_multi_writes_every_path_from_one_render() {
+    let (camera, world) = scene();
+    let (a, b) = (
+        common::temp_path("multi-a.ppm"),
+        common::temp_path("multi-b.PPM"),
+    );
+
+    camera.render_multi(&world, &[&a, &b]).unwrap();
+    let (first, second) = (fs::read(&a).unwrap(), fs::read(&b).unwrap());
+    fs::remove_file(&a).unwrap();
+    fs::remove_file(&b).unwrap();
+
+    assert!(first.starts_with(b"P3\n8 6\n255\n"));
+    assert_eq!(first, second);
+}
+
+#[test]
+fn unknown_extensions_are_unsupported() {
+    let (camera, world) = scene();
+    let path = common::temp_path("multi.bmp");
+
+    let error = camera.render_multi(&world, &[path.as_path()]).unwrap_err();
+    let written = path.exists();
+    let _ = fs::remove_file(&path);
+
+    assert!(
+        matches!(&error, RenderError::Io(error) if error.kind() == io::ErrorKind::Unsupported),
+        "{error:?}"
+    );
+    assert!(!written);
+}
+
+#[cfg(feature = "png")]
+#[test]
+fn render_multi_writes_ppm_and_png() {
+    let (camera, world) = scene();
+    let (ppm, png) = (
+        common::temp_path("multi.ppm"),
+        common::temp_path("multi.png"),
+    );
+
+    camera.render_multi(&world, &[&ppm, &png]).unwrap();
+    let png_bytes = fs::read(&png).unwrap();
+    fs::remove_file(&ppm).unwrap();
+    fs::remove_file(&png).unwrap();
+
+    assert!(png_bytes.starts_with(b"\x89PNG\r\n\x1a\n"));
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "gltf")]
+
+use ray_tracing_rs::assert_color_eq;
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::objects::Hittable;
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::scene::gltf;
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{Interval, Point};
+
+#[test]
+fn loads_single_triangle_with_base_color() {
+    let world = gltf::load("tests/assets/triangle.gltf").unwrap();
+
+    let bbox = world.bounding_box().unwrap();
+    assert_eq!(bbox.min().x(), -1.0);
+    assert_eq!(bbox.max().y(), 1.0);
+    assert_eq!(bbox.min().z(), -2.0);
+
+    let origin = Point::new(0.0, 0.0, 0.0);
+    let ray = Ray::new(&origin, Vector::new(0.0, 0.0, -1.0).unit());
+    assert!(world.hit_p(Interval::new(0.001, f64::MAX), &ray));
+
+    // The triangle is a perfect mirror facing the camera, so the ray bounces
+    // straight back into the sky at the horizon, (0.75, 0.85098, 1.0).
+    let color = ray.color(&world, 2);
+    assert_color_eq!(color, Color::new(0.8 * 0.75, 0.4 * 0.85098, 0.2), 1e-6);
+}
@@ -0,0 +1,76 @@
+use ray_tracing_rs::assert_color_eq;
+use ray_tracing_rs::color::{Color, GamutMapping};
+use ray_tracing_rs::materials::DiffuseLight;
+use ray_tracing_rs::objects::Sphere;
+use ray_tracing_rs::scene::RenderOptions;
+use ray_tracing_rs::{Camera, HittableList, ImageOptions, Point};
+
+mod common;
+
+/// A camera whose whole view is filled by a light brighter than white.
+fn bright_scene() -> (Camera, HittableList) {
+    let camera = common::forward_camera(ImageOptions::new(4, 3).unwrap());
+
+    let mut world = HittableList::new();
+    let light = DiffuseLight::new(Color::new(4.0, 2.0, 0.5));
+    world.add(Sphere::new(Point::new(0.0, 0.0, -15.0), 10.0, light));
+    (camera, world)
+}
+
+#[test]
+fn f32_buffer_keeps_values_above_one() {
+    let (camera, world) = bright_scene();
+
+    let buffer = camera.render_to_f32(&world);
+
+    assert_eq!(buffer.len(), 4 * 3 * 3);
+    assert_eq!(&buffer[..3], &[4.0, 2.0, 0.5]);
+}
+
+#[test]
+#[cfg(feature = "exr")]
+fn f32_buffer_is_written_as_exr() {
+    use ray_tracing_rs::image::write_exr;
+
+    let (camera, world) = bright_scene();
+    let buffer = camera.render_to_f32(&world);
+    let path = common::temp_path("hdr.exr");
+
+    write_exr(&path, &buffer, 4, 3).unwrap();
+    assert!(write_exr(&path, &buffer, 4, 4).is_err());
+
+    let bytes = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(&bytes[..4], &[0x76, 0x2f, 0x31, 0x01]);
+}
+
+#[test]
+fn gamut_mapping_applies_only_to_written_bytes() {
+    let camera = common::forward_camera(ImageOptions::new(4, 3).unwrap());
+    let orange = Color::new(1.6, 0.3, 0.05);
+    let mut world = HittableList::new();
+    world.add(Sphere::new(
+        Point::new(0.0, 0.0, -15.0),
+        14.0,
+        DiffuseLight::new(orange),
+    ));
+    let options = RenderOptions::new().gamut_mapping(GamutMapping::PreserveHue);
+
+    // Rendered pixels and sample passes keep the radiance above 1.0
+    let pixels = camera.render_in_memory_with_options(&world, &options);
+    assert_color_eq!(pixels[0], orange);
+    let pass = camera.render_sample_pass(&world, &options);
+    assert_color_eq!(pass.pixels()[0], orange);
+
+    let path = common::temp_path("gamut.ppm");
+    camera.render_with_options(&world, &path, &options).unwrap();
+    let written = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let [r, g, b] = orange.to_rgb8_mapped(GamutMapping::PreserveHue);
+    assert_ne!([r, g, b], orange.to_rgb8());
+    assert_eq!(
+        written.lines().nth(3),
+        Some(format!("{r} {g} {b}").as_str())
+    );
+}
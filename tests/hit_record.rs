@@ -0,0 +1,29 @@
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::Lambertian;
+use ray_tracing_rs::objects::{Hittable, Sphere};
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{Interval, Point};
+
+#[test]
+fn tangent_frame_is_orthonormal_around_the_normal() {
+    let sphere = Sphere::new(
+        Point::new(0.0, 0.0, -3.0),
+        1.0,
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    );
+    let origin = Point::new(0.3, 0.4, 0.0);
+    let ray = Ray::new(&origin, Vector::new(0.0, 0.0, -1.0).unit());
+    let record = sphere.hit(Interval::new(0.001, f64::MAX), &ray).unwrap();
+
+    let frame = record.tangent_frame();
+    let (u, v, w) = (frame.u(), frame.v(), frame.w());
+
+    assert!((w.relax() - record.normal().relax()).len() < 1e-12);
+    for axis in [u, v, w] {
+        assert!((axis.len() - 1.0).abs() < 1e-12);
+    }
+    assert!(u.dot(&v).abs() < 1e-12);
+    assert!(v.dot(&w).abs() < 1e-12);
+    assert!(w.dot(&u).abs() < 1e-12);
+}
@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::Lambertian;
+use ray_tracing_rs::objects::{HitRecord, Hittable, Plane, Sphere};
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{HittableList, Interval, Point};
+
+#[test]
+fn hit_all_reports_entry_and_exit_of_every_sphere_in_order() {
+    let material = Lambertian::new(Color::new(0.5, 0.5, 0.5));
+    let mut world = HittableList::new();
+    // Added out of order to check the result is sorted
+    for z in [-6.0, -2.0, -4.0] {
+        world.add(Sphere::new(Point::new(0.0, 0.0, z), 0.5, material.clone()));
+    }
+    let origin = Point::origin();
+    let ray = Ray::new(&origin, Vector::new(0.0, 0.0, -1.0).unit());
+
+    let hits = world.hit_all(Interval::new(0.001, f64::MAX), &ray);
+
+    let ts: Vec<f64> = hits.iter().map(|rec| rec.t()).collect();
+    let expected = [1.5, 2.5, 3.5, 4.5, 5.5, 6.5];
+    assert_eq!(ts.len(), expected.len());
+    for (t, expected) in ts.iter().zip(expected) {
+        assert!((t - expected).abs() < 1e-9);
+    }
+    let front_faces: Vec<bool> = hits.iter().map(|rec| rec.front_face()).collect();
+    assert_eq!(front_faces, [true, false, true, false, true, false]);
+
+    let nearest = world.hit(Interval::new(0.001, f64::MAX), &ray).unwrap();
+    assert_eq!(nearest.t(), hits[0].t());
+}
+
+/// A sphere that ignores the start of the interval it is searched in.
+struct Stuck(Sphere);
+
+impl Hittable for Stuck {
+    fn hit(&self, ray_t: Interval, ray: &Ray) -> Option<HitRecord> {
+        self.0.hit(Interval::new(0.001, ray_t.max), ray)
+    }
+}
+
+#[test]
+fn hit_all_stops_on_objects_that_ignore_the_interval() {
+    let material = Lambertian::new(Color::new(0.5, 0.5, 0.5));
+    let mut world = HittableList::new();
+    world.add(Stuck(Sphere::new(
+        Point::new(0.0, 0.0, -2.0),
+        0.5,
+        material,
+    )));
+    let origin = Point::origin();
+    let ray = Ray::new(&origin, Vector::new(0.0, 0.0, -1.0).unit());
+
+    let hits = world.hit_all(Interval::new(0.001, f64::MAX), &ray);
+
+    assert_eq!(hits.len(), 1);
+    assert!((hits[0].t() - 1.5).abs() < 1e-9);
+}
+
+#[test]
+fn single_object_lists_hit_like_the_object() {
+    let sphere = Sphere::new(
+        Point::new(0.0, 0.0, -3.0),
+        1.0,
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    );
+    let mut world = HittableList::new();
+    world.add(Sphere::new(
+        Point::new(0.0, 0.0, -3.0),
+        1.0,
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    ));
+
+    let origin = Point::origin();
+    for i in 0..64 {
+        let dir = Vector::new(i as f64 / 64.0 - 0.5, 0.1, -1.0).unit();
+        let ray = Ray::new(&origin, dir);
+        for interval in [Interval::new(0.001, f64::MAX), Interval::new(0.001, 2.5)] {
+            let expected = sphere.hit(interval, &ray);
+            let actual = world.hit(interval, &ray);
+            assert_eq!(
+                actual.as_ref().map(|record| (record.t(), record.uv())),
+                expected.as_ref().map(|record| (record.t(), record.uv()))
+            );
+        }
+    }
+}
+
+#[test]
+fn bare_slices_of_objects_are_hittable() {
+    let material = Lambertian::new(Color::new(0.5, 0.5, 0.5));
+    let objects: Vec<Arc<dyn Hittable>> = vec![
+        Arc::new(Sphere::new(
+            Point::new(0.0, 0.0, -5.0),
+            1.0,
+            material.clone(),
+        )),
+        Arc::new(Sphere::new(Point::new(0.0, 0.0, -3.0), 1.0, material)),
+    ];
+    let origin = Point::origin();
+    let ray = Ray::new(&origin, Vector::new(0.0, 0.0, -1.0).unit());
+    let interval = Interval::new(0.001, f64::MAX);
+
+    let slice: &[Arc<dyn Hittable>] = &objects;
+    assert_eq!(slice.hit(interval, &ray).unwrap().t(), 2.0);
+    assert!(!slice.hit_p(Interval::new(0.001, 1.5), &ray));
+    let bbox = slice.bounding_box().unwrap();
+    assert_eq!((bbox.min().z(), bbox.max().z()), (-6.0, -2.0));
+
+    // Vecs and slice references can stand in for a world
+    let worlds: [&dyn Hittable; 2] = [&objects, &slice];
+    for world in worlds {
+        assert_eq!(world.hit(interval, &ray).unwrap().t(), 2.0);
+    }
+}
+
+#[test]
+fn tuples_and_options_hit_their_nearest_surface() {
+    let material = Lambertian::new(Color::new(0.5, 0.5, 0.5));
+    let sphere = Sphere::new(Point::new(0.0, 0.0, -2.0), 0.5, material.clone());
+    let floor = Plane::new(Vector::new(0.0, 1.0, 0.0).unit(), 1.0, material.clone());
+    let scene = (sphere, floor);
+    let ray_t = Interval::new(0.001, f64::MAX);
+    let origin = Point::origin();
+
+    // Straight ahead the sphere is in front of the floor
+    let ahead = Ray::new(&origin, Vector::new(0.0, 0.0, -1.0).unit());
+    assert!((scene.hit(ray_t, &ahead).unwrap().t() - 1.5).abs() < 1e-9);
+
+    // Looking down past the sphere only the floor is hit
+    let down = Ray::new(&origin, Vector::new(0.0, -1.0, -1.0).unit());
+    let floor_hit = scene.hit(ray_t, &down).unwrap();
+    assert!((floor_hit.point().y() + 1.0).abs() < 1e-9);
+    assert!(scene.bounding_box().is_none());
+
+    let nothing: Option<Sphere> = None;
+    let lone = Some(Sphere::new(Point::new(0.0, 0.0, -4.0), 0.5, material));
+    assert!(nothing.hit(ray_t, &ahead).is_none());
+    let triple = (nothing, lone, scene);
+    assert!((triple.hit(ray_t, &ahead).unwrap().t() - 1.5).abs() < 1e-9);
+}
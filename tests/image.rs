@@ -0,0 +1,90 @@
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::image::{BlendMode, Image};
+
+mod common;
+
+fn filled(width: u32, height: u32, color: Color) -> Image {
+    Image::from_pixels(width, height, vec![color; (width * height) as usize]).unwrap()
+}
+
+#[test]
+fn multiply_with_white_keeps_the_other_image() {
+    let white = filled(2, 2, Color::new(1.0, 1.0, 1.0));
+    let gray = filled(2, 2, Color::new(0.5, 0.5, 0.5));
+
+    let blended = white.blend(&gray, BlendMode::Multiply, 1.0);
+
+    for pixel in blended.pixels() {
+        assert_eq!(pixel.r(), 0.5);
+        assert_eq!(pixel.b(), 0.5);
+    }
+}
+
+#[test]
+fn blend_modes_and_factor() {
+    let base = filled(1, 1, Color::new(0.5, 0.2, 0.0));
+    let layer = filled(1, 1, Color::new(0.5, 0.4, 1.0));
+
+    let normal = base
+        .blend(&layer, BlendMode::Normal, 0.5)
+        .get(0, 0)
+        .unwrap();
+    let add = base.blend(&layer, BlendMode::Add, 1.0).get(0, 0).unwrap();
+    let screen = base
+        .blend(&layer, BlendMode::Screen, 1.0)
+        .get(0, 0)
+        .unwrap();
+    let none = base.blend(&layer, BlendMode::Add, 0.0).get(0, 0).unwrap();
+
+    assert!((normal.g() - 0.3).abs() < 1e-12);
+    assert_eq!(add.r(), 1.0);
+    assert!((screen.r() - 0.75).abs() < 1e-12);
+    assert_eq!(screen.b(), 1.0);
+    assert_eq!(none.g(), 0.2);
+}
+
+#[test]
+#[should_panic]
+fn blending_different_sizes_panics() {
+    let a = filled(2, 2, Color::new(0.0, 0.0, 0.0));
+    let b = filled(2, 1, Color::new(0.0, 0.0, 0.0));
+    a.blend(&b, BlendMode::Normal, 1.0);
+}
+
+#[test]
+fn raw_images_round_trip_exactly() {
+    let path = common::temp_path("raw-round-trip.raw");
+    let mut image = filled(3, 2, Color::new(0.1, 2.5, 1.0 / 3.0));
+    image.set(2, 1, Color::new(-1.0, 1e300, 0.0));
+
+    image.save_raw(&path, 17).unwrap();
+    let (loaded, samples) = Image::load_raw(&path).unwrap();
+
+    assert_eq!(samples, 17);
+    assert_eq!((loaded.width(), loaded.height()), (3, 2));
+    for (a, b) in loaded.pixels().iter().zip(image.pixels()) {
+        assert_eq!((a.r(), a.g(), a.b()), (b.r(), b.g(), b.b()));
+    }
+
+    std::fs::write(&path, b"P3\n1 1\n255\n0 0 0\n").unwrap();
+    let error = Image::load_raw(&path).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn rmse_and_psnr_measure_distance_from_a_reference() {
+    let reference = filled(4, 4, Color::new(0.5, 0.5, 0.5));
+    assert_eq!(reference.rmse(&reference), 0.0);
+    assert_eq!(reference.psnr(&reference), f64::INFINITY);
+
+    let mut perturbed = reference.clone();
+    perturbed.set(1, 2, Color::new(0.5, 0.62, 0.5));
+    let rmse = perturbed.rmse(&reference);
+    assert!((rmse - (0.12f64.powi(2) / 48.0).sqrt()).abs() < 1e-12);
+    assert!((perturbed.psnr(&reference) - -20.0 * rmse.log10()).abs() < 1e-12);
+
+    let noisier = filled(4, 4, Color::new(0.6, 0.4, 0.5));
+    assert!(noisier.rmse(&reference) > rmse);
+    assert!(noisier.psnr(&reference) < perturbed.psnr(&reference));
+}
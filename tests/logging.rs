@@ -0,0 +1,59 @@
+use std::sync::Mutex;
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use ray_tracing_rs::scene::{ParallelOptions, RenderOptions};
+use ray_tracing_rs::{HittableList, ImageOptions};
+
+mod common;
+
+/// Collects every message logged at info level or above.
+struct Capture(Mutex<Vec<String>>);
+
+impl Log for Capture {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Info
+    }
+
+    fn log(&self, record: &Record) {
+        self.0.lock().unwrap().push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: Capture = Capture(Mutex::new(Vec::new()));
+
+#[test]
+fn all_at_once_logs_progress_while_computing() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Info);
+
+    let camera = common::forward_camera(ImageOptions::new(6, 4).unwrap());
+    let options = RenderOptions::new().parallel(ParallelOptions::AllAtOnce);
+    let path = common::temp_path("logging.ppm");
+
+    camera
+        .render_with_options(&HittableList::new(), &path, &options)
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let messages = LOGGER.0.lock().unwrap();
+    let computed: Vec<_> = messages
+        .iter()
+        .filter(|m| m.starts_with("Scanlines computed"))
+        .collect();
+    let finished = messages
+        .iter()
+        .position(|m| m == "Finished calculations!")
+        .unwrap();
+
+    assert_eq!(computed.len(), 4);
+    assert_eq!(computed.last().unwrap().as_str(), "Scanlines computed: 4/4");
+    assert!(
+        messages
+            .iter()
+            .position(|m| m.starts_with("Scanlines computed"))
+            .unwrap()
+            < finished
+    );
+}
@@ -0,0 +1,26 @@
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::Lambertian;
+use ray_tracing_rs::objects::Sphere;
+use ray_tracing_rs::{HittableList, Point, hittable_list};
+
+#[test]
+fn hittable_list_holds_every_object_in_order() {
+    let material = Lambertian::new(Color::new(0.5, 0.5, 0.5));
+    let sphere = |x: f64| Sphere::new(Point::new(x, 0.0, -1.0), 0.5, material.clone());
+
+    let world = hittable_list![sphere(-1.0), sphere(0.0), sphere(1.0),];
+
+    assert_eq!(world.objects().len(), 3);
+    let centers: Vec<f64> = world
+        .objects()
+        .iter()
+        .map(|object| object.bounding_box().unwrap().min().x() + 0.5)
+        .collect();
+    assert_eq!(centers, [-1.0, 0.0, 1.0]);
+}
+
+#[test]
+fn empty_hittable_list_is_empty() {
+    let world: HittableList = hittable_list![];
+    assert!(world.objects().is_empty());
+}
@@ -0,0 +1,63 @@
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::Lambertian;
+use ray_tracing_rs::objects::{Hittable, Masked, Plane, RayTypeMask, Sphere};
+use ray_tracing_rs::ray::{Ray, RayType};
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{HittableList, Interval, Point};
+
+fn gray() -> Lambertian {
+    Lambertian::new(Color::new(0.5, 0.5, 0.5))
+}
+
+/// A floor with a sphere hovering above it, optionally visible to camera rays only.
+fn world(camera_only: bool) -> HittableList {
+    let sphere = Sphere::new(Point::new(0.0, 1.0, 0.0), 0.5, gray());
+    let mut world = HittableList::new();
+    world.add(Plane::new(Vector::new(0.0, 1.0, 0.0).unit(), 0.0, gray()));
+    if camera_only {
+        world.add(Masked::new(sphere, RayTypeMask::CAMERA));
+    } else {
+        world.add(sphere);
+    }
+    world
+}
+
+#[test]
+fn masks_filter_by_ray_type() {
+    let mask = RayTypeMask::CAMERA | RayTypeMask::REFLECTION;
+
+    assert!(mask.contains(RayType::Camera));
+    assert!(mask.contains(RayType::Reflection));
+    assert!(!mask.contains(RayType::Shadow));
+    assert!(!RayTypeMask::NONE.contains(RayType::Camera));
+    assert!(RayTypeMask::ALL.contains(RayType::Shadow));
+}
+
+#[test]
+fn camera_only_object_is_seen_but_casts_no_shadow() {
+    let world = world(true);
+    let interval = Interval::new(0.001, f64::MAX);
+    let below = Point::new(0.0, 0.0, 0.0);
+    let up = Vector::new(0.0, 1.0, 0.0).unit();
+
+    let seen = world.hit(interval, &Ray::new(&Point::new(0.0, 3.0, 0.0), -up));
+    assert!((seen.unwrap().t() - 1.5).abs() < 1e-9);
+    assert!(!world.hit_p(
+        interval,
+        &Ray::new(&below, up).with_ray_type(RayType::Shadow)
+    ));
+    assert!(
+        world
+            .hit(interval, &Ray::new(&below, up).child(&below, up))
+            .is_none()
+    );
+
+    // Ambient occlusion probes are shadow rays, so the floor beneath stays unoccluded
+    let eye = Point::new(4.0, 0.5, 0.0);
+    let to_floor = Ray::new(&eye, (below - eye).unit());
+    let masked = to_floor.ambient_occlusion(&world, 10.0, 256);
+    let unmasked = to_floor.ambient_occlusion(&self::world(false), 10.0, 256);
+
+    assert_eq!(masked.r(), 1.0);
+    assert!(unmasked.r() < 1.0);
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use ray_tracing_rs::Point;
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::{Lambertian, MaterialRegistry, Metal};
+use ray_tracing_rs::objects::Sphere;
+
+#[test]
+fn spheres_share_named_material() {
+    let mut registry = MaterialRegistry::new();
+    registry.insert("matte", Lambertian::new(Color::new(0.5, 0.5, 0.5)));
+    registry.insert("chrome", Metal::new(Color::new(0.9, 0.9, 0.9), 0.0));
+
+    let a = Sphere::with_shared_material(
+        Point::new(-1.0, 0.0, -1.0),
+        0.5,
+        registry.get("matte").unwrap(),
+    );
+    let b = Sphere::with_shared_material(
+        Point::new(1.0, 0.0, -1.0),
+        0.5,
+        registry.get("matte").unwrap(),
+    );
+    let c = Sphere::with_shared_material(
+        Point::new(0.0, 0.0, -1.0),
+        0.5,
+        registry.get("chrome").unwrap(),
+    );
+
+    assert!(Arc::ptr_eq(a.material(), b.material()));
+    assert!(!Arc::ptr_eq(a.material(), c.material()));
+    assert!(registry.get("missing").is_none());
+}
@@ -0,0 +1,288 @@
+use std::f64::consts::PI;
+
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::{
+    Clearcoat, Dielectric, FresnelMetal, Lambertian, LatitudeBlend, Material, Metal, NormalMapped,
+    RayInteraction, ThinFilm,
+};
+use ray_tracing_rs::objects::{Hittable, Obb, Plane, Sphere};
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::scene::{ParallelOptions, RenderOptions};
+use ray_tracing_rs::textures::SolidColor;
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{HittableList, ImageOptions, Interval, Point};
+
+mod common;
+
+#[test]
+fn fresnel_metal_brightens_at_grazing_angles() {
+    let gold = FresnelMetal::new(Color::new(1.0, 0.71, 0.29), 0.0);
+
+    let head_on = gold.reflectance(1.0);
+    let grazing = gold.reflectance(0.05);
+
+    assert!((head_on.g() - 0.71).abs() < 1e-12);
+    assert!(grazing.g() > head_on.g());
+    assert!(grazing.b() > head_on.b());
+    assert_eq!(grazing.r(), 1.0);
+}
+
+#[test]
+fn one_sided_lambertian_is_black_from_behind() {
+    let material = Lambertian::new(Color::new(0.8, 0.8, 0.8)).two_sided(false);
+    let mut world = HittableList::new();
+    world.add(Plane::new(Vector::new(0.0, 1.0, 0.0).unit(), 0.0, material));
+
+    let above = Point::new(0.0, 1.0, 0.0);
+    let below = Point::new(0.0, -1.0, 0.0);
+    let front = Ray::new(&above, Vector::new(0.0, -1.0, 0.0).unit()).color(&world, 4);
+    let back = Ray::new(&below, Vector::new(0.0, 1.0, 0.0).unit()).color(&world, 4);
+
+    assert!(front.r() > 0.0);
+    assert_eq!((back.r(), back.g(), back.b()), (0.0, 0.0, 0.0));
+}
+
+#[test]
+fn normal_maps_tilt_only_when_off_center() {
+    let floor = Plane::new(
+        Vector::new(0.0, 1.0, 0.0).unit(),
+        0.0,
+        Lambertian::new(Color::new(0.8, 0.8, 0.8)),
+    );
+    let above = Point::new(0.2, 1.0, 0.3);
+    let ray = Ray::new(&above, Vector::new(0.0, -1.0, 0.0).unit());
+    let record = floor.hit(Interval::new(0.001, f64::MAX), &ray).unwrap();
+    let mapped = |texel| {
+        let material = NormalMapped::new(
+            Lambertian::new(Color::new(0.8, 0.8, 0.8)),
+            SolidColor::new(texel),
+        );
+        material.normal(&record)
+    };
+
+    let flat = mapped(Color::new(0.5, 0.5, 1.0));
+    assert!((flat.dot(&record.normal()) - 1.0).abs() < 1e-12);
+
+    let tilted = mapped(Color::new(1.0, 0.5, 1.0));
+    let cos = tilted.dot(&record.normal());
+    assert!((cos - 0.5_f64.sqrt()).abs() < 1e-12);
+}
+
+#[test]
+fn clearcoat_reflects_more_at_grazing_angles() {
+    let paint = Clearcoat::new(Lambertian::new(Color::new(0.8, 0.1, 0.1)), 1.5);
+    let mut world = HittableList::new();
+    world.add(Plane::new(Vector::new(0.0, 1.0, 0.0).unit(), 0.0, paint));
+
+    let coat_fraction = |dir: Vector| {
+        let origin = Point::new(0.0, 1.0, 0.0);
+        let ray = Ray::new(&origin, dir.unit());
+        let record = world.hit(Interval::new(0.001, f64::MAX), &ray).unwrap();
+        let coat_hits = (0..2000)
+            .filter(|_| match record.material().interact(&ray, &record) {
+                RayInteraction::Scattered(emergent) => emergent.attenuation().g() == 1.0,
+                RayInteraction::Absorbed => false,
+            })
+            .count();
+        coat_hits as f64 / 2000.0
+    };
+
+    let head_on = coat_fraction(Vector::new(0.0, -1.0, 0.0));
+    let grazing = coat_fraction(Vector::new(20.0, -1.0, 0.0));
+
+    assert!(head_on < 0.1);
+    assert!(grazing > 0.5);
+}
+
+#[test]
+fn thin_film_hue_shifts_with_thickness() {
+    let dominant = |thickness: f64| {
+        let color = ThinFilm::new(thickness, 1.33).reflectance(1.0);
+        let channels = [color.r(), color.g(), color.b()];
+        (0..3)
+            .max_by(|&a, &b| channels[a].total_cmp(&channels[b]))
+            .unwrap()
+    };
+
+    assert_eq!(dominant(250.0), 2);
+    assert_eq!(dominant(300.0), 1);
+    assert_eq!(dominant(350.0), 0);
+}
+
+#[test]
+fn thin_film_hue_shifts_with_view_angle() {
+    let film = ThinFilm::new(300.0, 1.33);
+    let head_on = film.reflectance(1.0);
+    let oblique = film.reflectance(0.3);
+
+    assert!(head_on.g() > head_on.b());
+    assert!(oblique.b() > oblique.g());
+}
+
+#[test]
+fn seeded_glass_renders_repeat_exactly() {
+    let mut world = HittableList::new();
+    world
+        .add(Sphere::new(
+            Point::new(0.0, 0.0, -2.0),
+            0.5,
+            Dielectric::new(1.5),
+        ))
+        .add(Plane::new(
+            Vector::new(0.0, 1.0, 0.0).unit(),
+            0.5,
+            Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+        ));
+
+    let origin = Point::origin();
+    let trace = |seed| {
+        ray_tracing_rs::seed_thread_rng(seed);
+        (0..256)
+            .map(|i| {
+                let dir = Vector::new(i as f64 / 512.0 - 0.25, -0.1, -1.0).unit();
+                let color = Ray::new(&origin, dir).color(&world, 50);
+                [color.r(), color.g(), color.b()]
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(trace(7), trace(7));
+    assert_ne!(trace(7), trace(8));
+}
+
+#[test]
+fn seeded_parallel_glass_renders_repeat_exactly() {
+    let mut world = HittableList::new();
+    world
+        .add(Sphere::new(
+            Point::new(0.0, 0.0, -2.0),
+            0.5,
+            Dielectric::new(1.5),
+        ))
+        .add(Plane::new(
+            Vector::new(0.0, 1.0, 0.0).unit(),
+            0.5,
+            Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+        ));
+    let camera = common::forward_camera(ImageOptions::new(12, 8).unwrap().antialias(8));
+
+    let render = |parallel, seed| {
+        let options = RenderOptions::new()
+            .parallel(parallel)
+            .parallel_samples(true)
+            .seed(seed);
+        camera
+            .render_in_memory_with_options(&world, &options)
+            .iter()
+            .map(|color| [color.r(), color.g(), color.b()])
+            .collect::<Vec<_>>()
+    };
+
+    let first = render(ParallelOptions::AllAtOnce, 7);
+    assert_eq!(render(ParallelOptions::AllAtOnce, 7), first);
+    assert_eq!(render(ParallelOptions::ByRows, 7), first);
+    assert_eq!(render(ParallelOptions::Series, 7), first);
+    assert_ne!(render(ParallelOptions::AllAtOnce, 8), first);
+}
+
+#[test]
+fn latitude_blend_splits_a_sphere_between_top_and_bottom() {
+    let (red, blue) = (Color::new(0.9, 0.1, 0.1), Color::new(0.1, 0.1, 0.9));
+    let blend = LatitudeBlend::new(Lambertian::new(red), Lambertian::new(blue), 0.5, 0.2);
+    let sphere = Sphere::new(Point::origin(), 1.0, blend.clone());
+
+    let attenuation_from = |origin: Point, dir: Vector| {
+        let ray = Ray::new(&origin, dir.unit());
+        let record = sphere.hit(Interval::new(0.001, f64::MAX), &ray).unwrap();
+        match blend.interact(&ray, &record) {
+            RayInteraction::Scattered(emergent) => emergent.attenuation(),
+            RayInteraction::Absorbed => panic!("Lambertian never absorbs"),
+        }
+    };
+
+    for _ in 0..32 {
+        let top = attenuation_from(Point::new(0.0, 3.0, 0.0), Vector::new(0.0, -1.0, 0.0));
+        let bottom = attenuation_from(Point::new(0.0, -3.0, 0.0), Vector::new(0.0, 1.0, 0.0));
+        assert_eq!(top.r(), red.r());
+        assert_eq!(bottom.b(), blue.b());
+        assert_eq!(bottom.r(), blue.r());
+    }
+
+    // At the equator both materials are used
+    let equator: Vec<f64> = (0..256)
+        .map(|_| attenuation_from(Point::new(3.0, 0.0, 0.0), Vector::new(-1.0, 0.0, 0.0)).r())
+        .collect();
+    assert!(equator.contains(&red.r()) && equator.contains(&blue.r()));
+}
+
+#[test]
+fn latitude_blend_follows_height_on_any_object() {
+    let (red, blue) = (Color::new(0.9, 0.1, 0.1), Color::new(0.1, 0.1, 0.9));
+    let blend = LatitudeBlend::new(Lambertian::new(red), Lambertian::new(blue), 0.5, 0.0);
+    let axes = [
+        Vector::new(1.0, 0.0, 0.0).unit(),
+        Vector::new(0.0, 1.0, 0.0).unit(),
+        Vector::new(0.0, 0.0, 1.0).unit(),
+    ];
+    let tower = Obb::new(
+        Point::new(0.0, 2.0, 0.0),
+        axes,
+        Vector::new(0.5, 2.0, 0.5),
+        blend.clone(),
+    );
+
+    // Both hits land on the same side face, one near its top and one near its
+    // bottom
+    let hit_at = |y: f64| {
+        let origin = Point::new(3.0, y, 0.0);
+        let ray = Ray::new(&origin, Vector::new(-1.0, 0.0, 0.0).unit());
+        tower.hit(Interval::new(0.001, f64::MAX), &ray).unwrap()
+    };
+    let (high, low) = (hit_at(3.5), hit_at(0.5));
+    assert!((high.height() - 0.875).abs() < 1e-9);
+    assert!((low.height() - 0.125).abs() < 1e-9);
+
+    let origin = Point::new(3.0, 2.0, 0.0);
+    let ray = Ray::new(&origin, Vector::new(-1.0, 0.0, 0.0).unit());
+    let attenuation = |record| match blend.interact(&ray, record) {
+        RayInteraction::Scattered(emergent) => emergent.attenuation(),
+        RayInteraction::Absorbed => panic!("Lambertian never absorbs"),
+    };
+    assert_eq!(attenuation(&high).r(), red.r());
+    assert_eq!(attenuation(&low).r(), blue.r());
+}
+
+#[test]
+fn lambertian_scattering_pdf_integrates_to_one() {
+    let lambertian = Lambertian::new(Color::new(0.5, 0.5, 0.5));
+    let floor = Plane::new(Vector::new(0.0, 1.0, 0.0).unit(), 0.0, lambertian.clone());
+    let above = Point::new(0.0, 1.0, 0.0);
+    let ray = Ray::new(&above, Vector::new(0.3, -1.0, 0.2).unit());
+    let record = floor.hit(Interval::new(0.001, f64::MAX), &ray).unwrap();
+
+    // Midpoint rule over the whole sphere of directions; the pdf is zero below
+    // the surface
+    let steps = 400;
+    let (d_theta, d_phi) = (PI / steps as f64, 2.0 * PI / steps as f64);
+    let mut integral = 0.0;
+    for i in 0..steps {
+        let theta = (i as f64 + 0.5) * d_theta;
+        for j in 0..steps {
+            let phi = (j as f64 + 0.5) * d_phi;
+            let dir = Vector::new(
+                theta.sin() * phi.cos(),
+                theta.cos(),
+                theta.sin() * phi.sin(),
+            )
+            .unit();
+            let pdf = lambertian.scattering_pdf(&ray, &record, &dir).unwrap();
+            integral += pdf * theta.sin() * d_theta * d_phi;
+        }
+    }
+    assert!((integral - 1.0).abs() < 1e-3, "{integral}");
+
+    // Mirrors scatter into a single direction, which has no density
+    let mirror = Metal::new(Color::new(0.9, 0.9, 0.9), 0.0);
+    let up = Vector::new(0.0, 1.0, 0.0).unit();
+    assert!(mirror.scattering_pdf(&ray, &record, &up).is_none());
+}
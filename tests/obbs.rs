@@ -0,0 +1,49 @@
+use std::f64::consts::SQRT_2;
+
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::Lambertian;
+use ray_tracing_rs::objects::{ClosedSolid, Hittable, Obb};
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{Interval, Point};
+
+/// A unit-half-extent cube turned 45 degrees about the y-axis, so its corners
+/// point along x and z and its bounding box reaches `SQRT_2` along both.
+fn turned_cube() -> Obb {
+    Obb::new(
+        Point::origin(),
+        [
+            Vector::new(1.0, 0.0, 1.0).unit(),
+            Vector::new(0.0, 1.0, 0.0).unit(),
+            Vector::new(-1.0, 0.0, 1.0).unit(),
+        ],
+        Vector::new(1.0, 1.0, 1.0),
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    )
+}
+
+#[test]
+fn rotated_box_is_hit_inside_its_outline_only() {
+    let cube = turned_cube();
+    let interval = Interval::new(0.001, f64::MAX);
+    let bbox = cube.bounding_box().unwrap();
+    assert!((bbox.max().x() - SQRT_2).abs() < 1e-9);
+    assert!((bbox.max().z() - SQRT_2).abs() < 1e-9);
+
+    // Near the x corner, the box's face is at z = SQRT_2 - x
+    let origin = Point::new(1.3, 0.0, 5.0);
+    let ray = Ray::new(&origin, Vector::new(0.0, 0.0, -1.0).unit());
+    let record = cube.hit(interval, &ray).unwrap();
+    assert!((record.point().z() - (SQRT_2 - 1.3)).abs() < 1e-9);
+    let normal = record.normal();
+    assert!((normal.x() - normal.z()).abs() < 1e-9 && normal.x() > 0.0);
+
+    // Inside the bounding box's corner, but outside the turned cube
+    let origin = Point::new(1.2, 5.0, 1.2);
+    let ray = Ray::new(&origin, Vector::new(0.0, -1.0, 0.0).unit());
+    assert!(bbox.hit(interval, &ray));
+    assert!(cube.hit(interval, &ray).is_none());
+    assert!(!cube.hit_p(interval, &ray));
+    assert!(!cube.contains_point(&Point::new(1.2, 0.0, 1.2)));
+    assert!(cube.contains_point(&Point::new(1.0, 0.0, 0.0)));
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "open")]
+
+use std::fs;
+
+use ray_tracing_rs::{HittableList, ImageOptions};
+
+mod common;
+
+#[test]
+fn file_is_written_before_it_is_opened() {
+    let camera = common::forward_camera(ImageOptions::new(4, 3).unwrap());
+    let path = common::temp_path("open.ppm");
+
+    let mut opened = false;
+    camera
+        .render_and_open_with(&HittableList::new(), &path, |written| {
+            let contents = fs::read_to_string(written)?;
+            assert!(contents.starts_with("P3\n4 3\n255\n"));
+            assert_eq!(contents.lines().count(), 3 + 4 * 3);
+            opened = true;
+            Ok(())
+        })
+        .unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert!(opened);
+}
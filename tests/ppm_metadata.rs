@@ -0,0 +1,114 @@
+use std::fs;
+use std::io;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::Lambertian;
+use ray_tracing_rs::objects::Sphere;
+use ray_tracing_rs::scene::{ParallelOptions, RenderOptions};
+use ray_tracing_rs::{Camera, ConfigError, HittableList, ImageOptions, Point, RenderError};
+
+mod common;
+
+fn scene() -> (Camera, HittableList) {
+    let camera = common::forward_camera(ImageOptions::new(6, 4).unwrap().antialias(3));
+
+    let mut world = HittableList::new();
+    world.add(Sphere::new(
+        Point::new(0.0, 0.0, -2.0),
+        0.5,
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    ));
+    (camera, world)
+}
+
+/// Parse a P3 PPM, skipping comment lines, into its width, height, and samples.
+fn parse_ppm(contents: &str) -> (u32, u32, Vec<u32>) {
+    let mut tokens = contents
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .flat_map(str::split_whitespace);
+    assert_eq!(tokens.next(), Some("P3"));
+    let mut number = || tokens.next().unwrap().parse::<u32>().unwrap();
+    let (width, height, max) = (number(), number(), number());
+    assert_eq!(max, 255);
+    let samples = (0..width * height * 3).map(|_| number()).collect();
+    (width, height, samples)
+}
+
+#[test]
+fn metadata_comment_is_written_after_the_magic_number() {
+    let (camera, world) = scene();
+    let path = common::temp_path("ppm-metadata.ppm");
+
+    camera
+        .render_with_options(&world, &path, &RenderOptions::new().embed_metadata(true))
+        .unwrap();
+
+    let contents = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert!(contents.starts_with("P3\n# ray-tracing-rs spp=3 depth=50\n6 4\n"));
+    let (width, height, samples) = parse_ppm(&contents);
+    assert_eq!((width, height, samples.len()), (6, 4, 72));
+}
+
+#[test]
+fn metadata_is_off_by_default() {
+    let (camera, world) = scene();
+    let path = common::temp_path("ppm-no-metadata.ppm");
+
+    camera.render(&world, &path).unwrap();
+    let contents = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert!(!contents.contains('#'));
+}
+
+#[test]
+fn unwritable_paths_return_the_io_error() {
+    let (camera, world) = scene();
+    let path = common::temp_path("missing-dir").join("out.ppm");
+
+    let error = camera.render(&world, &path).unwrap_err();
+
+    assert!(
+        matches!(&error, RenderError::Io(error) if error.kind() == io::ErrorKind::NotFound),
+        "{error:?}"
+    );
+}
+
+#[test]
+fn render_errors_say_why_the_render_failed() {
+    let (camera, world) = scene();
+    let path = common::temp_path("cancelled.ppm");
+
+    let cancel = Arc::new(AtomicBool::new(true));
+    for parallel in [
+        ParallelOptions::AllAtOnce,
+        ParallelOptions::ByRows,
+        ParallelOptions::Series,
+    ] {
+        let options = RenderOptions::new()
+            .parallel(parallel)
+            .cancel_flag(Arc::clone(&cancel));
+        let error = camera.render_with_options(&world, &path, &options);
+        assert!(matches!(error, Err(RenderError::Cancelled)), "{error:?}");
+    }
+    fs::remove_file(&path).unwrap();
+
+    let error = camera.render_tiles_to_dir(&world, &path, 0);
+    assert!(
+        matches!(error, Err(RenderError::Build(ConfigError::InvalidRegion))),
+        "{error:?}"
+    );
+
+    // Each view fits, but the side-by-side pair does not
+    let wide = common::forward_camera(ImageOptions::new(12_000, 12_000).unwrap());
+    let error = wide.render_stereo(&world, 0.064, &path);
+    assert!(
+        matches!(error, Err(RenderError::ImageTooLarge)),
+        "{error:?}"
+    );
+    assert!(!path.exists());
+}
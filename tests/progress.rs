@@ -0,0 +1,24 @@
+#![cfg(feature = "indicatif")]
+
+use indicatif::{ProgressBar, ProgressDrawTarget};
+use ray_tracing_rs::scene::RenderOptions;
+use ray_tracing_rs::{HittableList, ImageOptions};
+
+mod common;
+
+#[test]
+fn progress_bar_completes_after_render() {
+    let camera = common::forward_camera(ImageOptions::new(8, 6).unwrap());
+
+    let bar = ProgressBar::with_draw_target(None, ProgressDrawTarget::hidden());
+    let options = RenderOptions::new().progress_bar(bar.clone());
+    let path = common::temp_path("progress.ppm");
+    camera
+        .render_with_options(&HittableList::new(), &path, &options)
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(bar.length(), Some(6));
+    assert_eq!(bar.position(), 6);
+    assert!(bar.is_finished());
+}
@@ -0,0 +1,164 @@
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::{DiffuseLight, Lambertian, Metal};
+use ray_tracing_rs::objects::Sphere;
+use ray_tracing_rs::scene::{RenderOptions, stitch_regions, stitch_tiles};
+use ray_tracing_rs::{Camera, ConfigError, HittableList, ImageOptions, Point, assert_color_eq};
+
+mod common;
+
+fn camera(width: u32, height: u32) -> Camera {
+    common::forward_camera(ImageOptions::new(width, height).unwrap())
+}
+
+/// A mirror sphere, which shades deterministically without antialiasing.
+fn mirror_world() -> HittableList {
+    let mut world = HittableList::new();
+    let material = Metal::new(Color::new(0.8, 0.8, 0.8), 0.0);
+    world.add(Sphere::new(Point::new(0.0, 0.0, -1.5), 0.5, material));
+    world
+}
+
+#[test]
+fn stitched_regions_match_full_render() {
+    let camera = camera(20, 20);
+    let world = mirror_world();
+
+    let regions = [
+        (0, 0, 10, 10),
+        (10, 0, 10, 10),
+        (0, 10, 10, 10),
+        (10, 10, 10, 10),
+    ];
+    let tiles = regions
+        .iter()
+        .map(|&region| (region, camera.render_region(&world, region).unwrap()))
+        .collect();
+    let stitched = stitch_regions(20, 20, tiles).unwrap();
+
+    let full: Vec<_> = camera
+        .render_in_memory(&world)
+        .into_iter()
+        .map(|pixel| pixel.to_string())
+        .collect();
+    let stitched: Vec<_> = stitched
+        .pixels()
+        .iter()
+        .map(|pixel| pixel.to_string())
+        .collect();
+    assert_eq!(full, stitched);
+}
+
+/// A diffuse floor under a small light, which shades differently every sample.
+fn noisy_world() -> HittableList {
+    let mut world = HittableList::new();
+    world
+        .add(Sphere::new(
+            Point::new(0.0, -100.5, -1.0),
+            100.0,
+            Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+        ))
+        .add(Sphere::new(
+            Point::new(0.0, 1.0, -1.5),
+            0.5,
+            DiffuseLight::new(Color::new(4.0, 4.0, 4.0)),
+        ));
+    world
+}
+
+#[test]
+fn seeded_antialiased_regions_match_a_seeded_full_render() {
+    let camera = common::forward_camera(ImageOptions::new(20, 20).unwrap().antialias(4));
+    let world = noisy_world();
+    let options = RenderOptions::new().seed(3);
+
+    let regions = [
+        (0, 0, 10, 10),
+        (10, 0, 10, 10),
+        (0, 10, 10, 10),
+        (10, 10, 10, 10),
+    ];
+    let tiles = regions
+        .iter()
+        .map(|&region| {
+            let tile = camera.render_region_with_options(&world, region, &options);
+            (region, tile.unwrap())
+        })
+        .collect();
+    let stitched = stitch_regions(20, 20, tiles).unwrap();
+
+    let full = camera.render_in_memory_with_options(&world, &options);
+    for (stitched, full) in stitched.pixels().iter().zip(&full) {
+        assert_color_eq!(*stitched, *full, 0.0);
+    }
+}
+
+#[test]
+fn render_region_rejects_out_of_bounds_regions() {
+    let camera = camera(20, 20);
+    let world = mirror_world();
+
+    assert_eq!(
+        camera.render_region(&world, (15, 0, 10, 10)).unwrap_err(),
+        ConfigError::InvalidRegion
+    );
+    assert_eq!(
+        camera.render_region(&world, (0, 0, 0, 10)).unwrap_err(),
+        ConfigError::InvalidRegion
+    );
+}
+
+#[test]
+fn tiles_written_to_a_directory_stitch_into_the_full_render() {
+    let camera = camera(20, 20);
+    let world = mirror_world();
+    let dir = common::temp_path("tiles");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    camera.render_tiles_to_dir(&world, &dir, 10).unwrap();
+    assert!(dir.join("tile_10_0.ppm").is_file());
+    let stitched = stitch_tiles(&dir).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!((stitched.width(), stitched.height()), (20, 20));
+    let full: Vec<_> = camera
+        .render_in_memory(&world)
+        .into_iter()
+        .map(|pixel| pixel.to_string())
+        .collect();
+    let stitched: Vec<_> = stitched
+        .pixels()
+        .iter()
+        .map(|pixel| pixel.to_string())
+        .collect();
+    assert_eq!(full, stitched);
+}
+
+#[test]
+fn seeded_tiles_stitch_into_the_seeded_full_render() {
+    let camera = common::forward_camera(ImageOptions::new(20, 20).unwrap().antialias(4));
+    let world = noisy_world();
+    let options = RenderOptions::new().seed(11);
+    let full: Vec<_> = camera
+        .render_in_memory_with_options(&world, &options)
+        .into_iter()
+        .map(|pixel| pixel.to_string())
+        .collect();
+
+    // Different tilings stand in for different machines splitting the work
+    for tile_size in [7, 10] {
+        let dir = common::temp_path(&format!("seeded-tiles-{tile_size}"));
+        let _ = std::fs::remove_dir_all(&dir);
+        camera
+            .render_tiles_to_dir_with_options(&world, &dir, tile_size, &options)
+            .unwrap();
+        let stitched = stitch_tiles(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let stitched: Vec<_> = stitched
+            .pixels()
+            .iter()
+            .map(|pixel| pixel.to_string())
+            .collect();
+        assert_eq!(full, stitched);
+    }
+}
@@ -0,0 +1,69 @@
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::{DiffuseLight, Lambertian};
+use ray_tracing_rs::objects::Sphere;
+use ray_tracing_rs::scene::CacheStatus;
+use ray_tracing_rs::{Camera, HittableList, ImageOptions, Point};
+
+mod common;
+
+fn test_camera() -> Camera {
+    common::forward_camera(ImageOptions::new(8, 4).unwrap())
+}
+
+fn sphere_world(radius: f64) -> HittableList {
+    let mut world = HittableList::new();
+    let material = Lambertian::new(Color::new(0.8, 0.3, 0.3));
+    world.add(Sphere::new(Point::new(0.0, 0.0, -1.0), radius, material));
+    world
+}
+
+#[test]
+fn second_render_of_an_unchanged_scene_hits_the_cache() {
+    let dir = common::temp_path("render-cache");
+    let _ = std::fs::remove_dir_all(&dir);
+    let first = common::temp_path("render-cache-first.ppm");
+    let second = common::temp_path("render-cache-second.ppm");
+    let camera = test_camera();
+
+    let status = camera
+        .render_cached(&sphere_world(0.5), &dir, &first)
+        .unwrap();
+    assert_eq!(status, CacheStatus::Miss);
+
+    let status = camera
+        .render_cached(&sphere_world(0.5), &dir, &second)
+        .unwrap();
+    assert_eq!(status, CacheStatus::Hit);
+    assert_eq!(
+        std::fs::read_to_string(&first).unwrap(),
+        std::fs::read_to_string(&second).unwrap()
+    );
+
+    let status = camera
+        .render_cached(&sphere_world(0.4), &dir, &second)
+        .unwrap();
+    assert_eq!(status, CacheStatus::Miss);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+    std::fs::remove_file(&first).unwrap();
+    std::fs::remove_file(&second).unwrap();
+}
+
+#[test]
+fn fingerprints_track_the_camera_and_the_world() {
+    let camera = test_camera();
+    let world = sphere_world(0.5);
+    let fingerprint = camera.fingerprint(&world).unwrap();
+
+    assert_eq!(camera.fingerprint(&sphere_world(0.5)), Some(fingerprint));
+    assert_ne!(camera.fingerprint(&sphere_world(0.6)), Some(fingerprint));
+
+    let mut moved = camera.clone();
+    moved.move_to(Point::new(0.0, 0.1, 0.0)).unwrap();
+    assert_ne!(moved.fingerprint(&world), Some(fingerprint));
+
+    let mut animated = HittableList::new();
+    let light = DiffuseLight::animated(|t| Color::new(t, t, t));
+    animated.add(Sphere::new(Point::new(0.0, 0.0, -1.0), 0.5, light));
+    assert_eq!(camera.fingerprint(&animated), None);
+}
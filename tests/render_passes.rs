@@ -0,0 +1,394 @@
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::{DiffuseLight, Lambertian, Metal};
+use std::sync::Arc;
+
+use ray_tracing_rs::materials::Material;
+use ray_tracing_rs::objects::{Hittable, Plane, Sphere, Triangle};
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::scene::integrator::{AmbientOcclusion, Integrator, PathTracer};
+use ray_tracing_rs::scene::{
+    DirectLighting, NormalSpace, ParallelOptions, RenderOptions, RenderPass,
+};
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{
+    Camera, CameraConfig, CameraModel, CameraPose, HittableList, ImageOptions,
+    PerspectiveProjection, Point, assert_color_eq, seed_thread_rng,
+};
+
+mod common;
+
+/// A camera looking straight down at the floor, with +x to the right.
+fn top_down_camera() -> Camera {
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 2.0, 0.0),
+        Point::new(0.0, -0.5, 0.0),
+        Vector::new(0.0, 0.0, -1.0),
+    )
+    .unwrap();
+    common::pinhole_camera(pose, ImageOptions::new(16, 16).unwrap(), 90.0)
+}
+
+/// A floor at `y = -0.5` meeting a wall at `x = 1.0`.
+fn floor_and_wall() -> HittableList {
+    let material = Lambertian::new(Color::new(0.5, 0.5, 0.5));
+    let mut world = HittableList::new();
+    world
+        .add(Plane::new(
+            Vector::new(0.0, 1.0, 0.0).unit(),
+            0.5,
+            material.clone(),
+        ))
+        .add(Plane::new(
+            Vector::new(-1.0, 0.0, 0.0).unit(),
+            1.0,
+            material,
+        ));
+    world
+}
+
+#[test]
+fn ambient_occlusion_darkens_points_near_walls() {
+    let camera = top_down_camera();
+    let world = floor_and_wall();
+    let options = RenderOptions::new().pass(RenderPass::AmbientOcclusion {
+        radius: 1.0,
+        samples: 256,
+    });
+
+    let pixels = camera.render_in_memory_with_options(&world, &options);
+
+    // Column 2 is ~2.7 units from the wall, column 10 is ~0.2 units from it.
+    let exposed = pixels[8 * 16 + 2];
+    let near_wall = pixels[8 * 16 + 10];
+
+    assert_eq!(exposed.r(), 1.0);
+    assert!(near_wall.r() < exposed.r());
+    assert_eq!(near_wall.r(), near_wall.g());
+}
+
+#[test]
+fn variance_is_higher_on_edges_than_flat_regions() {
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(0.0, 0.0, -1.0),
+        Vector::new(0.0, 1.0, 0.0),
+    )
+    .unwrap();
+    let camera = common::pinhole_camera(pose, ImageOptions::new(9, 9).unwrap().antialias(64), 90.0);
+
+    let black = DiffuseLight::new(Color::new(0.0, 0.0, 0.0));
+
+    // A black wall filling the whole view.
+    let mut world = HittableList::new();
+    world.add(Plane::new(
+        Vector::new(0.0, 0.0, 1.0).unit(),
+        1.0,
+        black.clone(),
+    ));
+
+    // A black wall covering the right half of the view, so the middle column
+    // straddles the edge between the wall and the sky.
+    let mut world_with_edge = HittableList::new();
+    world_with_edge.add(Triangle::new(
+        Point::new(0.0, -10.0, -1.0),
+        Point::new(10.0, -10.0, -1.0),
+        Point::new(0.0, 10.0, -1.0),
+        black,
+    ));
+
+    let (_, flat) = camera.render_with_variance(&world);
+    let (mean, edge) = camera.render_with_variance(&world_with_edge);
+
+    assert_eq!(mean.width(), 9);
+    assert_eq!(flat.get(4, 4).unwrap().r(), 0.0);
+    assert!(edge.get(4, 4).unwrap().r() > 0.0);
+    assert_eq!(edge.get(6, 4).unwrap().r(), 0.0);
+}
+
+#[test]
+fn light_sampling_reduces_variance_from_small_lights() {
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 2.0, 0.0),
+        Point::new(0.0, -0.5, 0.0),
+        Vector::new(0.0, 0.0, -1.0),
+    )
+    .unwrap();
+    let camera = common::pinhole_camera(pose, ImageOptions::new(8, 8).unwrap().antialias(32), 60.0);
+
+    // A small, bright light just outside the view, above a diffuse floor.
+    let light: Arc<dyn Material> = Arc::new(DiffuseLight::new(Color::new(40.0, 40.0, 40.0)));
+    let light_center = Point::new(2.0, 0.5, 0.0);
+    let mut world = HittableList::new();
+    world
+        .add(Plane::new(
+            Vector::new(0.0, 1.0, 0.0).unit(),
+            0.5,
+            Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+        ))
+        .add(Sphere::with_shared_material(
+            light_center,
+            0.2,
+            Arc::clone(&light),
+        ));
+    let mut lights = HittableList::new();
+    lights.add(Sphere::with_shared_material(light_center, 0.2, light));
+
+    let mean_variance = |strategy| {
+        let options = RenderOptions::new()
+            .lights(lights.clone())
+            .direct_lighting(strategy);
+        let (_, variance) = camera.render_with_variance_with_options(&world, &options);
+        let pixels = variance.pixels();
+        pixels.iter().map(|p| p.r()).sum::<f64>() / pixels.len() as f64
+    };
+
+    let material_only = mean_variance(DirectLighting::MaterialOnly);
+    let light_only = mean_variance(DirectLighting::LightOnly);
+    let mis = mean_variance(DirectLighting::Mis);
+
+    assert!(light_only < material_only);
+    assert!(mis < material_only);
+}
+
+#[test]
+fn progressive_converges_to_antialiased_render() {
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 2.0, 0.0),
+        Point::new(0.0, -0.5, 0.0),
+        Vector::new(0.0, 0.0, -1.0),
+    )
+    .unwrap();
+    let projection = PerspectiveProjection::new(60.0).unwrap();
+    let model = CameraModel::pinhole(1.0).unwrap();
+    let config = |image| CameraConfig::new(pose, image, projection, model);
+    let progressive_camera = Camera::new(config(ImageOptions::new(8, 8).unwrap()));
+    let direct_camera = Camera::new(config(ImageOptions::new(8, 8).unwrap().antialias(64)));
+    let world = floor_and_wall();
+
+    let images: Vec<_> = progressive_camera.progressive(&world, 64).collect();
+    let direct = direct_camera.render_in_memory(&world);
+
+    assert_eq!(images.len(), 64);
+    assert!(images.iter().all(|image| image.pixels().len() == 64));
+    let mean = |pixels: &[Color]| pixels.iter().map(|p| p.r()).sum::<f64>() / pixels.len() as f64;
+    let last = images.last().unwrap();
+    assert!((mean(last.pixels()) - mean(&direct)).abs() < 0.02);
+}
+
+/// Shades every ray the same, whatever the scene.
+struct ConstantIntegrator(Color);
+
+impl Integrator for ConstantIntegrator {
+    fn radiance(&self, _ray: &Ray, _world: &dyn Hittable, _depth: u32) -> Color {
+        self.0
+    }
+}
+
+#[test]
+fn custom_integrators_replace_the_built_in_passes() {
+    let camera = top_down_camera();
+    let world = floor_and_wall();
+    let red = Color::new(1.0, 0.0, 0.0);
+
+    let options = RenderOptions::new()
+        .pass(RenderPass::AmbientOcclusion {
+            radius: 1.0,
+            samples: 4,
+        })
+        .integrator(ConstantIntegrator(red));
+    let pixels = camera.render_in_memory_with_options(&world, &options);
+    assert!(
+        pixels
+            .iter()
+            .all(|pixel| (pixel.r(), pixel.g(), pixel.b()) == (1.0, 0.0, 0.0))
+    );
+
+    // The built-in integrators can be plugged in explicitly too
+    let occlusion = RenderOptions::new().integrator(AmbientOcclusion::new(1.0, 0));
+    let pixels = camera.render_in_memory_with_options(&world, &occlusion);
+    assert!(pixels.iter().all(|pixel| pixel.r() == 1.0));
+    let traced = camera
+        .render_in_memory_with_options(&world, &RenderOptions::new().integrator(PathTracer::new()));
+    assert!(traced.iter().any(|pixel| pixel.r() < 1.0));
+}
+
+/// The center pixel of a normals pass looking straight at a plane through the
+/// origin with normal `facing`.
+fn center_normal(facing: Vector, space: NormalSpace) -> Color {
+    let up = if facing.y().abs() > 0.9 {
+        Vector::new(0.0, 0.0, -1.0)
+    } else {
+        Vector::new(0.0, 1.0, 0.0)
+    };
+    let pose = CameraPose::look_at(
+        Point::from_vector(facing.unit().inner() * 3.0),
+        Point::origin(),
+        up,
+    )
+    .unwrap();
+    let camera = common::pinhole_camera(pose, ImageOptions::new(9, 9).unwrap(), 60.0);
+
+    let mut world = HittableList::new();
+    world.add(Plane::new(
+        facing.unit(),
+        0.0,
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    ));
+    let options = RenderOptions::new().pass(RenderPass::Normals { space });
+
+    camera.render_in_memory_with_options(&world, &options)[4 * 9 + 4]
+}
+
+#[test]
+fn camera_space_normals_of_facing_surfaces_are_blue() {
+    for facing in [
+        Vector::new(0.0, 0.0, 1.0),
+        Vector::new(0.0, 1.0, 0.0),
+        Vector::new(1.0, 1.0, -1.0),
+    ] {
+        let color = center_normal(facing, NormalSpace::Camera);
+        assert_color_eq!(color, Color::new(0.5, 0.5, 1.0), 1e-6);
+    }
+}
+
+#[test]
+fn world_space_normals_follow_the_surface() {
+    let color = center_normal(Vector::new(0.0, 1.0, 0.0), NormalSpace::World);
+    assert_color_eq!(color, Color::new(0.5, 1.0, 0.5), 1e-6);
+
+    let color = center_normal(Vector::new(-1.0, 0.0, 0.0), NormalSpace::World);
+    assert_color_eq!(color, Color::new(0.0, 0.5, 0.5), 1e-6);
+}
+
+#[test]
+fn mirrored_light_arrives_at_depth_one() {
+    // The camera looks at a mirror and sees, reflected, a light behind itself
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 0.0, 0.0),
+        Point::new(0.0, 0.0, -1.0),
+        Vector::new(0.0, 1.0, 0.0),
+    )
+    .unwrap();
+    let camera = common::pinhole_camera(pose, ImageOptions::new(9, 9).unwrap(), 20.0);
+    let mut world = HittableList::new();
+    world
+        .add(Plane::new(
+            Vector::new(0.0, 0.0, 1.0).unit(),
+            2.0,
+            Metal::new(Color::new(0.8, 0.8, 0.8), 0.0),
+        ))
+        .add(Sphere::new(
+            Point::new(0.0, 0.0, 3.0),
+            1.0,
+            DiffuseLight::new(Color::new(4.0, 4.0, 4.0)),
+        ));
+
+    let aovs = camera.render_depth_aovs(&world, 3);
+
+    assert_eq!(aovs.len(), 3);
+    let center = |depth: usize| aovs[depth].get(4, 4).unwrap();
+    assert_color_eq!(center(0), Color::new(0.0, 0.0, 0.0));
+    assert_color_eq!(center(1), Color::new(3.2, 3.2, 3.2), 1e-9);
+    assert_color_eq!(center(2), Color::new(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn coverage_pass_is_a_white_disk_on_black() {
+    let camera = common::forward_camera(ImageOptions::new(16, 16).unwrap().antialias(0));
+    let mut world = HittableList::new();
+    world.add(Sphere::new(
+        Point::new(0.0, 0.0, -3.0),
+        1.0,
+        Lambertian::new(Color::new(0.1, 0.2, 0.3)),
+    ));
+
+    let options = RenderOptions::new().pass(RenderPass::Coverage);
+    let pixels = camera.render_in_memory_with_options(&world, &options);
+
+    let white = Color::new(1.0, 1.0, 1.0);
+    let black = Color::new(0.0, 0.0, 0.0);
+    assert_color_eq!(pixels[8 * 16 + 8], white);
+    assert_color_eq!(pixels[0], black);
+    assert!(
+        pixels
+            .iter()
+            .all(|&pixel| pixel.r() == 0.0 || pixel.r() == 1.0)
+    );
+    assert!(
+        pixels
+            .iter()
+            .all(|pixel| pixel.r() == pixel.g() && pixel.g() == pixel.b())
+    );
+
+    // The sphere subtends about 4.9 pixels of radius, so around 75 pixels
+    let covered = pixels.iter().filter(|pixel| pixel.r() == 1.0).count();
+    assert!((60..90).contains(&covered), "{covered}");
+
+    // Spectral sampling and clamping shape radiance, not the matte
+    let options = options.spectral(true).clamp_indirect(0.5);
+    let shaped = camera.render_in_memory_with_options(&world, &options);
+    for (shaped, pixel) in shaped.iter().zip(&pixels) {
+        assert_color_eq!(*shaped, *pixel);
+    }
+}
+
+#[test]
+fn mis_reduces_variance_on_glossy_floors() {
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 2.0, 0.0),
+        Point::new(0.0, -0.5, 0.0),
+        Vector::new(0.0, 0.0, -1.0),
+    )
+    .unwrap();
+    let camera = common::pinhole_camera(pose, ImageOptions::new(8, 8).unwrap().antialias(0), 60.0);
+
+    // A small, bright light beside the view, above a rough metal floor
+    let light: Arc<dyn Material> = Arc::new(DiffuseLight::new(Color::new(40.0, 40.0, 40.0)));
+    let light_center = Point::new(1.0, 0.5, 0.0);
+    let mut world = HittableList::new();
+    world
+        .add(Plane::new(
+            Vector::new(0.0, 1.0, 0.0).unit(),
+            0.5,
+            Metal::new(Color::new(0.8, 0.8, 0.8), 0.5),
+        ))
+        .add(Sphere::with_shared_material(
+            light_center,
+            0.2,
+            Arc::clone(&light),
+        ));
+    let mut lights = HittableList::new();
+    lights.add(Sphere::with_shared_material(light_center, 0.2, light));
+
+    // Mean brightness and mean per-pixel variance over repeated renders
+    let render = |strategy| {
+        let options = RenderOptions::new()
+            .lights(lights.clone())
+            .direct_lighting(strategy)
+            .parallel(ParallelOptions::Series);
+        seed_thread_rng(3);
+        let renders: Vec<Vec<f64>> = (0..64)
+            .map(|_| {
+                let pixels = camera.render_in_memory_with_options(&world, &options);
+                pixels.iter().map(|pixel| pixel.r()).collect()
+            })
+            .collect();
+        let pixel_count = renders[0].len();
+        let (mut mean, mut variance) = (0.0, 0.0);
+        for pixel in 0..pixel_count {
+            let values: Vec<f64> = renders.iter().map(|render| render[pixel]).collect();
+            let pixel_mean = values.iter().sum::<f64>() / values.len() as f64;
+            mean += pixel_mean / pixel_count as f64;
+            variance += values.iter().map(|v| (v - pixel_mean).powi(2)).sum::<f64>()
+                / (values.len() * pixel_count) as f64;
+        }
+        (mean, variance)
+    };
+
+    let (material_mean, material_variance) = render(DirectLighting::MaterialOnly);
+    let (mis_mean, mis_variance) = render(DirectLighting::Mis);
+
+    // Both converge to the same image, but MIS finds the light more often
+    assert!((mis_mean - material_mean).abs() < 0.1 * material_mean);
+    assert!(mis_variance * 4.0 < material_variance);
+}
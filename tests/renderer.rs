@@ -0,0 +1,61 @@
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::{DiffuseLight, Lambertian};
+use ray_tracing_rs::objects::Sphere;
+use ray_tracing_rs::scene::RenderOptions;
+use ray_tracing_rs::{Camera, HittableList, ImageOptions, Point, Renderer};
+
+mod common;
+
+fn camera(width: u32) -> Camera {
+    common::forward_camera(ImageOptions::new(width, 4).unwrap())
+}
+
+#[test]
+fn renders_from_several_threads_share_one_pool() {
+    let renderer = Renderer::new(2).unwrap();
+    assert_eq!(renderer.threads(), 2);
+
+    let mut world = HittableList::new();
+    world.add(Sphere::new(
+        Point::new(0.0, 0.0, -1.0),
+        0.5,
+        Lambertian::new(Color::new(0.8, 0.3, 0.3)),
+    ));
+
+    let widths = [4, 6, 8];
+    let paths = widths.map(|width| common::temp_path(&format!("renderer-{width}.ppm")));
+    std::thread::scope(|scope| {
+        for (width, path) in widths.iter().zip(&paths) {
+            let (renderer, world) = (&renderer, &world);
+            scope.spawn(move || renderer.render(&camera(*width), world, path).unwrap());
+        }
+    });
+
+    for (width, path) in widths.iter().zip(&paths) {
+        let ppm = std::fs::read_to_string(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert!(ppm.starts_with(&format!("P3\n{width} 4\n")));
+        assert_eq!(ppm.lines().count(), 3 + (width * 4) as usize);
+    }
+}
+
+#[test]
+fn parallel_samples_average_like_sequential_ones() {
+    // The camera sits inside a uniformly glowing sphere, so every sample agrees
+    let mut world = HittableList::new();
+    world.add(Sphere::new(
+        Point::origin(),
+        10.0,
+        DiffuseLight::new(Color::new(0.25, 0.5, 0.75)),
+    ));
+    let mut camera = camera(4);
+    camera.set_image_options(ImageOptions::new(4, 4).unwrap().antialias(64));
+
+    let sequential = camera.render_in_memory(&world);
+    let parallel =
+        camera.render_in_memory_with_options(&world, &RenderOptions::new().parallel_samples(true));
+    for (a, b) in sequential.iter().zip(&parallel) {
+        assert!((a.r() - b.r()).abs() < 1e-12);
+        assert!((a.b() - b.b()).abs() < 1e-12);
+    }
+}
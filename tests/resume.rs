@@ -0,0 +1,162 @@
+use std::io;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::time::{Duration, Instant};
+
+use ray_tracing_rs::background::{Background, EnvironmentMap};
+use ray_tracing_rs::color::{Color, ColorSum};
+use ray_tracing_rs::image::Image;
+use ray_tracing_rs::materials::{DiffuseLight, Lambertian};
+use ray_tracing_rs::objects::Sphere;
+use ray_tracing_rs::scene::{ParallelOptions, RenderOptions};
+use ray_tracing_rs::{
+    Camera, HittableList, ImageOptions, Point, RenderError, assert_color_eq, seed_thread_rng,
+};
+
+mod common;
+
+fn test_camera(width: u32) -> Camera {
+    common::forward_camera(ImageOptions::new(width, 3).unwrap())
+}
+
+/// The camera sits inside a uniformly glowing sphere, so every sample of every
+/// pass is the same and renders can be compared exactly.
+fn glowing_room() -> HittableList {
+    let mut world = HittableList::new();
+    world.add(Sphere::new(
+        Point::origin(),
+        10.0,
+        DiffuseLight::new(Color::new(0.25, 0.5, 0.75)),
+    ));
+    world
+}
+
+#[test]
+fn resuming_matches_rendering_all_passes_at_once() {
+    let path = common::temp_path("resume.raw");
+    let _ = std::fs::remove_file(&path);
+    let (camera, world) = (test_camera(4), glowing_room());
+
+    camera.resume_render(&world, &path, 2).unwrap();
+    let resumed = camera.resume_render(&world, &path, 2).unwrap();
+    let (_, samples) = Image::load_raw(&path).unwrap();
+    assert_eq!(samples, 4);
+
+    let passes: Vec<_> = (0..4)
+        .map(|_| camera.render_sample_pass(&world, &RenderOptions::default()))
+        .collect();
+    for (index, pixel) in resumed.pixels().iter().enumerate() {
+        let mut direct = Color::new(0.0, 0.0, 0.0);
+        for pass in &passes {
+            direct += pass.pixels()[index];
+        }
+        let direct = direct / 4.0;
+        assert_eq!(
+            (pixel.r(), pixel.g(), pixel.b()),
+            (direct.r(), direct.g(), direct.b())
+        );
+    }
+
+    let error = test_camera(5).resume_render(&world, &path, 1).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+    assert!(
+        matches!(&error, RenderError::Io(error) if error.kind() == io::ErrorKind::InvalidInput),
+        "{error:?}"
+    );
+}
+
+#[test]
+fn resuming_a_noisy_render_matches_rendering_all_passes_at_once() {
+    let path = common::temp_path("resume-noisy.raw");
+    let _ = std::fs::remove_file(&path);
+    let camera = test_camera(4);
+    let mut world = HittableList::new();
+    world.add(Sphere::new(
+        Point::new(0.0, -100.5, -1.0),
+        100.0,
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    ));
+    world.add(Sphere::new(
+        Point::new(0.0, 1.0, -1.0),
+        0.5,
+        DiffuseLight::new(Color::new(4.0, 4.0, 4.0)),
+    ));
+    let options = RenderOptions::new().parallel(ParallelOptions::Series);
+
+    seed_thread_rng(5);
+    camera
+        .resume_render_with_options(&world, &path, 3, &options)
+        .unwrap();
+    let resumed = camera
+        .resume_render_with_options(&world, &path, 5, &options)
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    seed_thread_rng(5);
+    let passes: Vec<_> = (0..8)
+        .map(|_| camera.render_sample_pass(&world, &options))
+        .collect();
+    let mut sums = vec![ColorSum::new(); 12];
+    for pass in &passes {
+        for (sum, pixel) in sums.iter_mut().zip(pass.pixels()) {
+            sum.add(*pixel);
+        }
+    }
+
+    // The scene is noisy, so matching needs every pass to repeat exactly
+    let (first, last) = (passes[0].pixels(), passes[7].pixels());
+    assert!(first.iter().zip(last).any(|(a, b)| a.r() != b.r()));
+    for (pixel, sum) in resumed.pixels().iter().zip(&sums) {
+        assert_color_eq!(*pixel, sum.total() / 8.0);
+    }
+}
+
+#[test]
+fn time_budgeted_renders_run_until_the_budget_and_write_the_average() {
+    let path = common::temp_path("render-for.ppm");
+    let (camera, world) = (test_camera(4), glowing_room());
+    let budget = Duration::from_millis(200);
+
+    let start = Instant::now();
+    let passes = camera.render_for(&world, budget, &path).unwrap();
+    assert!(start.elapsed() >= budget);
+    assert!(passes >= 1);
+
+    let image = Image::load_ppm(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!((image.width(), image.height()), (4, 3));
+    let glow = Color::new(0.25, 0.5, 0.75).to_string();
+    assert!(image.pixels().iter().all(|pixel| pixel.to_string() == glow));
+}
+
+#[test]
+fn time_budgeted_renders_use_their_render_options() {
+    let path = common::temp_path("render-for-options.ppm");
+    let camera = test_camera(4);
+    let teal = Color::new(0.0, 0.5, 0.5);
+    let sky = Image::from_pixels(2, 1, vec![teal; 2]).unwrap();
+    let sky = Background::Environment(Arc::new(EnvironmentMap::new(sky).unwrap()));
+    let options = RenderOptions::new().background(sky);
+
+    camera
+        .render_for_with_options(&HittableList::new(), Duration::ZERO, &path, &options)
+        .unwrap();
+    let image = Image::load_ppm(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(
+        image
+            .pixels()
+            .iter()
+            .all(|pixel| pixel.to_string() == teal.to_string())
+    );
+
+    let cancelled = options.cancel_flag(Arc::new(AtomicBool::new(true)));
+    let error = camera.render_for_with_options(
+        &HittableList::new(),
+        Duration::from_secs(60),
+        &path,
+        &cancelled,
+    );
+    assert!(matches!(error, Err(RenderError::Cancelled)), "{error:?}");
+    assert!(!path.exists());
+}
@@ -0,0 +1,51 @@
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::Lambertian;
+use ray_tracing_rs::objects::Plane;
+use ray_tracing_rs::scene::RenderOptions;
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{CameraPose, HittableList, ImageOptions, Point};
+
+mod common;
+
+const SIZE: u32 = 16;
+
+fn inside(x: u32, y: u32) -> bool {
+    (4..12).contains(&x) && (4..12).contains(&y)
+}
+
+#[test]
+fn focus_region_gets_less_noisy_pixels() {
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 2.0, 0.0),
+        Point::new(0.0, -0.5, 0.0),
+        Vector::new(0.0, 0.0, -1.0),
+    )
+    .unwrap();
+    let image = ImageOptions::new(SIZE, SIZE).unwrap().antialias(1);
+    let camera = common::pinhole_camera(pose, image, 60.0);
+
+    // A diffuse floor lit only by the sky, so every sample is noisy.
+    let mut world = HittableList::new();
+    world.add(Plane::new(
+        Vector::new(0.0, 1.0, 0.0).unit(),
+        0.5,
+        Lambertian::new(Color::new(0.8, 0.8, 0.8)),
+    ));
+    let options = RenderOptions::new().sample_map(|x, y| if inside(x, y) { 16 } else { 1 });
+
+    let first = camera.render_in_memory_with_options(&world, &options);
+    let second = camera.render_in_memory_with_options(&world, &options);
+
+    let (mut inside_diff, mut outside_diff) = (0.0, 0.0);
+    for (index, (a, b)) in first.iter().zip(&second).enumerate() {
+        let (x, y) = (index as u32 % SIZE, index as u32 / SIZE);
+        let diff = (a.r() - b.r()).powi(2);
+        if inside(x, y) {
+            inside_diff += diff / 64.0;
+        } else {
+            outside_diff += diff / 192.0;
+        }
+    }
+
+    assert!(inside_diff * 4.0 < outside_diff);
+}
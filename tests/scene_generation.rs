@@ -0,0 +1,99 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::Lambertian;
+use ray_tracing_rs::objects::{Aabb, BvhNode, Hittable, Masked, RayTypeMask, Scale, Sphere};
+use ray_tracing_rs::scene::generate::{jitter_positions, poisson_spheres, sphere_placements};
+use ray_tracing_rs::{HittableList, Point};
+
+fn region() -> Aabb {
+    Aabb::new(Point::new(-5.0, 0.2, -5.0), Point::new(5.0, 0.2, 5.0))
+}
+
+#[test]
+fn generated_spheres_do_not_overlap() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let spheres = sphere_placements(&region(), 200, 0.1..=0.4, &mut rng);
+
+    assert!(spheres.len() > 50);
+    for (i, (a, ra)) in spheres.iter().enumerate() {
+        assert!((0.1..=0.4).contains(ra));
+        assert!(a.x().abs() <= 5.0 && a.z().abs() <= 5.0 && a.y() == 0.2);
+        for (b, rb) in &spheres[i + 1..] {
+            assert!((a - b).len() > ra + rb);
+        }
+    }
+}
+
+#[test]
+fn poisson_spheres_stay_near_region() {
+    let mut rng = StdRng::seed_from_u64(11);
+    let world = poisson_spheres(&region(), 20, 0.2..=0.2, &mut rng);
+
+    let bbox = world.bounding_box().unwrap();
+    assert!(bbox.min().x() >= -5.2 && bbox.max().x() <= 5.2);
+    assert!(bbox.min().y() >= 0.0 && bbox.max().y() <= 0.4);
+}
+
+#[test]
+fn jittered_objects_move_at_most_max_offset() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let mut world = poisson_spheres(&region(), 30, 0.2..=0.2, &mut rng);
+    let centers = |world: &HittableList| -> Vec<Point> {
+        world
+            .objects()
+            .iter()
+            .map(|object| {
+                let bbox = object.bounding_box().unwrap();
+                bbox.min() + (bbox.max() - bbox.min()) * 0.5
+            })
+            .collect()
+    };
+    let before = centers(&world);
+
+    jitter_positions(&mut world, 0.3, &mut rng);
+
+    let moved: Vec<f64> = before
+        .iter()
+        .zip(centers(&world))
+        .map(|(a, b)| (b - a).len())
+        .collect();
+    assert_eq!(moved.len(), 30);
+    assert!(moved.iter().all(|&distance| distance <= 0.3 + 1e-12));
+    assert!(moved.iter().all(|&distance| distance > 0.0));
+}
+
+#[test]
+fn jitter_moves_objects_inside_wrappers() {
+    let mut rng = StdRng::seed_from_u64(5);
+    let sphere = |x: f64| {
+        Sphere::new(
+            Point::new(x, 0.0, 0.0),
+            0.5,
+            Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+        )
+    };
+    let mut pair = HittableList::new();
+    pair.add(sphere(4.0)).add(sphere(6.0));
+    let mut world = HittableList::new();
+    world
+        .add(Masked::new(sphere(0.0), RayTypeMask::CAMERA))
+        .add(Scale::new(sphere(1.0), 2.0))
+        .add(BvhNode::build(&pair).unwrap());
+    let boxes = |world: &HittableList| -> Vec<Aabb> {
+        world
+            .objects()
+            .iter()
+            .map(|object| object.bounding_box().unwrap())
+            .collect()
+    };
+    let before = boxes(&world);
+
+    jitter_positions(&mut world, 0.3, &mut rng);
+
+    for (a, b) in before.iter().zip(boxes(&world)) {
+        let (min, max) = (b.min() - a.min(), b.max() - a.max());
+        assert!((min - max).len() < 1e-9, "boxes keep their size");
+        assert!(min.len() > 0.0 && min.len() <= 0.3 + 1e-12);
+    }
+}
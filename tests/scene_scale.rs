@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use ray_tracing_rs::background::{Background, EnvironmentMap};
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::image::Image;
+use ray_tracing_rs::materials::Lambertian;
+use ray_tracing_rs::objects::{Hittable, Masked, Plane, RayTypeMask, Scale, Sphere};
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::scene::RenderOptions;
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{CameraPose, HittableList, ImageOptions, Interval, Point};
+
+mod common;
+
+fn two_spheres() -> HittableList {
+    let material = Lambertian::new(Color::new(0.5, 0.5, 0.5));
+    let mut world = HittableList::new();
+    world
+        .add(Sphere::new(
+            Point::new(0.0, 0.0, -3.0),
+            1.0,
+            material.clone(),
+        ))
+        .add(Sphere::new(Point::new(3.0, 0.0, -5.0), 1.0, material));
+    world
+}
+
+fn hit_t(world: &HittableList, ray: &Ray) -> f64 {
+    world
+        .hit(Interval::new(0.001, f64::MAX), ray)
+        .map(|record| record.t())
+        .unwrap()
+}
+
+#[test]
+fn scaling_about_the_eye_doubles_hit_distances() {
+    let origin = Point::origin();
+    let rays = [
+        Ray::new(&origin, Vector::new(0.0, 0.0, -1.0).unit()),
+        Ray::new(&origin, Vector::new(3.0, 0.0, -4.0).unit()),
+    ];
+    let mut world = two_spheres();
+    let before = rays.map(|ray| hit_t(&world, &ray));
+
+    world.scale(2.0, origin);
+
+    for (ray, t) in rays.iter().zip(before) {
+        assert!((hit_t(&world, ray) - 2.0 * t).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn scaling_reaches_inside_wrappers() {
+    let origin = Point::origin();
+    let ray = Ray::new(&origin, Vector::new(0.0, 0.0, -1.0).unit());
+    let sphere = || {
+        Sphere::new(
+            Point::new(0.0, 0.0, -3.0),
+            1.0,
+            Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+        )
+    };
+
+    let mut world = HittableList::new();
+    world.add(Masked::new(sphere(), RayTypeMask::ALL));
+    world.scale(2.0, Point::new(0.0, 0.0, 1.0));
+    assert!((hit_t(&world, &ray) - 5.0).abs() < 1e-9);
+
+    // The wrapped sphere sits at z = -6 with radius 2 before rescaling
+    let mut world = HittableList::new();
+    world.add(Scale::new(sphere(), 2.0));
+    world.scale(0.5, Point::new(0.0, 0.0, 2.0));
+    assert!((hit_t(&world, &ray) - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn scaling_moves_planes_away_from_the_pivot() {
+    let normal = Vector::new(0.0, 1.0, 0.0).unit();
+    let mut world = HittableList::new();
+    world.add(Plane::new(
+        normal,
+        1.0,
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    ));
+
+    let pivot = Point::new(0.0, 2.0, 0.0);
+    world.scale(3.0, pivot);
+
+    let ray = Ray::new(&pivot, Vector::new(0.0, -1.0, 0.0).unit());
+    assert!((hit_t(&world, &ray) - 9.0).abs() < 1e-9);
+}
+
+#[test]
+fn adaptive_ray_offsets_remove_acne_from_huge_scenes() {
+    // A ground sphere and camera scaled up until rounding error in hit
+    // points is far larger than the flat 0.001 minimum hit distance
+    let scale = 1e12;
+    let mut world = HittableList::new();
+    world.add(Sphere::new(
+        Point::new(0.0, -1000.0, 0.0),
+        1000.0,
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    ));
+    world.scale(scale, Point::origin());
+
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 5.0 * scale, 10.0 * scale),
+        Point::origin(),
+        Vector::new(0.0, 1.0, 0.0),
+    )
+    .unwrap();
+    let camera =
+        common::pinhole_camera(pose, ImageOptions::new(16, 16).unwrap().antialias(0), 20.0);
+
+    // Under a uniform white sky every diffuse bounce off the convex ground
+    // escapes, so each pixel is exactly the albedo unless a ray hits the
+    // surface it just left
+    let white = Image::from_pixels(2, 1, vec![Color::new(1.0, 1.0, 1.0); 2]).unwrap();
+    let sky = Background::Environment(Arc::new(EnvironmentMap::new(white).unwrap()));
+    let acne = |adaptive: bool| {
+        let options = RenderOptions::new()
+            .background(sky.clone())
+            .adaptive_ray_offset(adaptive);
+        let pixels = camera.render_in_memory_with_options(&world, &options);
+        let ground = pixels[pixels.len() - 1];
+        pixels
+            .iter()
+            .filter(|pixel| pixel.g() != ground.g())
+            .count()
+    };
+
+    assert!(acne(false) > 0);
+    assert_eq!(acne(true), 0);
+}
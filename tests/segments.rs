@@ -0,0 +1,72 @@
+use ray_tracing_rs::assert_vec_eq;
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::Lambertian;
+use ray_tracing_rs::objects::{Hittable, Segment};
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{Interval, Point};
+
+fn x_axis() -> Segment {
+    Segment::new(
+        Point::new(-1.0, 0.0, 0.0),
+        Point::new(1.0, 0.0, 0.0),
+        0.05,
+        Lambertian::new(Color::new(1.0, 0.0, 0.0)),
+    )
+}
+
+fn hit_from(segment: &Segment, origin: Point, dir: Vector) -> Option<(f64, Vector)> {
+    let ray = Ray::new(&origin, dir.unit());
+    segment
+        .hit(Interval::new(0.001, f64::MAX), &ray)
+        .map(|record| (record.t(), *record.normal().inner()))
+}
+
+#[test]
+fn segment_is_hit_within_its_radius() {
+    let segment = x_axis();
+    let down = Vector::new(0.0, -1.0, 0.0);
+
+    let (t, normal) = hit_from(&segment, Point::new(0.3, 1.0, 0.0), down).unwrap();
+    assert!((t - 0.95).abs() < 1e-9);
+    assert_vec_eq!(normal, Vector::new(0.0, 1.0, 0.0));
+
+    assert!(hit_from(&segment, Point::new(0.3, 1.0, 0.06), down).is_none());
+    assert!(!segment.hit_p(
+        Interval::new(0.001, f64::MAX),
+        &Ray::new(&Point::new(0.3, 1.0, 0.06), down.unit())
+    ));
+}
+
+#[test]
+fn segment_ends_are_rounded() {
+    let segment = x_axis();
+
+    // Straight down the axis, into the hemispherical cap
+    let (t, normal) = hit_from(
+        &segment,
+        Point::new(3.0, 0.0, 0.0),
+        Vector::new(-1.0, 0.0, 0.0),
+    )
+    .unwrap();
+    assert!((t - 1.95).abs() < 1e-9);
+    assert_vec_eq!(normal, Vector::new(1.0, 0.0, 0.0));
+
+    // Past the end but within the cap's sphere
+    assert!(
+        hit_from(
+            &segment,
+            Point::new(1.03, 1.0, 0.0),
+            Vector::new(0.0, -1.0, 0.0)
+        )
+        .is_some()
+    );
+    assert!(
+        hit_from(
+            &segment,
+            Point::new(1.06, 1.0, 0.0),
+            Vector::new(0.0, -1.0, 0.0)
+        )
+        .is_none()
+    );
+}
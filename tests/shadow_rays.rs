@@ -0,0 +1,24 @@
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::Lambertian;
+use ray_tracing_rs::objects::Sphere;
+use ray_tracing_rs::ray::Ray;
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{HittableList, Point};
+
+#[test]
+fn occluders_beyond_t_max_do_not_block() {
+    let mut world = HittableList::new();
+    world.add(Sphere::new(
+        Point::new(0.0, 0.0, -5.0),
+        1.0,
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    ));
+
+    let origin = Point::origin();
+    let ray = Ray::new(&origin, Vector::new(0.0, 0.0, -1.0).unit());
+
+    // The sphere's near side is 4 units away
+    assert!(!ray.hits_before(&world, 3.9));
+    assert!(ray.hits_before(&world, 4.1));
+    assert!(ray.hits_before(&world, f64::MAX));
+}
@@ -0,0 +1,43 @@
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::DiffuseLight;
+use ray_tracing_rs::objects::Sphere;
+use ray_tracing_rs::{HittableList, ImageOptions, Point};
+
+mod common;
+
+const WIDTH: u32 = 30;
+
+/// Lit pixels in the left, middle, and right thirds of the image.
+fn lit_thirds(world: &HittableList) -> [usize; 3] {
+    let camera = common::forward_camera(ImageOptions::new(WIDTH, 10).unwrap());
+
+    let mut thirds = [0; 3];
+    for (i, pixel) in camera.render_in_memory(world).iter().enumerate() {
+        if pixel.r() == 2.0 {
+            thirds[(i as u32 % WIDTH / (WIDTH / 3)) as usize] += 1;
+        }
+    }
+    thirds
+}
+
+#[test]
+fn subset_renders_only_the_selected_objects() {
+    let mut world = HittableList::new();
+    for x in [-3.0, 0.0, 3.0] {
+        world.add(Sphere::new(
+            Point::new(x, 0.0, -3.0),
+            0.5,
+            DiffuseLight::new(Color::new(2.0, 2.0, 2.0)),
+        ));
+    }
+
+    let all = lit_thirds(&world);
+    assert!(all.iter().all(|&lit| lit > 0));
+
+    let middle = lit_thirds(&world.view_subset(&[1]));
+    assert_eq!(middle, [0, all[1], 0]);
+
+    let outer = world.view_subset(&[2, 0, 7]);
+    assert_eq!(outer.objects().len(), 2);
+    assert_eq!(lit_thirds(&outer), [all[0], 0, all[2]]);
+}
@@ -0,0 +1,38 @@
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{Point, assert_color_eq, assert_vec_eq};
+
+#[test]
+fn near_equal_values_pass() {
+    assert_vec_eq!(
+        Vector::new(0.1 + 0.2, 1.0, -2.0),
+        Vector::new(0.3, 1.0, -2.0)
+    );
+    assert_vec_eq!(Point::new(1.0, 2.0, 3.0), Point::new(1.05, 2.0, 3.0), 0.1);
+    assert_vec_eq!(
+        Vector::new(3.0, 0.0, 4.0).unit(),
+        Vector::new(0.6, 0.0, 0.8)
+    );
+    assert_color_eq!(Color::new(0.25, 0.5, 1.0) * 2.0, Color::new(0.5, 1.0, 2.0));
+}
+
+#[test]
+#[should_panic(expected = "y differs by 0.5")]
+fn far_apart_vectors_fail() {
+    assert_vec_eq!(Vector::new(1.0, 1.0, 1.0), Vector::new(1.0, 1.5, 1.0));
+}
+
+#[test]
+#[should_panic(expected = "b differs")]
+fn far_apart_colors_fail() {
+    assert_color_eq!(Color::new(0.0, 0.0, 0.0), Color::new(0.0, 0.0, 0.1), 0.01);
+}
+
+#[test]
+#[should_panic(expected = "x differs by NaN")]
+fn nan_never_matches() {
+    assert_vec_eq!(
+        Vector::new(f64::NAN, 0.0, 0.0),
+        Vector::new(f64::NAN, 0.0, 0.0)
+    );
+}
@@ -0,0 +1,215 @@
+use std::f64::consts::PI;
+
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::{DiffuseLight, Metal};
+use ray_tracing_rs::objects::{Hittable, Plane, Sphere};
+use ray_tracing_rs::presets::{self, FLOOR_HEIGHT};
+use ray_tracing_rs::ray::{Ray, RayDifferential};
+use ray_tracing_rs::textures::{Checker, SolidColor, Texture};
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{Camera, CameraPose, HittableList, ImageOptions, Interval, Point};
+
+mod common;
+
+fn render(camera: &Camera, world: &HittableList) -> Vec<String> {
+    camera
+        .render_in_memory(world)
+        .into_iter()
+        .map(|pixel| pixel.to_string())
+        .collect()
+}
+
+#[test]
+fn checker_roughness_mixes_sharp_and_blurry_reflections() {
+    let camera = common::forward_camera(ImageOptions::new(24, 24).unwrap());
+
+    let roughness = Checker::new(8.0, SolidColor::scalar(0.0), SolidColor::scalar(1.0));
+    let metal = Metal::with_roughness_texture(Color::new(0.9, 0.9, 0.9), roughness);
+    let mut world = HittableList::new();
+    world.add(Sphere::new(Point::new(0.0, 0.0, -2.0), 1.0, metal));
+
+    let blank = render(&camera, &HittableList::new());
+    let first = render(&camera, &world);
+    let second = render(&camera, &world);
+
+    let on_sphere = |i: &usize| first[*i] != blank[*i] || second[*i] != blank[*i];
+    let sharp = (0..first.len())
+        .filter(on_sphere)
+        .filter(|&i| first[i] == second[i])
+        .count();
+    let blurry = (0..first.len())
+        .filter(on_sphere)
+        .filter(|&i| first[i] != second[i])
+        .count();
+
+    assert!(sharp > 0);
+    assert!(blurry > 0);
+}
+
+#[test]
+fn checkered_floor_preset_lies_beneath_origin() {
+    let mut world = HittableList::new();
+    world.add(presets::checkered_floor());
+
+    let origin = Point::new(0.0, 0.0, 0.0);
+    let down = Ray::new(&origin, Vector::new(0.0, -1.0, 0.0).unit());
+    let up = Ray::new(&origin, Vector::new(0.0, 1.0, 0.0).unit());
+
+    let depth = -FLOOR_HEIGHT;
+    assert!(world.hit_p(Interval::new(0.001, depth + 1e-6), &down));
+    assert!(!world.hit_p(Interval::new(0.001, depth - 1e-6), &down));
+    assert!(!world.hit_p(Interval::new(0.001, f64::MAX), &up));
+}
+
+#[test]
+fn checker_averages_over_wide_footprints() {
+    let checker = Checker::new(
+        10.0,
+        SolidColor::new(Color::new(0.0, 0.0, 0.0)),
+        SolidColor::new(Color::new(1.0, 1.0, 1.0)),
+    );
+    let point = Point::new(0.0, 0.0, 0.0);
+
+    let sharp = checker.value_filtered(0.05, 0.05, &point, 0.0);
+    let averaged = checker.value_filtered(0.05, 0.05, &point, 3.0);
+
+    assert_eq!(sharp.r(), 0.0);
+    assert!((averaged.r() - 0.5).abs() < 0.05);
+}
+
+#[test]
+fn footprint_grows_at_grazing_angles() {
+    let mut world = HittableList::new();
+    world.add(presets::checkered_floor());
+
+    let differential = RayDifferential {
+        dx: Vector::new(0.01, 0.0, 0.0),
+        dy: Vector::new(0.0, 0.0, 0.01),
+    };
+    let origin = Point::new(0.0, 0.0, 0.0);
+    let head_on =
+        Ray::new(&origin, Vector::new(0.0, -1.0, 0.0).unit()).with_differential(differential);
+    let grazing =
+        Ray::new(&origin, Vector::new(0.0, -0.05, -1.0).unit()).with_differential(differential);
+
+    let interval = Interval::new(0.001, f64::MAX);
+    let head_on_footprint = head_on.footprint(&world.hit(interval, &head_on).unwrap());
+    let grazing_footprint = grazing.footprint(&world.hit(interval, &grazing).unwrap());
+
+    assert!((head_on_footprint - 0.005).abs() < 1e-9);
+    assert!(grazing_footprint > 10.0 * head_on_footprint);
+}
+
+#[test]
+fn uv_footprints_shrink_with_the_surface_they_land_on() {
+    let differential = RayDifferential {
+        dx: Vector::new(0.01, 0.0, 0.0),
+        dy: Vector::new(0.0, 0.01, 0.0),
+    };
+    let origin = Point::new(0.0, 0.0, 20.0);
+    let ray = Ray::new(&origin, Vector::new(0.0, 0.0, -1.0).unit()).with_differential(differential);
+    let interval = Interval::new(0.001, f64::MAX);
+    let uv_footprint = |radius: f64| {
+        let sphere = Sphere::new(
+            Point::new(0.0, 0.0, -radius),
+            radius,
+            Metal::new(Color::new(1.0, 1.0, 1.0), 0.0),
+        );
+        let record = sphere.hit(interval, &ray).unwrap();
+        (ray.footprint(&record), ray.uv_footprint(&record))
+    };
+
+    // Both spheres are hit 20 units away, but the (u, v) square is wrapped
+    // around ten times more surface on the larger one
+    let (small_world, small_uv) = uv_footprint(1.0);
+    let (large_world, large_uv) = uv_footprint(10.0);
+    assert!((small_world - large_world).abs() < 1e-9);
+    assert!((small_uv / large_uv - 10.0).abs() < 1e-9);
+    assert!((small_uv - small_world / (2.0 * PI.sqrt())).abs() < 1e-12);
+}
+
+#[test]
+fn textured_light_emits_its_texture() {
+    let (red, blue) = (Color::new(4.0, 0.0, 0.0), Color::new(0.0, 0.0, 4.0));
+    let screen = DiffuseLight::textured(Checker::new(
+        2.0,
+        SolidColor::new(red),
+        SolidColor::new(blue),
+    ));
+    let mut world = HittableList::new();
+    world.add(Sphere::new(Point::origin(), 1.0, screen));
+
+    let glow_towards = |normal: Vector| {
+        let origin = Point::origin() + normal * 5.0;
+        Ray::new(&origin, -normal.unit()).color(&world, 4)
+    };
+    // (u, v) of about (0.25, 0.6) and (0.6, 0.6): neighbouring squares
+    let odd = glow_towards(Vector::new(0.0, 0.3, 0.95));
+    let even = glow_towards(Vector::new(0.8, 0.3, -0.52));
+
+    assert_eq!((odd.r(), odd.b()), (blue.r(), blue.b()));
+    assert_eq!((even.r(), even.b()), (red.r(), red.b()));
+}
+
+/// A texture that ignores footprints and always point-samples `0`.
+struct PointSampled<T>(T);
+
+impl<T: Texture> Texture for PointSampled<T> {
+    fn value(&self, u: f64, v: f64, point: &Point) -> Color {
+        self.0.value(u, v, point)
+    }
+}
+
+#[test]
+fn filtered_checker_matches_a_supersampled_grazing_floor() {
+    let checker = || {
+        Checker::new(
+            4.0,
+            SolidColor::new(Color::new(0.0, 0.0, 0.0)),
+            SolidColor::new(Color::new(1.0, 1.0, 1.0)),
+        )
+    };
+    let floor = |texture: Box<dyn Fn() -> DiffuseLight>| {
+        let mut world = HittableList::new();
+        world.add(Plane::new(
+            Vector::new(0.0, 1.0, 0.0).unit(),
+            0.0,
+            texture(),
+        ));
+        world
+    };
+    let filtered = floor(Box::new(move || DiffuseLight::textured(checker())));
+    let point_sampled = floor(Box::new(move || {
+        DiffuseLight::textured(PointSampled(checker()))
+    }));
+
+    // Low over the floor, looking towards the horizon
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 0.5, 0.0),
+        Point::new(0.0, 0.0, -4.0),
+        Vector::new(0.0, 1.0, 0.0),
+    )
+    .unwrap();
+    let image = |spp| ImageOptions::new(32, 16).unwrap().antialias(spp);
+    let render = |world: &HittableList, spp| {
+        common::pinhole_camera(pose, image(spp), 60.0)
+            .render_in_memory(world)
+            .iter()
+            .map(|pixel| pixel.r())
+            .collect::<Vec<_>>()
+    };
+    let reference = render(&point_sampled, 1024);
+    let error = |pixels: Vec<f64>| {
+        pixels
+            .iter()
+            .zip(&reference)
+            .map(|(pixel, reference)| (pixel - reference).abs())
+            .sum::<f64>()
+            / reference.len() as f64
+    };
+
+    let filtered_error = error(render(&filtered, 0));
+    let aliased_error = error(render(&point_sampled, 0));
+    assert!(filtered_error < 0.08, "{filtered_error}");
+    assert!(filtered_error * 2.5 < aliased_error);
+}
@@ -0,0 +1,166 @@
+use std::fs;
+
+use ray_tracing_rs::color::Color;
+use ray_tracing_rs::materials::{DiffuseLight, Lambertian, ShadowCatcher};
+use ray_tracing_rs::objects::{Plane, Sphere};
+use ray_tracing_rs::scene::{DirectLighting, ParallelOptions, RenderOptions};
+use ray_tracing_rs::vector::Vector;
+use ray_tracing_rs::{
+    Camera, CameraPose, HittableList, ImageOptions, Point, assert_color_eq, seed_thread_rng,
+};
+
+mod common;
+
+const SIZE: u32 = 9;
+
+fn sphere_on_empty() -> (Camera, HittableList) {
+    let camera = common::forward_camera(ImageOptions::new(SIZE, SIZE).unwrap());
+
+    let mut world = HittableList::new();
+    world.add(Sphere::new(
+        Point::new(0.0, 0.0, -3.0),
+        0.5,
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    ));
+    (camera, world)
+}
+
+#[test]
+fn background_is_transparent_and_sphere_opaque() {
+    let (camera, world) = sphere_on_empty();
+    let options = RenderOptions::new().transparent_background(true);
+
+    let pixels = camera.render_rgba_in_memory(&world, &options);
+
+    let center = (SIZE / 2 * SIZE + SIZE / 2) as usize;
+    assert_eq!(pixels[center].1, 1.0);
+    assert_eq!(pixels[0].1, 0.0);
+    assert_eq!(pixels[pixels.len() - 1].1, 0.0);
+}
+
+#[test]
+fn transparent_renders_are_written_as_rgba_pam() {
+    let (camera, world) = sphere_on_empty();
+    let options = RenderOptions::new().transparent_background(true);
+    let path = common::temp_path("transparent.pam");
+
+    camera.render_with_options(&world, &path, &options).unwrap();
+    let bytes = fs::read(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let header = "P7\nWIDTH 9\nHEIGHT 9\nDEPTH 4\nMAXVAL 255\nTUPLTYPE RGB_ALPHA\nENDHDR\n";
+    assert!(bytes.starts_with(header.as_bytes()));
+    let body = &bytes[header.len()..];
+    assert_eq!(body.len(), (SIZE * SIZE * 4) as usize);
+    assert_eq!(body[3], 0);
+    let center = (SIZE / 2 * SIZE + SIZE / 2) as usize * 4;
+    assert_eq!(body[center + 3], 255);
+}
+
+/// A shadow-catching floor seen from above, with a ball casting a shadow from
+/// a small light onto the center of the image. Returns the lights as well.
+fn ball_over_catcher() -> (Camera, HittableList, HittableList) {
+    let pose = CameraPose::look_at(
+        Point::new(0.0, 5.0, 0.0),
+        Point::new(0.0, 0.0, 0.0),
+        Vector::new(0.0, 0.0, -1.0),
+    )
+    .unwrap();
+    let camera = common::pinhole_camera(pose, ImageOptions::new(SIZE, SIZE).unwrap(), 90.0);
+
+    let light = || {
+        Sphere::new(
+            Point::new(3.0, 3.0, 0.0),
+            0.25,
+            DiffuseLight::new(Color::new(20.0, 20.0, 20.0)),
+        )
+    };
+    let mut lights = HittableList::new();
+    lights.add(light());
+
+    let mut world = HittableList::new();
+    world
+        .add(Plane::new(
+            Vector::new(0.0, 1.0, 0.0).unit(),
+            0.0,
+            ShadowCatcher::new(),
+        ))
+        .add(Sphere::new(
+            Point::new(1.5, 1.5, 0.0),
+            0.5,
+            Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+        ))
+        .add(light());
+    (camera, world, lights)
+}
+
+#[test]
+fn shadow_catcher_is_opaque_only_in_shadow() {
+    let (camera, world, lights) = ball_over_catcher();
+    let options = RenderOptions::new()
+        .transparent_background(true)
+        .lights(lights);
+
+    let pixels = camera.render_rgba_in_memory(&world, &options);
+
+    let row = (SIZE / 2 * SIZE) as usize;
+    let (shadow, lit) = (pixels[row + SIZE as usize / 2], pixels[row]);
+    assert_eq!(shadow.1, 1.0);
+    assert_eq!(shadow.0.to_rgb8(), [0, 0, 0]);
+    assert_eq!(lit.1, 0.0);
+}
+
+#[test]
+fn shadow_catcher_darkens_the_background_in_shadow() {
+    let (camera, world, lights) = ball_over_catcher();
+    let options = RenderOptions::new().lights(lights);
+
+    let pixels = camera.render_in_memory_with_options(&world, &options);
+
+    let row = (SIZE / 2 * SIZE) as usize;
+    let (shadow, lit) = (pixels[row + SIZE as usize / 2], pixels[row]);
+    assert_eq!(shadow.to_rgb8(), [0, 0, 0]);
+    assert!(lit.r() > 0.1, "lit floor shows the sky behind it: {lit}");
+}
+
+#[test]
+fn shadow_catchers_do_not_block_light() {
+    // A ball lit only by a light below the catching floor
+    let light = || {
+        Sphere::new(
+            Point::new(0.0, -3.0, 2.0),
+            0.5,
+            DiffuseLight::new(Color::new(20.0, 20.0, 20.0)),
+        )
+    };
+    let mut lights = HittableList::new();
+    lights.add(light());
+    let mut ball = HittableList::new();
+    ball.add(Sphere::new(
+        Point::new(0.0, 0.0, -3.0),
+        0.5,
+        Lambertian::new(Color::new(0.5, 0.5, 0.5)),
+    ))
+    .add(light());
+    let mut caught = ball.clone();
+    caught.add(Plane::new(
+        Vector::new(0.0, 1.0, 0.0).unit(),
+        1.0,
+        ShadowCatcher::new(),
+    ));
+
+    let camera = common::forward_camera(ImageOptions::new(SIZE, SIZE).unwrap().antialias(0));
+    let options = RenderOptions::new()
+        .lights(lights)
+        .direct_lighting(DirectLighting::LightOnly)
+        .parallel(ParallelOptions::Series);
+    let center = (SIZE / 2 * SIZE + SIZE / 2) as usize;
+    let ball_center = |world: &HittableList| {
+        seed_thread_rng(7);
+        camera.render_in_memory_with_options(world, &options)[center]
+    };
+
+    let (without, with) = (ball_center(&ball), ball_center(&caught));
+    assert!(without.r() > 0.1);
+    assert_color_eq!(with, without);
+}
@@ -0,0 +1,75 @@
+use ray_tracing_rs::vector::{UtVector, Vector};
+
+#[test]
+fn new_unchecked_accepts_unit_vectors() {
+    let v = UtVector::new_unchecked(Vector::new(0.0, 1.0, 0.0));
+    assert_eq!(v.y(), 1.0);
+}
+
+// Release builds skip the length check entirely, so this only panics in debug.
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn new_unchecked_panics_on_non_unit_in_debug() {
+    UtVector::new_unchecked(Vector::new(2.0, 0.0, 0.0));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+#[allow(deprecated)]
+fn assert_unit_unsafe_panics_on_non_unit_in_debug() {
+    Vector::new(0.0, 0.5, 0.0).assert_unit_unsafe();
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn new_unchecked_trusts_input_in_release() {
+    let v = UtVector::new_unchecked(Vector::new(2.0, 0.0, 0.0));
+    assert_eq!(v.x(), 2.0);
+}
+
+#[test]
+fn points_and_vectors_are_distinct() {
+    use ray_tracing_rs::Point;
+
+    let a = Point::new(1.0, 2.0, 3.0);
+    let b = Point::new(0.0, 2.0, 1.0);
+
+    let between: Vector = a - b;
+    assert_eq!(between.len_squared(), 5.0);
+
+    let moved: Point = b + between;
+    assert_eq!(moved.x(), a.x());
+    assert_eq!(moved.z(), a.z());
+}
+
+#[test]
+fn unit_vectors_support_direct_arithmetic() {
+    let utv = Vector::new(3.0, 0.0, 4.0).unit();
+    let other = Vector::new(1.0, 2.0, 3.0);
+
+    let (utv_ref, other_ref) = (&utv, &other);
+
+    let same = |a: Vector, b: Vector| (a - b).len() < 1e-12;
+    assert!(same(utv * 2.0, utv.inner() * 2.0));
+    assert!(same(utv_ref + other_ref, utv.inner() + other));
+    assert!(same(utv_ref - other_ref, utv.inner() - other));
+    assert!(same(other - utv, other - utv.inner()));
+    assert!(same(other_ref + utv_ref, other + utv.inner()));
+}
+
+#[test]
+fn projection_and_rejection_split_a_vector() {
+    let v = Vector::new(1.0, 1.0, 0.0);
+    let axis = Vector::new(1.0, 0.0, 0.0);
+
+    let projected = v.project_onto(&axis);
+    let rejected = v.reject_from(&axis);
+
+    assert_eq!(
+        (projected.x(), projected.y(), projected.z()),
+        (1.0, 0.0, 0.0)
+    );
+    assert_eq!((rejected.x(), rejected.y(), rejected.z()), (0.0, 1.0, 0.0));
+}